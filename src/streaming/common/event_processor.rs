@@ -9,8 +9,11 @@ use crate::streaming::common::BackpressureStrategy;
 use crate::streaming::common::{
     MetricsEventType, MetricsManager, StreamClientConfig as ClientConfig,
 };
-use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::common::filter::{EventTypeFilter, InvokerFilter};
 use crate::streaming::event_parser::core::account_event_parser::AccountEventParser;
+use crate::streaming::event_parser::core::address_lookup_table::{
+    update_address_lookup_table, ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+};
 use crate::streaming::event_parser::core::common_event_parser::CommonEventParser;
 
 use crate::streaming::event_parser::EventParser;
@@ -28,6 +31,7 @@ pub struct EventProcessor {
     pub(crate) parser_cache: OnceCell<Arc<dyn EventParser>>,
     pub(crate) protocols: Vec<Protocol>,
     pub(crate) event_type_filter: Option<EventTypeFilter>,
+    pub(crate) invoker_filter: Option<InvokerFilter>,
     pub(crate) callback: Option<Arc<dyn Fn(Box<dyn UnifiedEvent>) + Send + Sync>>,
     pub(crate) backpressure_config: BackpressureConfig,
     pub(crate) grpc_queue: Arc<SegQueue<(EventPretty, Option<Pubkey>)>>,
@@ -52,6 +56,7 @@ impl EventProcessor {
             parser_cache: OnceCell::new(),
             protocols: vec![],
             event_type_filter: None,
+            invoker_filter: None,
             backpressure_config,
             callback: None,
             grpc_queue,
@@ -85,6 +90,11 @@ impl EventProcessor {
         }
     }
 
+    /// 设置调用方程序过滤，仅作用于交易事件
+    pub fn set_invoker_filter(&mut self, invoker_filter: Option<InvokerFilter>) {
+        self.invoker_filter = invoker_filter;
+    }
+
     pub fn get_parser(&self) -> Arc<dyn EventParser> {
         self.parser_cache.get().unwrap().clone()
     }
@@ -92,8 +102,12 @@ impl EventProcessor {
     fn create_adapter_callback(&self) -> Arc<dyn Fn(Box<dyn UnifiedEvent>) + Send + Sync> {
         let callback = self.callback.clone().unwrap();
         let metrics_manager = self.metrics_manager.clone();
+        let invoker_filter = self.invoker_filter.clone();
 
         Arc::new(move |event: Box<dyn UnifiedEvent>| {
+            if invoker_filter.as_ref().is_some_and(|filter| !filter.matches(event.metadata())) {
+                return;
+            }
             let processing_time_us = event.handle_us() as f64;
             callback(event);
             metrics_manager.update_metrics(MetricsEventType::Transaction, 1, processing_time_us);
@@ -165,6 +179,11 @@ impl EventProcessor {
         match event_pretty {
             EventPretty::Account(account_pretty) => {
                 self.metrics_manager.add_account_process_count();
+                // 地址查找表账户只用于更新缓存，不产生事件
+                if account_pretty.owner == ADDRESS_LOOKUP_TABLE_PROGRAM_ID {
+                    update_address_lookup_table(account_pretty.pubkey, &account_pretty.data);
+                    return Ok(());
+                }
                 let account_event = AccountEventParser::parse_account_event(
                     &self.protocols,
                     account_pretty,
@@ -186,87 +205,20 @@ impl EventProcessor {
                 let grpc_tx = transaction_pretty.grpc_tx;
 
                 let parser = self.get_parser();
-                
-                // 🔧 CRITICAL FIX: Use raw transaction data callback that sets raw_transaction for RaydiumAmmV4
-                // Check if we have RaydiumAmmV4 protocols that need full raw data
-                let has_raydium_amm_v4 = self.protocols.contains(&crate::streaming::event_parser::Protocol::RaydiumAmmV4);
-                
-                if has_raydium_amm_v4 {
-                    // Create callback that sets raw transaction data for RaydiumAmmV4 events
-                    let callback = self.callback.clone().unwrap();
-                    let metrics_manager = self.metrics_manager.clone();
-                    let grpc_tx_for_callback = grpc_tx.clone();
-                    
-                    let raw_data_callback = Arc::new(move |event: &Box<dyn crate::streaming::event_parser::core::traits::UnifiedEvent>| {
-                        // Check if this is a RaydiumAmmV4 event and add raw transaction data
-                        use crate::streaming::event_parser::protocols::raydium_amm_v4::RaydiumAmmV4SwapEvent;
-                        if let Some(raydium_event) = event.as_any().downcast_ref::<RaydiumAmmV4SwapEvent>() {
-                            // Convert to mutable and set raw transaction data
-                            let mut raydium_event_mut = raydium_event.clone();
-                            
-                            // Convert SubscribeUpdateTransactionInfo to EncodedTransactionWithStatusMeta
-                            if let Ok(tx_with_meta) = yellowstone_grpc_proto::convert_from::create_tx_with_meta(grpc_tx_for_callback.clone()) {
-                                if let Ok(encoded_tx) = tx_with_meta.encode(
-                                    solana_transaction_status::UiTransactionEncoding::Base64, 
-                                    Some(u8::MAX), 
-                                    true
-                                ) {
-                                    use crate::streaming::event_parser::common::types::RawTransactionWrapper;
-                                    let raw_wrapper = RawTransactionWrapper::new(encoded_tx);
-                                    raydium_event_mut.metadata.raw_transaction = Some(raw_wrapper);
-                                }
-                            }
-                            
-                            // Clone the event with raw data and invoke original callback
-                            let event_with_raw_data: Box<dyn crate::streaming::event_parser::core::traits::UnifiedEvent> = Box::new(raydium_event_mut);
-                            let processing_time_us = event_with_raw_data.handle_us() as f64;
-                            callback(event_with_raw_data);
-                            metrics_manager.update_metrics(crate::streaming::common::MetricsEventType::Transaction, 1, processing_time_us);
-                        } else {
-                            // For non-RaydiumAmmV4 events, use normal callback
-                            let processing_time_us = event.handle_us() as f64;
-                            callback(event.clone());
-                            metrics_manager.update_metrics(crate::streaming::common::MetricsEventType::Transaction, 1, processing_time_us);
-                        }
-                    });
-                    
-                    // Parse with the modified callback that adds raw data
-                    parser
-                        .parse_grpc_transaction(
-                            grpc_tx,
-                            signature,
-                            Some(slot),
-                            block_time,
-                            recv_us,
-                            bot_wallet,
-                            transaction_index,
-                            raw_data_callback,
-                        )
-                        .await?;
-                } else {
-                    // Lightweight parsing for other protocols - create correct callback signature
-                    let callback = self.callback.clone().unwrap();
-                    let metrics_manager = self.metrics_manager.clone();
-                    
-                    let lightweight_callback = Arc::new(move |event: &Box<dyn crate::streaming::event_parser::core::traits::UnifiedEvent>| {
-                        let processing_time_us = event.handle_us() as f64;
-                        callback(event.clone());
-                        metrics_manager.update_metrics(crate::streaming::common::MetricsEventType::Transaction, 1, processing_time_us);
-                    });
-                    
-                    parser
-                        .parse_grpc_transaction(
-                            grpc_tx,
-                            signature,
-                            Some(slot),
-                            block_time,
-                            recv_us,
-                            bot_wallet,
-                            transaction_index,
-                            lightweight_callback,
-                        )
-                        .await?;
-                }
+                // 交易上下文直接写入交给回调的事件副本，无需再次复制
+                let adapter_callback = self.create_adapter_callback();
+                parser
+                    .parse_grpc_transaction_owned(
+                        grpc_tx,
+                        signature,
+                        Some(slot),
+                        block_time,
+                        recv_us,
+                        bot_wallet,
+                        transaction_index,
+                        adapter_callback,
+                    )
+                    .await?;
             }
             EventPretty::BlockMeta(block_meta_pretty) => {
                 self.metrics_manager.add_block_meta_process_count();
@@ -471,6 +423,7 @@ impl Clone for EventProcessor {
             parser_cache: self.parser_cache.clone(),
             protocols: self.protocols.clone(),
             event_type_filter: self.event_type_filter.clone(),
+            invoker_filter: self.invoker_filter.clone(),
             backpressure_config: self.backpressure_config.clone(),
             callback: self.callback.clone(),
             grpc_queue: self.grpc_queue.clone(),
@@ -0,0 +1,451 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{
+    EncodedTransaction, EncodedTransactionWithStatusMeta, UiMessage, UiTransactionStatusMeta,
+    UiTransactionTokenBalance,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use yellowstone_grpc_proto::prelude::{TokenBalance, TransactionStatusMeta};
+
+/// 代币余额变化
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenBalanceChange {
+    /// 代币账户
+    pub account: Pubkey,
+    /// 代币账户所有者
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub pre_amount: u64,
+    pub post_amount: u64,
+    pub change: i128,
+}
+
+/// 账户在交易前后的SOL和代币余额变化
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChanges {
+    pub owner: Pubkey,
+    pub sol_pre: u64,
+    pub sol_post: u64,
+    pub sol_change: i64,
+    /// 仅包含余额发生变化的代币账户
+    pub token_changes: Vec<TokenBalanceChange>,
+}
+
+/// 代币余额的借用视图，屏蔽gRPC和RPC两种结构的差异
+struct RawTokenBalance<'a> {
+    account_index: usize,
+    mint: &'a str,
+    owner: &'a str,
+    decimals: u8,
+    amount: &'a str,
+}
+
+impl<'a> From<&'a TokenBalance> for RawTokenBalance<'a> {
+    fn from(balance: &'a TokenBalance) -> Self {
+        let (decimals, amount) = balance
+            .ui_token_amount
+            .as_ref()
+            .map(|ui| (ui.decimals as u8, ui.amount.as_str()))
+            .unwrap_or((0, "0"));
+        Self {
+            account_index: balance.account_index as usize,
+            mint: balance.mint.as_str(),
+            owner: balance.owner.as_str(),
+            decimals,
+            amount,
+        }
+    }
+}
+
+impl<'a> From<&'a UiTransactionTokenBalance> for RawTokenBalance<'a> {
+    fn from(balance: &'a UiTransactionTokenBalance) -> Self {
+        Self {
+            account_index: balance.account_index as usize,
+            mint: balance.mint.as_str(),
+            owner: match &balance.owner {
+                OptionSerializer::Some(owner) => owner.as_str(),
+                _ => "",
+            },
+            decimals: balance.ui_token_amount.decimals,
+            amount: balance.ui_token_amount.amount.as_str(),
+        }
+    }
+}
+
+impl BalanceChanges {
+    /// 从gRPC交易meta中提取 `accounts[owner_index]` 的余额变化
+    pub fn from_grpc_meta(
+        accounts: &[Pubkey],
+        owner_index: usize,
+        meta: &TransactionStatusMeta,
+    ) -> Option<Self> {
+        Self::build(
+            accounts,
+            owner_index,
+            &meta.pre_balances,
+            &meta.post_balances,
+            meta.pre_token_balances.iter().map(RawTokenBalance::from),
+            meta.post_token_balances.iter().map(RawTokenBalance::from),
+        )
+    }
+
+    /// 从RPC交易meta中提取 `accounts[owner_index]` 的余额变化
+    pub fn from_ui_meta(
+        accounts: &[Pubkey],
+        owner_index: usize,
+        meta: &UiTransactionStatusMeta,
+    ) -> Option<Self> {
+        let empty = Vec::new();
+        let pre_token_balances = match &meta.pre_token_balances {
+            OptionSerializer::Some(balances) => balances,
+            _ => &empty,
+        };
+        let post_token_balances = match &meta.post_token_balances {
+            OptionSerializer::Some(balances) => balances,
+            _ => &empty,
+        };
+        Self::build(
+            accounts,
+            owner_index,
+            &meta.pre_balances,
+            &meta.post_balances,
+            pre_token_balances.iter().map(RawTokenBalance::from),
+            post_token_balances.iter().map(RawTokenBalance::from),
+        )
+    }
+
+    /// 从编码交易中提取指定账户的余额变化，未指定时使用手续费支付者
+    pub fn from_encoded_transaction(
+        tx: &EncodedTransactionWithStatusMeta,
+        owner: Option<&Pubkey>,
+    ) -> Option<Self> {
+        let meta = tx.meta.as_ref()?;
+        let mut accounts = encoded_transaction_account_keys(&tx.transaction)?;
+        // Raw/Binary消息只包含静态账户，需要补上查找表加载的地址
+        if let OptionSerializer::Some(loaded_addresses) = &meta.loaded_addresses {
+            if accounts.len() < meta.pre_balances.len() {
+                accounts.extend(
+                    loaded_addresses
+                        .writable
+                        .iter()
+                        .chain(loaded_addresses.readonly.iter())
+                        .filter_map(|s| Pubkey::from_str(s).ok()),
+                );
+            }
+        }
+        let owner_index = owner
+            .and_then(|owner| accounts.iter().position(|account| account == owner))
+            .unwrap_or(0);
+        Self::from_ui_meta(&accounts, owner_index, meta)
+    }
+
+    /// 指定mint的代币余额变化总和
+    pub fn token_change(&self, mint: &Pubkey) -> i128 {
+        self.token_changes.iter().filter(|c| &c.mint == mint).map(|c| c.change).sum()
+    }
+
+    fn build<'a>(
+        accounts: &[Pubkey],
+        owner_index: usize,
+        pre_balances: &[u64],
+        post_balances: &[u64],
+        pre_token_balances: impl Iterator<Item = RawTokenBalance<'a>>,
+        post_token_balances: impl Iterator<Item = RawTokenBalance<'a>>,
+    ) -> Option<Self> {
+        let owner = *accounts.get(owner_index)?;
+        let owner_str = owner.to_string();
+        let sol_pre = pre_balances.get(owner_index).copied().unwrap_or(0);
+        let sol_post = post_balances.get(owner_index).copied().unwrap_or(0);
+
+        let mut token_changes: Vec<TokenBalanceChange> = Vec::new();
+        for (is_post, balance) in
+            pre_token_balances.map(|b| (false, b)).chain(post_token_balances.map(|b| (true, b)))
+        {
+            if balance.owner != owner_str {
+                continue;
+            }
+            let amount = balance.amount.parse::<u64>().unwrap_or(0);
+            let account = accounts.get(balance.account_index).copied().unwrap_or_default();
+            let index = match token_changes.iter().position(|c| c.account == account) {
+                Some(index) => index,
+                None => {
+                    token_changes.push(TokenBalanceChange {
+                        account,
+                        owner,
+                        mint: Pubkey::from_str(balance.mint).unwrap_or_default(),
+                        decimals: balance.decimals,
+                        ..Default::default()
+                    });
+                    token_changes.len() - 1
+                }
+            };
+            if is_post {
+                token_changes[index].post_amount = amount;
+            } else {
+                token_changes[index].pre_amount = amount;
+            }
+        }
+        token_changes.retain_mut(|c| {
+            c.change = c.post_amount as i128 - c.pre_amount as i128;
+            c.change != 0
+        });
+
+        Some(Self {
+            owner,
+            sol_pre,
+            sol_post,
+            sol_change: sol_post as i64 - sol_pre as i64,
+            token_changes,
+        })
+    }
+}
+
+/// 代币账户在交易前后的余额
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenAccountBalance {
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub pre_amount: u64,
+    pub post_amount: u64,
+}
+
+impl TokenAccountBalance {
+    pub fn change(&self) -> i128 {
+        self.post_amount as i128 - self.pre_amount as i128
+    }
+}
+
+/// 交易中所有代币账户的余额，按账户地址索引
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenAccountBalances {
+    balances: HashMap<Pubkey, TokenAccountBalance>,
+}
+
+impl TokenAccountBalances {
+    /// 从gRPC交易meta中提取
+    pub fn from_grpc_meta(accounts: &[Pubkey], meta: &TransactionStatusMeta) -> Self {
+        Self::build(
+            accounts,
+            meta.pre_token_balances.iter().map(RawTokenBalance::from),
+            meta.post_token_balances.iter().map(RawTokenBalance::from),
+        )
+    }
+
+    /// 从RPC交易meta中提取
+    pub fn from_ui_meta(accounts: &[Pubkey], meta: &UiTransactionStatusMeta) -> Self {
+        let empty = Vec::new();
+        let pre_token_balances = match &meta.pre_token_balances {
+            OptionSerializer::Some(balances) => balances,
+            _ => &empty,
+        };
+        let post_token_balances = match &meta.post_token_balances {
+            OptionSerializer::Some(balances) => balances,
+            _ => &empty,
+        };
+        Self::build(
+            accounts,
+            pre_token_balances.iter().map(RawTokenBalance::from),
+            post_token_balances.iter().map(RawTokenBalance::from),
+        )
+    }
+
+    pub fn get(&self, account: &Pubkey) -> Option<&TokenAccountBalance> {
+        self.balances.get(account)
+    }
+
+    /// 代币账户的mint
+    pub fn mint(&self, account: &Pubkey) -> Option<Pubkey> {
+        self.balances.get(account).map(|balance| balance.mint)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.balances.is_empty()
+    }
+
+    fn build<'a>(
+        accounts: &[Pubkey],
+        pre_token_balances: impl Iterator<Item = RawTokenBalance<'a>>,
+        post_token_balances: impl Iterator<Item = RawTokenBalance<'a>>,
+    ) -> Self {
+        let mut balances: HashMap<Pubkey, TokenAccountBalance> = HashMap::new();
+        for (is_post, balance) in
+            pre_token_balances.map(|b| (false, b)).chain(post_token_balances.map(|b| (true, b)))
+        {
+            let Some(account) = accounts.get(balance.account_index).copied() else {
+                continue;
+            };
+            let amount = balance.amount.parse::<u64>().unwrap_or(0);
+            let entry = balances.entry(account).or_insert_with(|| TokenAccountBalance {
+                mint: Pubkey::from_str(balance.mint).unwrap_or_default(),
+                decimals: balance.decimals,
+                ..Default::default()
+            });
+            if is_post {
+                entry.post_amount = amount;
+            } else {
+                entry.pre_amount = amount;
+            }
+        }
+        Self { balances }
+    }
+}
+
+/// 获取编码交易的账户列表
+fn encoded_transaction_account_keys(transaction: &EncodedTransaction) -> Option<Vec<Pubkey>> {
+    match transaction {
+        EncodedTransaction::Json(ui_tx) => match &ui_tx.message {
+            UiMessage::Raw(message) => {
+                Some(message.account_keys.iter().filter_map(|k| Pubkey::from_str(k).ok()).collect())
+            }
+            UiMessage::Parsed(message) => Some(
+                message.account_keys.iter().filter_map(|k| Pubkey::from_str(&k.pubkey).ok()).collect(),
+            ),
+        },
+        EncodedTransaction::Accounts(list) => {
+            Some(list.account_keys.iter().filter_map(|k| Pubkey::from_str(&k.pubkey).ok()).collect())
+        }
+        _ => transaction.decode().map(|tx| tx.message.static_account_keys().to_vec()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::prelude::UiTokenAmount;
+
+    fn ui_token_balance(
+        index: usize,
+        mint: &Pubkey,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "accountIndex": index,
+            "mint": mint.to_string(),
+            "owner": owner.to_string(),
+            "programId": spl_token::ID.to_string(),
+            "uiTokenAmount": {
+                "uiAmount": null,
+                "decimals": 6,
+                "amount": amount.to_string(),
+                "uiAmountString": "",
+            },
+        })
+    }
+
+    #[test]
+    fn encoded_transaction_includes_lookup_table_accounts() {
+        let signer = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let static_token = Pubkey::new_unique();
+        let loaded_token = Pubkey::new_unique();
+        let other_token = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let tx: EncodedTransactionWithStatusMeta = serde_json::from_value(serde_json::json!({
+            "transaction": {
+                "signatures": [],
+                "message": {
+                    "header": {
+                        "numRequiredSignatures": 1,
+                        "numReadonlySignedAccounts": 0,
+                        "numReadonlyUnsignedAccounts": 0,
+                    },
+                    "accountKeys": [signer.to_string(), other.to_string(), static_token.to_string()],
+                    "recentBlockhash": Pubkey::default().to_string(),
+                    "instructions": [],
+                },
+            },
+            "meta": {
+                "err": null,
+                "status": { "Ok": null },
+                "fee": 5000,
+                "preBalances": [1_000_000, 0, 0, 0, 0],
+                "postBalances": [900_000, 100_000, 0, 0, 0],
+                "preTokenBalances": [
+                    ui_token_balance(2, &mint, &signer, 500),
+                    ui_token_balance(4, &mint, &other, 100),
+                ],
+                "postTokenBalances": [
+                    ui_token_balance(2, &mint, &signer, 200),
+                    ui_token_balance(3, &mint, &signer, 300),
+                    ui_token_balance(4, &mint, &other, 50),
+                ],
+                "loadedAddresses": {
+                    "writable": [loaded_token.to_string()],
+                    "readonly": [other_token.to_string()],
+                },
+            },
+        }))
+        .unwrap();
+
+        let changes = BalanceChanges::from_encoded_transaction(&tx, None).unwrap();
+        assert_eq!(changes.owner, signer);
+        assert_eq!(changes.sol_change, -100_000);
+        assert_eq!(changes.token_changes.len(), 2);
+        assert_eq!(changes.token_changes[0].account, static_token);
+        assert_eq!(changes.token_changes[0].change, -300);
+        // 查找表加载的代币账户也应解析出正确地址
+        assert_eq!(changes.token_changes[1].account, loaded_token);
+        assert_eq!(changes.token_changes[1].owner, signer);
+        assert_eq!(changes.token_changes[1].mint, mint);
+        assert_eq!(changes.token_changes[1].decimals, 6);
+        assert_eq!(changes.token_changes[1].change, 300);
+        assert_eq!(changes.token_change(&mint), 0);
+
+        let other_changes = BalanceChanges::from_encoded_transaction(&tx, Some(&other)).unwrap();
+        assert_eq!(other_changes.sol_change, 100_000);
+        assert_eq!(other_changes.token_changes.len(), 1);
+        assert_eq!(other_changes.token_changes[0].account, other_token);
+        assert_eq!(other_changes.token_changes[0].change, -50);
+    }
+
+    #[test]
+    fn grpc_meta_signer_deltas() {
+        let signer = Pubkey::new_unique();
+        let token_account = Pubkey::new_unique();
+        let foreign_account = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let token_balance = |index: u32, owner: &Pubkey, amount: u64| TokenBalance {
+            account_index: index,
+            mint: mint.to_string(),
+            owner: owner.to_string(),
+            ui_token_amount: Some(UiTokenAmount {
+                decimals: 9,
+                amount: amount.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let meta = TransactionStatusMeta {
+            pre_balances: vec![2_000_000, 0, 0],
+            post_balances: vec![1_500_000, 0, 0],
+            post_token_balances: vec![
+                token_balance(1, &signer, 42),
+                token_balance(2, &Pubkey::new_unique(), 7),
+            ],
+            ..Default::default()
+        };
+        let accounts = [signer, token_account, foreign_account];
+
+        let changes = BalanceChanges::from_grpc_meta(&accounts, 0, &meta).unwrap();
+        assert_eq!(changes.sol_pre, 2_000_000);
+        assert_eq!(changes.sol_post, 1_500_000);
+        assert_eq!(changes.sol_change, -500_000);
+        assert_eq!(
+            changes.token_changes,
+            vec![TokenBalanceChange {
+                account: token_account,
+                owner: signer,
+                mint,
+                decimals: 9,
+                pre_amount: 0,
+                post_amount: 42,
+                change: 42,
+            }]
+        );
+        assert!(BalanceChanges::from_grpc_meta(&accounts, 3, &meta).is_none());
+    }
+}
@@ -1,5 +1,8 @@
+use solana_sdk::pubkey::Pubkey;
+
 use crate::streaming::event_parser::common::{
-    types::EventType, ACCOUNT_EVENT_TYPES, BLOCK_EVENT_TYPES,
+    types::{EventMetadata, EventType},
+    ACCOUNT_EVENT_TYPES, BLOCK_EVENT_TYPES,
 };
 
 #[derive(Debug, Clone, Default)]
@@ -22,3 +25,30 @@ impl EventTypeFilter {
         self.include.iter().any(|event| BLOCK_EVENT_TYPES.contains(event))
     }
 }
+
+/// 按调用方（路由）程序过滤事件
+///
+/// 顶层指令产生的事件没有调用方，`include` 非空时会被过滤掉
+#[derive(Debug, Clone, Default)]
+pub struct InvokerFilter {
+    /// 仅保留由这些程序调用的事件，为空时不限制
+    pub include: Vec<Pubkey>,
+    /// 排除由这些程序调用的事件
+    pub exclude: Vec<Pubkey>,
+    /// 仅比较直接调用者，否则比较整条调用链
+    pub direct_only: bool,
+}
+
+impl InvokerFilter {
+    pub fn matches(&self, metadata: &EventMetadata) -> bool {
+        let invokers = if self.direct_only {
+            metadata.direct_invoker().map(std::slice::from_ref).unwrap_or_default()
+        } else {
+            metadata.invoking_programs.as_slice()
+        };
+        if invokers.iter().any(|program| self.exclude.contains(program)) {
+            return false;
+        }
+        self.include.is_empty() || invokers.iter().any(|program| self.include.contains(program))
+    }
+}
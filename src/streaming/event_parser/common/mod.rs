@@ -1,6 +1,8 @@
 pub mod types;
 pub mod utils;
 pub mod filter;
+pub mod balance;
+pub mod transfer;
 
 /// 自动生成UnifiedEvent trait实现的宏
 #[macro_export]
@@ -70,9 +72,19 @@ macro_rules! impl_unified_event {
             fn transaction_index(&self) -> Option<u64> {
                 self.metadata.transaction_index
             }
+
+            fn metadata(&self) -> &$crate::streaming::event_parser::common::types::EventMetadata {
+                &self.metadata
+            }
+
+            fn metadata_mut(&mut self) -> &mut $crate::streaming::event_parser::common::types::EventMetadata {
+                &mut self.metadata
+            }
         }
     };
 }
 
 pub use types::*;
 pub use utils::*;
+pub use balance::*;
+pub use transfer::*;
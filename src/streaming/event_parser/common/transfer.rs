@@ -0,0 +1,261 @@
+use solana_sdk::pubkey::Pubkey;
+
+use super::types::TransferData;
+use super::utils::{read_u32_le, read_u64_le, read_u8};
+
+const TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+
+/// SPL Token 指令
+const TOKEN_TRANSFER: u8 = 3;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+/// Token-2022 TransferFeeExtension 及其 TransferCheckedWithFee 子指令
+const TOKEN_2022_TRANSFER_FEE_EXTENSION: u8 = 26;
+const TOKEN_2022_TRANSFER_CHECKED_WITH_FEE: u8 = 1;
+/// System Program 指令
+const SYSTEM_TRANSFER: u32 = 2;
+/// SOL精度
+const SOL_DECIMALS: u8 = 9;
+
+/// 内联指令的借用视图，屏蔽RPC和gRPC两种结构的差异
+struct RawInnerInstruction<'a> {
+    program_id_index: u32,
+    accounts: &'a [u8],
+    data: &'a [u8],
+    stack_height: Option<u32>,
+}
+
+impl<'a> From<&'a solana_transaction_status::InnerInstruction> for RawInnerInstruction<'a> {
+    fn from(instruction: &'a solana_transaction_status::InnerInstruction) -> Self {
+        Self {
+            program_id_index: instruction.instruction.program_id_index as u32,
+            accounts: &instruction.instruction.accounts,
+            data: &instruction.instruction.data,
+            stack_height: instruction.stack_height,
+        }
+    }
+}
+
+impl<'a> From<&'a yellowstone_grpc_proto::prelude::InnerInstruction> for RawInnerInstruction<'a> {
+    fn from(instruction: &'a yellowstone_grpc_proto::prelude::InnerInstruction) -> Self {
+        Self {
+            program_id_index: instruction.program_id_index,
+            accounts: &instruction.accounts,
+            data: &instruction.data,
+            stack_height: instruction.stack_height,
+        }
+    }
+}
+
+/// 解析事件 CPI 子树中的代币和SOL转账
+///
+/// `current_index` 为事件所在内联指令的下标，外层指令事件传 None
+pub fn parse_transfer_datas_from_next_instructions(
+    inner_instruction: &solana_transaction_status::InnerInstructions,
+    current_index: Option<usize>,
+    accounts: &[Pubkey],
+) -> Vec<TransferData> {
+    let instructions: Vec<RawInnerInstruction> =
+        inner_instruction.instructions.iter().map(RawInnerInstruction::from).collect();
+    parse_transfer_datas(&instructions, current_index, accounts)
+}
+
+/// 解析事件 CPI 子树中的代币和SOL转账（gRPC）
+pub fn parse_transfer_datas_from_next_grpc_instructions(
+    inner_instruction: &yellowstone_grpc_proto::prelude::InnerInstructions,
+    current_index: Option<usize>,
+    accounts: &[Pubkey],
+) -> Vec<TransferData> {
+    let instructions: Vec<RawInnerInstruction> =
+        inner_instruction.instructions.iter().map(RawInnerInstruction::from).collect();
+    parse_transfer_datas(&instructions, current_index, accounts)
+}
+
+fn parse_transfer_datas(
+    instructions: &[RawInnerInstruction],
+    current_index: Option<usize>,
+    accounts: &[Pubkey],
+) -> Vec<TransferData> {
+    let start = current_index.map_or(0, |index| index + 1);
+    // 内联指令事件的子树为其后 stack_height 更深的连续指令
+    let parent_height = current_index
+        .and_then(|index| instructions.get(index))
+        .map(|instruction| instruction.stack_height);
+
+    let mut transfer_datas = Vec::new();
+    for instruction in instructions.iter().skip(start) {
+        let Some(program_id) = accounts.get(instruction.program_id_index as usize).copied() else {
+            break;
+        };
+        match parent_height {
+            Some(Some(parent_height))
+                if instruction.stack_height.is_some_and(|height| height <= parent_height) =>
+            {
+                break
+            }
+            // 旧交易没有 stack_height，只取紧随其后的转账指令
+            Some(None) if !is_transfer_program(&program_id) => break,
+            _ => {}
+        }
+        if let Some(transfer_data) = parse_transfer_instruction(instruction, &program_id, accounts)
+        {
+            transfer_datas.push(transfer_data);
+        }
+    }
+    transfer_datas
+}
+
+fn is_transfer_program(program_id: &Pubkey) -> bool {
+    *program_id == TOKEN_PROGRAM_ID
+        || *program_id == TOKEN_2022_PROGRAM_ID
+        || *program_id == SYSTEM_PROGRAM_ID
+}
+
+fn parse_transfer_instruction(
+    instruction: &RawInnerInstruction,
+    program_id: &Pubkey,
+    accounts: &[Pubkey],
+) -> Option<TransferData> {
+    let data = instruction.data;
+    let get_pubkey = |i: usize| -> Option<Pubkey> {
+        instruction.accounts.get(i).and_then(|&index| accounts.get(index as usize)).copied()
+    };
+
+    if *program_id == SYSTEM_PROGRAM_ID {
+        if read_u32_le(data, 0)? != SYSTEM_TRANSFER {
+            return None;
+        }
+        return Some(TransferData {
+            token_program: *program_id,
+            source: get_pubkey(0)?,
+            destination: get_pubkey(1)?,
+            authority: None,
+            amount: read_u64_le(data, 4)?,
+            decimals: Some(SOL_DECIMALS),
+            mint: None,
+        });
+    }
+    if *program_id != TOKEN_PROGRAM_ID && *program_id != TOKEN_2022_PROGRAM_ID {
+        return None;
+    }
+
+    match *data.first()? {
+        TOKEN_TRANSFER => Some(TransferData {
+            token_program: *program_id,
+            source: get_pubkey(0)?,
+            destination: get_pubkey(1)?,
+            authority: get_pubkey(2),
+            amount: read_u64_le(data, 1)?,
+            decimals: None,
+            mint: None,
+        }),
+        TOKEN_TRANSFER_CHECKED => Some(TransferData {
+            token_program: *program_id,
+            source: get_pubkey(0)?,
+            destination: get_pubkey(2)?,
+            authority: get_pubkey(3),
+            amount: read_u64_le(data, 1)?,
+            decimals: Some(read_u8(data, 9)?),
+            mint: Some(get_pubkey(1)?),
+        }),
+        TOKEN_2022_TRANSFER_FEE_EXTENSION
+            if *program_id == TOKEN_2022_PROGRAM_ID
+                && read_u8(data, 1)? == TOKEN_2022_TRANSFER_CHECKED_WITH_FEE =>
+        {
+            Some(TransferData {
+                token_program: *program_id,
+                source: get_pubkey(0)?,
+                destination: get_pubkey(2)?,
+                authority: get_pubkey(3),
+                amount: read_u64_le(data, 2)?,
+                decimals: Some(read_u8(data, 10)?),
+                mint: Some(get_pubkey(1)?),
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yellowstone_grpc_proto::prelude::{InnerInstruction, InnerInstructions};
+
+    /// 账户表：0 Token 程序，1 Token-2022 程序，2 源账户，3 mint，4 目标账户，5 授权，6 其他程序
+    fn accounts() -> Vec<Pubkey> {
+        let mut accounts = vec![TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID];
+        accounts.extend((0..5).map(|_| Pubkey::new_unique()));
+        accounts
+    }
+
+    fn instruction(program_id_index: u32, data: Vec<u8>, stack_height: u32) -> InnerInstruction {
+        InnerInstruction {
+            program_id_index,
+            accounts: vec![2, 3, 4, 5],
+            data,
+            stack_height: Some(stack_height),
+        }
+    }
+
+    fn token_transfer(amount: u64, stack_height: u32) -> InnerInstruction {
+        let mut data = vec![TOKEN_TRANSFER];
+        data.extend_from_slice(&amount.to_le_bytes());
+        instruction(0, data, stack_height)
+    }
+
+    #[test]
+    fn inner_index_above_i8_range() {
+        let accounts = accounts();
+        let event_index = 200;
+        let mut instructions: Vec<InnerInstruction> =
+            (0..event_index).map(|i| token_transfer(i as u64 + 1, 3)).collect();
+        instructions.push(instruction(6, vec![0xff], 2));
+        instructions.push(token_transfer(1_000, 3));
+        instructions.push(token_transfer(2_000, 3));
+        // 回到事件所在层级，子树结束
+        instructions.push(instruction(6, vec![0xff], 2));
+        instructions.push(token_transfer(3_000, 3));
+        let inner_instructions = InnerInstructions { index: 0, instructions };
+
+        let transfers = parse_transfer_datas_from_next_grpc_instructions(
+            &inner_instructions,
+            Some(event_index),
+            &accounts,
+        );
+        let amounts: Vec<u64> = transfers.iter().map(|transfer| transfer.amount).collect();
+        assert_eq!(amounts, vec![1_000, 2_000]);
+
+        let transfers =
+            parse_transfer_datas_from_next_grpc_instructions(&inner_instructions, None, &accounts);
+        assert_eq!(transfers.len(), event_index + 3);
+    }
+
+    #[test]
+    fn transfer_checked_with_fee() {
+        let accounts = accounts();
+        let mut data = vec![TOKEN_2022_TRANSFER_FEE_EXTENSION, TOKEN_2022_TRANSFER_CHECKED_WITH_FEE];
+        data.extend_from_slice(&1_234_567u64.to_le_bytes());
+        data.push(6);
+        data.extend_from_slice(&890u64.to_le_bytes());
+        let inner_instructions =
+            InnerInstructions { index: 0, instructions: vec![instruction(1, data, 2)] };
+
+        let transfers =
+            parse_transfer_datas_from_next_grpc_instructions(&inner_instructions, None, &accounts);
+        assert_eq!(
+            transfers,
+            vec![TransferData {
+                token_program: TOKEN_2022_PROGRAM_ID,
+                source: accounts[2],
+                destination: accounts[4],
+                authority: Some(accounts[5]),
+                amount: 1_234_567,
+                decimals: Some(6),
+                mint: Some(accounts[3]),
+            }]
+        );
+    }
+}
@@ -2,8 +2,12 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use crossbeam_queue::ArrayQueue;
 use serde::{Deserialize, Serialize};
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
-use solana_transaction_status::EncodedTransactionWithStatusMeta;
 use std::{borrow::Cow, fmt, str::FromStr, sync::Arc};
+use yellowstone_grpc_proto::prelude::{
+    InnerInstructions, SubscribeUpdateTransactionInfo, TransactionStatusMeta,
+};
+
+use super::balance::{BalanceChanges, TokenAccountBalances};
 
 /// Transfer data for tracking token movements
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -25,11 +29,17 @@ use crate::{
             protocols::{
                 bonk::BonkTradeEvent,
                 pumpfun::PumpFunTradeEvent,
+                moonshot::MoonshotTradeEvent,
                 pumpswap::{PumpSwapBuyEvent, PumpSwapSellEvent},
                 raydium_amm_v4::RaydiumAmmV4SwapEvent,
                 raydium_clmm::{RaydiumClmmSwapEvent, RaydiumClmmSwapV2Event},
                 raydium_cpmm::RaydiumCpmmSwapEvent,
+                meteora_dlmm::MeteoraDlmmSwapEvent,
+                meteora_damm_v2::MeteoraDammV2SwapEvent,
+                meteora_dbc::MeteoraDbcSwapEvent,
+                orca_whirlpool::OrcaWhirlpoolSwapEvent,
             },
+            core::pool_mint_cache::get_pool_vault_mint,
             UnifiedEvent,
         },
     },
@@ -39,47 +49,85 @@ use crate::{
 const EVENT_METADATA_POOL_SIZE: usize = 1000;
 const TRANSFER_DATA_POOL_SIZE: usize = 2000;
 
-/// Wrapper for EncodedTransactionWithStatusMeta that implements required traits
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RawTransactionWrapper {
-    pub inner: EncodedTransactionWithStatusMeta,
+/// Shared handle to the raw gRPC transaction an event was parsed from.
+///
+/// Cloning only bumps a reference count; accessors borrow from the original
+/// protobuf message without re-encoding it.
+#[derive(Debug, Clone)]
+pub struct RawTransaction {
+    inner: Arc<SubscribeUpdateTransactionInfo>,
 }
 
-impl RawTransactionWrapper {
-    pub fn new(inner: EncodedTransactionWithStatusMeta) -> Self {
+impl RawTransaction {
+    pub fn new(inner: Arc<SubscribeUpdateTransactionInfo>) -> Self {
         Self { inner }
     }
-}
 
-impl PartialEq for RawTransactionWrapper {
-    fn eq(&self, other: &Self) -> bool {
-        // Compare based on serialized transaction data
-        match (serde_json::to_string(&self.inner), serde_json::to_string(&other.inner)) {
-            (Ok(a), Ok(b)) => a == b,
-            _ => false,
-        }
+    /// Underlying protobuf transaction
+    pub fn inner(&self) -> &SubscribeUpdateTransactionInfo {
+        &self.inner
+    }
+
+    /// Transaction signature
+    pub fn signature(&self) -> Signature {
+        Signature::try_from(self.inner.signature.as_slice()).unwrap_or_default()
+    }
+
+    /// Static account keys followed by loaded writable and readonly addresses
+    pub fn account_keys(&self) -> Vec<Pubkey> {
+        let static_keys = self
+            .inner
+            .transaction
+            .as_ref()
+            .and_then(|tx| tx.message.as_ref())
+            .map(|message| message.account_keys.as_slice())
+            .unwrap_or_default();
+        let loaded_keys = self
+            .meta()
+            .map(|meta| {
+                meta.loaded_writable_addresses.iter().chain(meta.loaded_readonly_addresses.iter())
+            })
+            .into_iter()
+            .flatten();
+        static_keys
+            .iter()
+            .chain(loaded_keys)
+            .filter_map(|key| Pubkey::try_from(key.as_slice()).ok())
+            .collect()
+    }
+
+    /// Transaction status meta
+    pub fn meta(&self) -> Option<&TransactionStatusMeta> {
+        self.inner.meta.as_ref()
+    }
+
+    /// Program log messages
+    pub fn log_messages(&self) -> &[String] {
+        self.meta().map(|meta| meta.log_messages.as_slice()).unwrap_or_default()
     }
-}
 
-impl Eq for RawTransactionWrapper {}
+    /// Inner instructions grouped by outer instruction index
+    pub fn inner_instructions(&self) -> &[InnerInstructions] {
+        self.meta().map(|meta| meta.inner_instructions.as_slice()).unwrap_or_default()
+    }
 
-impl BorshSerialize for RawTransactionWrapper {
-    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        let serialized = serde_json::to_string(&self.inner)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
-        BorshSerialize::serialize(&serialized, writer)
+    /// SOL and token balance changes of the given account
+    pub fn balance_changes(&self, owner: &Pubkey) -> Option<BalanceChanges> {
+        let meta = self.meta()?;
+        let accounts = self.account_keys();
+        let owner_index = accounts.iter().position(|account| account == owner)?;
+        BalanceChanges::from_grpc_meta(&accounts, owner_index, meta)
     }
 }
 
-impl BorshDeserialize for RawTransactionWrapper {
-    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-        let serialized = String::deserialize_reader(reader)?;
-        let inner = serde_json::from_str(&serialized)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
-        Ok(RawTransactionWrapper { inner })
+impl PartialEq for RawTransaction {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner) || self.inner == other.inner
     }
 }
 
+impl Eq for RawTransaction {}
+
 /// Event metadata object pool
 pub struct EventMetadataPool {
     pool: Arc<ArrayQueue<EventMetadata>>,
@@ -122,7 +170,17 @@ pub enum ProtocolType {
     RaydiumCpmm,
     RaydiumClmm,
     RaydiumAmmV4,
+    MeteoraDlmm,
+    MeteoraDammV2,
+    MeteoraDbc,
+    OrcaWhirlpool,
+    JupiterV6,
+    OpenBookV2,
+    Phoenix,
+    Moonshot,
     Common,
+    /// 运行时注册的自定义协议
+    Custom(String),
 }
 
 /// Event type enumeration
@@ -137,12 +195,31 @@ pub enum EventType {
     PumpSwapCreatePool,
     PumpSwapDeposit,
     PumpSwapWithdraw,
+    PumpSwapCollectCoinCreatorFee,
+    PumpSwapUpdateFeeConfig,
+    PumpSwapUpdateAdmin,
+    PumpSwapDisable,
+    PumpSwapClaimTokenIncentives,
+    PumpSwapInitUserVolumeAccumulator,
+    PumpSwapCloseUserVolumeAccumulator,
+    PumpSwapSyncUserVolumeAccumulator,
 
     // PumpFun events
     PumpFunCreateToken,
     PumpFunBuy,
     PumpFunSell,
     PumpFunMigrate,
+    PumpFunCreateTokenV2,
+    PumpFunBuyExactSolIn,
+    PumpFunCollectCreatorFee,
+    PumpFunSetParams,
+    PumpFunExtendAccount,
+    PumpFunClaimTokenIncentives,
+    PumpFunInitUserVolumeAccumulator,
+    PumpFunCloseUserVolumeAccumulator,
+    PumpFunSyncUserVolumeAccumulator,
+    PumpFunSetCreator,
+    PumpFunAdminSetCreator,
 
     // Bonk events
     BonkBuyExactIn,
@@ -153,6 +230,10 @@ pub enum EventType {
     BonkInitializeV2,
     BonkMigrateToAmm,
     BonkMigrateToCpswap,
+    BonkCreateVestingAccount,
+    BonkClaimVestedToken,
+    BonkClaimPlatformFee,
+    BonkClaimCreatorFee,
 
     // Raydium CPMM events
     RaydiumCpmmSwapBaseInput,
@@ -160,6 +241,9 @@ pub enum EventType {
     RaydiumCpmmDeposit,
     RaydiumCpmmInitialize,
     RaydiumCpmmWithdraw,
+    RaydiumCpmmCollectProtocolFee,
+    RaydiumCpmmCollectFundFee,
+    RaydiumCpmmUpdatePoolStatus,
 
     // Raydium CLMM events
     RaydiumClmmSwap,
@@ -170,6 +254,14 @@ pub enum EventType {
     RaydiumClmmCreatePool,
     RaydiumClmmOpenPositionWithToken22Nft,
     RaydiumClmmOpenPositionV2,
+    RaydiumClmmOpenPosition,
+    RaydiumClmmIncreaseLiquidity,
+    RaydiumClmmDecreaseLiquidity,
+    RaydiumClmmCollectRemainingRewards,
+    RaydiumClmmSwapRouterBaseIn,
+    RaydiumClmmInitializeReward,
+    RaydiumClmmSetRewardParams,
+    RaydiumClmmUpdateRewardInfos,
 
     // Raydium AMM V4 events
     RaydiumAmmV4SwapBaseIn,
@@ -179,27 +271,113 @@ pub enum EventType {
     RaydiumAmmV4Withdraw,
     RaydiumAmmV4WithdrawPnl,
 
+    // Meteora DLMM events
+    MeteoraDlmmSwap,
+    MeteoraDlmmSwapExactOut,
+    MeteoraDlmmAddLiquidity,
+    MeteoraDlmmRemoveLiquidity,
+    MeteoraDlmmInitializePosition,
+    MeteoraDlmmClosePosition,
+
+    // Meteora DAMM v2 events
+    MeteoraDammV2Swap,
+    MeteoraDammV2AddLiquidity,
+    MeteoraDammV2RemoveLiquidity,
+    MeteoraDammV2InitializePool,
+    MeteoraDammV2CreatePosition,
+    MeteoraDammV2ClaimPositionFee,
+
+    // Meteora DBC events
+    MeteoraDbcInitializePool,
+    MeteoraDbcSwap,
+    MeteoraDbcMigrateDammV1,
+    MeteoraDbcMigrateDammV2,
+
+    // Orca Whirlpool events
+    OrcaWhirlpoolSwap,
+    OrcaWhirlpoolSwapV2,
+    OrcaWhirlpoolTwoHopSwap,
+    OrcaWhirlpoolTwoHopSwapV2,
+    OrcaWhirlpoolIncreaseLiquidity,
+    OrcaWhirlpoolDecreaseLiquidity,
+    OrcaWhirlpoolOpenPosition,
+    OrcaWhirlpoolClosePosition,
+
+    // Jupiter v6 events
+    JupiterV6Route,
+    JupiterV6SharedAccountsRoute,
+    JupiterV6ExactOutRoute,
+    JupiterV6SharedAccountsExactOutRoute,
+    JupiterV6Swap,
+
+    // OpenBook v2 events
+    OpenBookV2PlaceOrder,
+    OpenBookV2PlaceTakeOrder,
+    OpenBookV2CancelOrder,
+    OpenBookV2CancelOrderByClientOrderId,
+
+    // Phoenix events
+    PhoenixSwap,
+    PhoenixPlaceLimitOrder,
+    PhoenixCancelOrders,
+    PhoenixMarketLog,
+
+    // Moonshot events
+    MoonshotTokenMint,
+    MoonshotBuy,
+    MoonshotSell,
+    MoonshotMigrateFunds,
+
     // Account events
     AccountRaydiumAmmV4AmmInfo,
     AccountPumpSwapGlobalConfig,
     AccountPumpSwapPool,
+    AccountPumpSwapFeeConfig,
+    AccountPumpSwapGlobalVolumeAccumulator,
+    AccountPumpSwapUserVolumeAccumulator,
     AccountBonkPoolState,
     AccountBonkGlobalConfig,
     AccountBonkPlatformConfig,
     AccountBonkVestingRecord,
     AccountPumpFunBondingCurve,
     AccountPumpFunGlobal,
+    AccountPumpFunGlobalVolumeAccumulator,
+    AccountPumpFunUserVolumeAccumulator,
+    AccountPumpFunFeeConfig,
     AccountRaydiumClmmAmmConfig,
     AccountRaydiumClmmPoolState,
     AccountRaydiumClmmTickArrayState,
+    AccountRaydiumClmmPersonalPositionState,
+    AccountRaydiumClmmProtocolPositionState,
+    AccountRaydiumClmmObservationState,
     AccountRaydiumCpmmAmmConfig,
     AccountRaydiumCpmmPoolState,
+    AccountMeteoraDlmmLbPair,
+    AccountMeteoraDlmmBinArray,
+    AccountMeteoraDammV2Pool,
+    AccountMeteoraDbcVirtualPool,
+    AccountMeteoraDbcPoolConfig,
+    AccountOrcaWhirlpool,
+    AccountOrcaWhirlpoolTickArray,
+    AccountOpenBookV2Market,
+    AccountOpenBookV2BookSide,
+    AccountOpenBookV2EventHeap,
+    AccountPhoenixMarket,
+    AccountMoonshotCurve,
 
     NonceAccount,
     TokenAccount,
 
+    // Custom events
+    /// 自定义协议事件，名称由注册方定义
+    Custom(String),
+
     // Common events
     BlockMeta,
+    /// 未能解析的原始指令
+    RawInstruction,
+    /// 协议程序升级
+    ProgramUpgrade,
     Unknown,
 }
 
@@ -207,21 +385,75 @@ pub const ACCOUNT_EVENT_TYPES: &[EventType] = &[
     EventType::AccountRaydiumAmmV4AmmInfo,
     EventType::AccountPumpSwapGlobalConfig,
     EventType::AccountPumpSwapPool,
+    EventType::AccountPumpSwapFeeConfig,
+    EventType::AccountPumpSwapGlobalVolumeAccumulator,
+    EventType::AccountPumpSwapUserVolumeAccumulator,
     EventType::AccountBonkPoolState,
     EventType::AccountBonkGlobalConfig,
     EventType::AccountBonkPlatformConfig,
     EventType::AccountBonkVestingRecord,
     EventType::AccountPumpFunBondingCurve,
     EventType::AccountPumpFunGlobal,
+    EventType::AccountPumpFunGlobalVolumeAccumulator,
+    EventType::AccountPumpFunUserVolumeAccumulator,
+    EventType::AccountPumpFunFeeConfig,
     EventType::AccountRaydiumClmmAmmConfig,
     EventType::AccountRaydiumClmmPoolState,
     EventType::AccountRaydiumClmmTickArrayState,
+    EventType::AccountRaydiumClmmPersonalPositionState,
+    EventType::AccountRaydiumClmmProtocolPositionState,
+    EventType::AccountRaydiumClmmObservationState,
     EventType::AccountRaydiumCpmmAmmConfig,
     EventType::AccountRaydiumCpmmPoolState,
+    EventType::AccountMeteoraDlmmLbPair,
+    EventType::AccountMeteoraDlmmBinArray,
+    EventType::AccountMeteoraDammV2Pool,
+    EventType::AccountMeteoraDbcVirtualPool,
+    EventType::AccountMeteoraDbcPoolConfig,
+    EventType::AccountOrcaWhirlpool,
+    EventType::AccountOrcaWhirlpoolTickArray,
+    EventType::AccountOpenBookV2Market,
+    EventType::AccountOpenBookV2BookSide,
+    EventType::AccountOpenBookV2EventHeap,
+    EventType::AccountPhoenixMarket,
+    EventType::AccountMoonshotCurve,
     EventType::TokenAccount,
     EventType::NonceAccount,
 ];
 pub const BLOCK_EVENT_TYPES: &[EventType] = &[EventType::BlockMeta];
+pub const TRADE_EVENT_TYPES: &[EventType] = &[
+    EventType::PumpSwapBuy,
+    EventType::PumpSwapSell,
+    EventType::PumpFunBuy,
+    EventType::PumpFunSell,
+    EventType::PumpFunBuyExactSolIn,
+    EventType::BonkBuyExactIn,
+    EventType::BonkBuyExactOut,
+    EventType::BonkSellExactIn,
+    EventType::BonkSellExactOut,
+    EventType::RaydiumCpmmSwapBaseInput,
+    EventType::RaydiumCpmmSwapBaseOutput,
+    EventType::RaydiumClmmSwap,
+    EventType::RaydiumClmmSwapV2,
+    EventType::RaydiumAmmV4SwapBaseIn,
+    EventType::RaydiumAmmV4SwapBaseOut,
+    EventType::MeteoraDlmmSwap,
+    EventType::MeteoraDlmmSwapExactOut,
+    EventType::MeteoraDammV2Swap,
+    EventType::MeteoraDbcSwap,
+    EventType::OrcaWhirlpoolSwap,
+    EventType::OrcaWhirlpoolSwapV2,
+    EventType::OrcaWhirlpoolTwoHopSwap,
+    EventType::OrcaWhirlpoolTwoHopSwapV2,
+    EventType::JupiterV6Route,
+    EventType::JupiterV6SharedAccountsRoute,
+    EventType::JupiterV6ExactOutRoute,
+    EventType::JupiterV6SharedAccountsExactOutRoute,
+    EventType::OpenBookV2PlaceTakeOrder,
+    EventType::PhoenixSwap,
+    EventType::MoonshotBuy,
+    EventType::MoonshotSell,
+];
 
 impl fmt::Display for EventType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -231,10 +463,41 @@ impl fmt::Display for EventType {
             EventType::PumpSwapCreatePool => write!(f, "PumpSwapCreatePool"),
             EventType::PumpSwapDeposit => write!(f, "PumpSwapDeposit"),
             EventType::PumpSwapWithdraw => write!(f, "PumpSwapWithdraw"),
+            EventType::PumpSwapCollectCoinCreatorFee => write!(f, "PumpSwapCollectCoinCreatorFee"),
+            EventType::PumpSwapUpdateFeeConfig => write!(f, "PumpSwapUpdateFeeConfig"),
+            EventType::PumpSwapUpdateAdmin => write!(f, "PumpSwapUpdateAdmin"),
+            EventType::PumpSwapDisable => write!(f, "PumpSwapDisable"),
+            EventType::PumpSwapClaimTokenIncentives => write!(f, "PumpSwapClaimTokenIncentives"),
+            EventType::PumpSwapInitUserVolumeAccumulator => {
+                write!(f, "PumpSwapInitUserVolumeAccumulator")
+            }
+            EventType::PumpSwapCloseUserVolumeAccumulator => {
+                write!(f, "PumpSwapCloseUserVolumeAccumulator")
+            }
+            EventType::PumpSwapSyncUserVolumeAccumulator => {
+                write!(f, "PumpSwapSyncUserVolumeAccumulator")
+            }
             EventType::PumpFunCreateToken => write!(f, "PumpFunCreateToken"),
             EventType::PumpFunBuy => write!(f, "PumpFunBuy"),
             EventType::PumpFunSell => write!(f, "PumpFunSell"),
             EventType::PumpFunMigrate => write!(f, "PumpFunMigrate"),
+            EventType::PumpFunCreateTokenV2 => write!(f, "PumpFunCreateTokenV2"),
+            EventType::PumpFunBuyExactSolIn => write!(f, "PumpFunBuyExactSolIn"),
+            EventType::PumpFunCollectCreatorFee => write!(f, "PumpFunCollectCreatorFee"),
+            EventType::PumpFunSetParams => write!(f, "PumpFunSetParams"),
+            EventType::PumpFunExtendAccount => write!(f, "PumpFunExtendAccount"),
+            EventType::PumpFunClaimTokenIncentives => write!(f, "PumpFunClaimTokenIncentives"),
+            EventType::PumpFunInitUserVolumeAccumulator => {
+                write!(f, "PumpFunInitUserVolumeAccumulator")
+            }
+            EventType::PumpFunCloseUserVolumeAccumulator => {
+                write!(f, "PumpFunCloseUserVolumeAccumulator")
+            }
+            EventType::PumpFunSyncUserVolumeAccumulator => {
+                write!(f, "PumpFunSyncUserVolumeAccumulator")
+            }
+            EventType::PumpFunSetCreator => write!(f, "PumpFunSetCreator"),
+            EventType::PumpFunAdminSetCreator => write!(f, "PumpFunAdminSetCreator"),
             EventType::BonkBuyExactIn => write!(f, "BonkBuyExactIn"),
             EventType::BonkBuyExactOut => write!(f, "BonkBuyExactOut"),
             EventType::BonkSellExactIn => write!(f, "BonkSellExactIn"),
@@ -243,11 +506,18 @@ impl fmt::Display for EventType {
             EventType::BonkInitializeV2 => write!(f, "BonkInitializeV2"),
             EventType::BonkMigrateToAmm => write!(f, "BonkMigrateToAmm"),
             EventType::BonkMigrateToCpswap => write!(f, "BonkMigrateToCpswap"),
+            EventType::BonkCreateVestingAccount => write!(f, "BonkCreateVestingAccount"),
+            EventType::BonkClaimVestedToken => write!(f, "BonkClaimVestedToken"),
+            EventType::BonkClaimPlatformFee => write!(f, "BonkClaimPlatformFee"),
+            EventType::BonkClaimCreatorFee => write!(f, "BonkClaimCreatorFee"),
             EventType::RaydiumCpmmSwapBaseInput => write!(f, "RaydiumCpmmSwapBaseInput"),
             EventType::RaydiumCpmmSwapBaseOutput => write!(f, "RaydiumCpmmSwapBaseOutput"),
             EventType::RaydiumCpmmDeposit => write!(f, "RaydiumCpmmDeposit"),
             EventType::RaydiumCpmmInitialize => write!(f, "RaydiumCpmmInitialize"),
             EventType::RaydiumCpmmWithdraw => write!(f, "RaydiumCpmmWithdraw"),
+            EventType::RaydiumCpmmCollectProtocolFee => write!(f, "RaydiumCpmmCollectProtocolFee"),
+            EventType::RaydiumCpmmCollectFundFee => write!(f, "RaydiumCpmmCollectFundFee"),
+            EventType::RaydiumCpmmUpdatePoolStatus => write!(f, "RaydiumCpmmUpdatePoolStatus"),
             EventType::RaydiumClmmSwap => write!(f, "RaydiumClmmSwap"),
             EventType::RaydiumClmmSwapV2 => write!(f, "RaydiumClmmSwapV2"),
             EventType::RaydiumClmmClosePosition => write!(f, "RaydiumClmmClosePosition"),
@@ -262,31 +532,134 @@ impl fmt::Display for EventType {
                 write!(f, "RaydiumClmmOpenPositionWithToken22Nft")
             }
             EventType::RaydiumClmmOpenPositionV2 => write!(f, "RaydiumClmmOpenPositionV2"),
+            EventType::RaydiumClmmOpenPosition => write!(f, "RaydiumClmmOpenPosition"),
+            EventType::RaydiumClmmIncreaseLiquidity => write!(f, "RaydiumClmmIncreaseLiquidity"),
+            EventType::RaydiumClmmDecreaseLiquidity => write!(f, "RaydiumClmmDecreaseLiquidity"),
+            EventType::RaydiumClmmCollectRemainingRewards => {
+                write!(f, "RaydiumClmmCollectRemainingRewards")
+            }
+            EventType::RaydiumClmmSwapRouterBaseIn => write!(f, "RaydiumClmmSwapRouterBaseIn"),
+            EventType::RaydiumClmmInitializeReward => write!(f, "RaydiumClmmInitializeReward"),
+            EventType::RaydiumClmmSetRewardParams => write!(f, "RaydiumClmmSetRewardParams"),
+            EventType::RaydiumClmmUpdateRewardInfos => write!(f, "RaydiumClmmUpdateRewardInfos"),
             EventType::RaydiumAmmV4SwapBaseIn => write!(f, "RaydiumAmmV4SwapBaseIn"),
             EventType::RaydiumAmmV4SwapBaseOut => write!(f, "RaydiumAmmV4SwapBaseOut"),
             EventType::RaydiumAmmV4Deposit => write!(f, "RaydiumAmmV4Deposit"),
             EventType::RaydiumAmmV4Initialize2 => write!(f, "RaydiumAmmV4Initialize2"),
             EventType::RaydiumAmmV4Withdraw => write!(f, "RaydiumAmmV4Withdraw"),
             EventType::RaydiumAmmV4WithdrawPnl => write!(f, "RaydiumAmmV4WithdrawPnl"),
+            EventType::MeteoraDlmmSwap => write!(f, "MeteoraDlmmSwap"),
+            EventType::MeteoraDlmmSwapExactOut => write!(f, "MeteoraDlmmSwapExactOut"),
+            EventType::MeteoraDlmmAddLiquidity => write!(f, "MeteoraDlmmAddLiquidity"),
+            EventType::MeteoraDlmmRemoveLiquidity => write!(f, "MeteoraDlmmRemoveLiquidity"),
+            EventType::MeteoraDlmmInitializePosition => {
+                write!(f, "MeteoraDlmmInitializePosition")
+            }
+            EventType::MeteoraDlmmClosePosition => write!(f, "MeteoraDlmmClosePosition"),
+            EventType::MeteoraDammV2Swap => write!(f, "MeteoraDammV2Swap"),
+            EventType::MeteoraDammV2AddLiquidity => write!(f, "MeteoraDammV2AddLiquidity"),
+            EventType::MeteoraDammV2RemoveLiquidity => write!(f, "MeteoraDammV2RemoveLiquidity"),
+            EventType::MeteoraDammV2InitializePool => write!(f, "MeteoraDammV2InitializePool"),
+            EventType::MeteoraDammV2CreatePosition => write!(f, "MeteoraDammV2CreatePosition"),
+            EventType::MeteoraDammV2ClaimPositionFee => {
+                write!(f, "MeteoraDammV2ClaimPositionFee")
+            }
+            EventType::MeteoraDbcInitializePool => write!(f, "MeteoraDbcInitializePool"),
+            EventType::MeteoraDbcSwap => write!(f, "MeteoraDbcSwap"),
+            EventType::MeteoraDbcMigrateDammV1 => write!(f, "MeteoraDbcMigrateDammV1"),
+            EventType::MeteoraDbcMigrateDammV2 => write!(f, "MeteoraDbcMigrateDammV2"),
+            EventType::OrcaWhirlpoolSwap => write!(f, "OrcaWhirlpoolSwap"),
+            EventType::OrcaWhirlpoolSwapV2 => write!(f, "OrcaWhirlpoolSwapV2"),
+            EventType::OrcaWhirlpoolTwoHopSwap => write!(f, "OrcaWhirlpoolTwoHopSwap"),
+            EventType::OrcaWhirlpoolTwoHopSwapV2 => write!(f, "OrcaWhirlpoolTwoHopSwapV2"),
+            EventType::OrcaWhirlpoolIncreaseLiquidity => {
+                write!(f, "OrcaWhirlpoolIncreaseLiquidity")
+            }
+            EventType::OrcaWhirlpoolDecreaseLiquidity => {
+                write!(f, "OrcaWhirlpoolDecreaseLiquidity")
+            }
+            EventType::OrcaWhirlpoolOpenPosition => write!(f, "OrcaWhirlpoolOpenPosition"),
+            EventType::OrcaWhirlpoolClosePosition => write!(f, "OrcaWhirlpoolClosePosition"),
+            EventType::JupiterV6Route => write!(f, "JupiterV6Route"),
+            EventType::JupiterV6SharedAccountsRoute => write!(f, "JupiterV6SharedAccountsRoute"),
+            EventType::JupiterV6ExactOutRoute => write!(f, "JupiterV6ExactOutRoute"),
+            EventType::JupiterV6SharedAccountsExactOutRoute => {
+                write!(f, "JupiterV6SharedAccountsExactOutRoute")
+            }
+            EventType::JupiterV6Swap => write!(f, "JupiterV6Swap"),
+            EventType::OpenBookV2PlaceOrder => write!(f, "OpenBookV2PlaceOrder"),
+            EventType::OpenBookV2PlaceTakeOrder => write!(f, "OpenBookV2PlaceTakeOrder"),
+            EventType::OpenBookV2CancelOrder => write!(f, "OpenBookV2CancelOrder"),
+            EventType::OpenBookV2CancelOrderByClientOrderId => {
+                write!(f, "OpenBookV2CancelOrderByClientOrderId")
+            }
+            EventType::PhoenixSwap => write!(f, "PhoenixSwap"),
+            EventType::PhoenixPlaceLimitOrder => write!(f, "PhoenixPlaceLimitOrder"),
+            EventType::PhoenixCancelOrders => write!(f, "PhoenixCancelOrders"),
+            EventType::PhoenixMarketLog => write!(f, "PhoenixMarketLog"),
+            EventType::MoonshotTokenMint => write!(f, "MoonshotTokenMint"),
+            EventType::MoonshotBuy => write!(f, "MoonshotBuy"),
+            EventType::MoonshotSell => write!(f, "MoonshotSell"),
+            EventType::MoonshotMigrateFunds => write!(f, "MoonshotMigrateFunds"),
             EventType::AccountRaydiumAmmV4AmmInfo => write!(f, "AccountRaydiumAmmV4AmmInfo"),
             EventType::AccountPumpSwapGlobalConfig => write!(f, "AccountPumpSwapGlobalConfig"),
             EventType::AccountPumpSwapPool => write!(f, "AccountPumpSwapPool"),
+            EventType::AccountPumpSwapFeeConfig => write!(f, "AccountPumpSwapFeeConfig"),
+            EventType::AccountPumpSwapGlobalVolumeAccumulator => {
+                write!(f, "AccountPumpSwapGlobalVolumeAccumulator")
+            }
+            EventType::AccountPumpSwapUserVolumeAccumulator => {
+                write!(f, "AccountPumpSwapUserVolumeAccumulator")
+            }
             EventType::AccountBonkPoolState => write!(f, "AccountBonkPoolState"),
             EventType::AccountBonkGlobalConfig => write!(f, "AccountBonkGlobalConfig"),
             EventType::AccountBonkPlatformConfig => write!(f, "AccountBonkPlatformConfig"),
             EventType::AccountBonkVestingRecord => write!(f, "AccountBonkVestingRecord"),
             EventType::AccountPumpFunBondingCurve => write!(f, "AccountPumpFunBondingCurve"),
             EventType::AccountPumpFunGlobal => write!(f, "AccountPumpFunGlobal"),
+            EventType::AccountPumpFunGlobalVolumeAccumulator => {
+                write!(f, "AccountPumpFunGlobalVolumeAccumulator")
+            }
+            EventType::AccountPumpFunUserVolumeAccumulator => {
+                write!(f, "AccountPumpFunUserVolumeAccumulator")
+            }
+            EventType::AccountPumpFunFeeConfig => write!(f, "AccountPumpFunFeeConfig"),
             EventType::AccountRaydiumClmmAmmConfig => write!(f, "AccountRaydiumClmmAmmConfig"),
             EventType::AccountRaydiumClmmPoolState => write!(f, "AccountRaydiumClmmPoolState"),
             EventType::AccountRaydiumClmmTickArrayState => {
                 write!(f, "AccountRaydiumClmmTickArrayState")
             }
+            EventType::AccountRaydiumClmmPersonalPositionState => {
+                write!(f, "AccountRaydiumClmmPersonalPositionState")
+            }
+            EventType::AccountRaydiumClmmProtocolPositionState => {
+                write!(f, "AccountRaydiumClmmProtocolPositionState")
+            }
+            EventType::AccountRaydiumClmmObservationState => {
+                write!(f, "AccountRaydiumClmmObservationState")
+            }
             EventType::AccountRaydiumCpmmAmmConfig => write!(f, "AccountRaydiumCpmmAmmConfig"),
             EventType::AccountRaydiumCpmmPoolState => write!(f, "AccountRaydiumCpmmPoolState"),
+            EventType::AccountMeteoraDlmmLbPair => write!(f, "AccountMeteoraDlmmLbPair"),
+            EventType::AccountMeteoraDlmmBinArray => write!(f, "AccountMeteoraDlmmBinArray"),
+            EventType::AccountMeteoraDammV2Pool => write!(f, "AccountMeteoraDammV2Pool"),
+            EventType::AccountMeteoraDbcVirtualPool => write!(f, "AccountMeteoraDbcVirtualPool"),
+            EventType::AccountMeteoraDbcPoolConfig => write!(f, "AccountMeteoraDbcPoolConfig"),
+            EventType::AccountOrcaWhirlpool => write!(f, "AccountOrcaWhirlpool"),
+            EventType::AccountOrcaWhirlpoolTickArray => {
+                write!(f, "AccountOrcaWhirlpoolTickArray")
+            }
+            EventType::AccountOpenBookV2Market => write!(f, "AccountOpenBookV2Market"),
+            EventType::AccountOpenBookV2BookSide => write!(f, "AccountOpenBookV2BookSide"),
+            EventType::AccountOpenBookV2EventHeap => write!(f, "AccountOpenBookV2EventHeap"),
+            EventType::AccountPhoenixMarket => write!(f, "AccountPhoenixMarket"),
+            EventType::AccountMoonshotCurve => write!(f, "AccountMoonshotCurve"),
             EventType::TokenAccount => write!(f, "TokenAccount"),
             EventType::NonceAccount => write!(f, "NonceAccount"),
+            EventType::Custom(name) => write!(f, "{name}"),
             EventType::BlockMeta => write!(f, "BlockMeta"),
+            EventType::RawInstruction => write!(f, "RawInstruction"),
+            EventType::ProgramUpgrade => write!(f, "ProgramUpgrade"),
             EventType::Unknown => write!(f, "Unknown"),
         }
     }
@@ -362,8 +735,18 @@ pub struct EventMetadata {
     pub swap_data: Option<SwapData>,
     pub outer_index: i64,
     pub inner_index: Option<i64>,
-    pub raw_transaction: Option<RawTransactionWrapper>,
+    /// 原始交易的共享句柄（不参与序列化）
+    #[serde(skip)]
+    pub raw_transaction: Option<RawTransaction>,
     pub transfer_datas: Vec<TransferData>,
+    /// 交易签名者的余额变化（仅交易类事件）
+    pub balance_changes: Option<BalanceChanges>,
+    /// 交易中未能解析的地址查找表（对应账户以Pubkey::default()填充）
+    pub unresolved_lookup_tables: Vec<Pubkey>,
+    /// 指令调用栈高度，顶层指令为 1（无法确定时为 None）
+    pub stack_height: Option<u32>,
+    /// 调用链：从顶层指令程序到直接调用者，顶层指令为空
+    pub invoking_programs: Vec<Pubkey>,
     pub id: String,
 }
 
@@ -398,10 +781,29 @@ impl EventMetadata {
             transaction_index,
             raw_transaction: None,
             transfer_datas: Vec::new(),
-            id: format!("{}-{}-{}", signature, outer_index, inner_index.unwrap_or(0)),
+            balance_changes: None,
+            unresolved_lookup_tables: Vec::new(),
+            stack_height: inner_index.is_none().then_some(1),
+            invoking_programs: Vec::new(),
+            id: Self::event_id(&signature, outer_index, inner_index),
         }
     }
 
+    /// 事件ID：`签名-外层序号-内联序号`，外层指令的内联序号记为 0
+    pub fn event_id(signature: &Signature, outer_index: i64, inner_index: Option<i64>) -> String {
+        format!("{}-{}-{}", signature, outer_index, inner_index.unwrap_or(0))
+    }
+
+    /// 发起本次调用的顶层程序（路由），顶层指令为 None
+    pub fn router_program(&self) -> Option<&Pubkey> {
+        self.invoking_programs.first()
+    }
+
+    /// 直接调用者程序，顶层指令为 None
+    pub fn direct_invoker(&self) -> Option<&Pubkey> {
+        self.invoking_programs.last()
+    }
+
     pub fn set_swap_data(&mut self, swap_data: SwapData) {
         self.swap_data = Some(swap_data);
     }
@@ -459,6 +861,10 @@ pub fn parse_swap_data_from_next_instructions(
             swap_data.from_mint = if e.is_buy { *SOL_MINT } else { e.mint };
             swap_data.to_mint   = if e.is_buy { e.mint } else { *SOL_MINT };
         },
+        MoonshotTradeEvent => |e: MoonshotTradeEvent| {
+            swap_data.from_mint = if e.is_buy { *SOL_MINT } else { e.mint };
+            swap_data.to_mint   = if e.is_buy { e.mint } else { *SOL_MINT };
+        },
         PumpSwapBuyEvent => |e: PumpSwapBuyEvent| {
             swap_data.from_mint = e.quote_mint;
             swap_data.to_mint   = e.base_mint;
@@ -501,6 +907,47 @@ pub fn parse_swap_data_from_next_instructions(
             from_vault = Some(e.pool_pc_token_account);
             to_vault   = Some(e.pool_coin_token_account);
         },
+        MeteoraDlmmSwapEvent => |e: MeteoraDlmmSwapEvent| {
+            user = Some(e.user);
+            from_mint = Some(e.token_x_mint);
+            to_mint   = Some(e.token_y_mint);
+            user_from_token = Some(e.user_token_in);
+            user_to_token   = Some(e.user_token_out);
+            from_vault = Some(e.reserve_x);
+            to_vault   = Some(e.reserve_y);
+        },
+        MeteoraDammV2SwapEvent => |e: MeteoraDammV2SwapEvent| {
+            user = Some(e.payer);
+            from_mint = Some(e.token_a_mint);
+            to_mint   = Some(e.token_b_mint);
+            user_from_token = Some(e.input_token_account);
+            user_to_token   = Some(e.output_token_account);
+            from_vault = Some(e.token_a_vault);
+            to_vault   = Some(e.token_b_vault);
+        },
+        MeteoraDbcSwapEvent => |e: MeteoraDbcSwapEvent| {
+            user = Some(e.payer);
+            from_mint = Some(e.base_mint);
+            to_mint   = Some(e.quote_mint);
+            user_from_token = Some(e.input_token_account);
+            user_to_token   = Some(e.output_token_account);
+            from_vault = Some(e.base_vault);
+            to_vault   = Some(e.quote_vault);
+        },
+        OrcaWhirlpoolSwapEvent => |e: OrcaWhirlpoolSwapEvent| {
+            user = Some(e.token_authority);
+            from_mint = Some(e.token_mint_a);
+            to_mint   = Some(e.token_mint_b);
+            if e.a_to_b {
+                user_from_token = Some(e.token_owner_account_a);
+                user_to_token   = Some(e.token_owner_account_b);
+            } else {
+                user_from_token = Some(e.token_owner_account_b);
+                user_to_token   = Some(e.token_owner_account_a);
+            }
+            from_vault = Some(e.token_vault_a);
+            to_vault   = Some(e.token_vault_b);
+        },
     });
 
     let user_to_token = user_to_token.unwrap_or_default();
@@ -559,11 +1006,11 @@ pub fn parse_swap_data_from_next_instructions(
                 swap_data.to_amount = amount;
             }
             (s, d) if s == user_from_token && d == to_vault => {
-                swap_data.from_mint = from_mint;
+                swap_data.from_mint = to_mint;
                 swap_data.from_amount = amount;
             }
             (s, d) if s == from_vault && d == user_to_token => {
-                swap_data.to_mint = to_mint;
+                swap_data.to_mint = from_mint;
                 swap_data.to_amount = amount;
             }
             _ => {}
@@ -626,6 +1073,10 @@ pub fn parse_swap_data_from_next_grpc_instructions(
             swap_data.from_mint = if e.is_buy { *SOL_MINT } else { e.mint };
             swap_data.to_mint   = if e.is_buy { e.mint } else { *SOL_MINT };
         },
+        MoonshotTradeEvent => |e: MoonshotTradeEvent| {
+            swap_data.from_mint = if e.is_buy { *SOL_MINT } else { e.mint };
+            swap_data.to_mint   = if e.is_buy { e.mint } else { *SOL_MINT };
+        },
         PumpSwapBuyEvent => |e: PumpSwapBuyEvent| {
             swap_data.from_mint = e.quote_mint;
             swap_data.to_mint   = e.base_mint;
@@ -668,6 +1119,47 @@ pub fn parse_swap_data_from_next_grpc_instructions(
             from_vault = Some(e.pool_pc_token_account);
             to_vault   = Some(e.pool_coin_token_account);
         },
+        MeteoraDlmmSwapEvent => |e: MeteoraDlmmSwapEvent| {
+            user = Some(e.user);
+            from_mint = Some(e.token_x_mint);
+            to_mint   = Some(e.token_y_mint);
+            user_from_token = Some(e.user_token_in);
+            user_to_token   = Some(e.user_token_out);
+            from_vault = Some(e.reserve_x);
+            to_vault   = Some(e.reserve_y);
+        },
+        MeteoraDammV2SwapEvent => |e: MeteoraDammV2SwapEvent| {
+            user = Some(e.payer);
+            from_mint = Some(e.token_a_mint);
+            to_mint   = Some(e.token_b_mint);
+            user_from_token = Some(e.input_token_account);
+            user_to_token   = Some(e.output_token_account);
+            from_vault = Some(e.token_a_vault);
+            to_vault   = Some(e.token_b_vault);
+        },
+        MeteoraDbcSwapEvent => |e: MeteoraDbcSwapEvent| {
+            user = Some(e.payer);
+            from_mint = Some(e.base_mint);
+            to_mint   = Some(e.quote_mint);
+            user_from_token = Some(e.input_token_account);
+            user_to_token   = Some(e.output_token_account);
+            from_vault = Some(e.base_vault);
+            to_vault   = Some(e.quote_vault);
+        },
+        OrcaWhirlpoolSwapEvent => |e: OrcaWhirlpoolSwapEvent| {
+            user = Some(e.token_authority);
+            from_mint = Some(e.token_mint_a);
+            to_mint   = Some(e.token_mint_b);
+            if e.a_to_b {
+                user_from_token = Some(e.token_owner_account_a);
+                user_to_token   = Some(e.token_owner_account_b);
+            } else {
+                user_from_token = Some(e.token_owner_account_b);
+                user_to_token   = Some(e.token_owner_account_a);
+            }
+            from_vault = Some(e.token_vault_a);
+            to_vault   = Some(e.token_vault_b);
+        },
     });
 
     let user_to_token = user_to_token.unwrap_or_default();
@@ -726,11 +1218,11 @@ pub fn parse_swap_data_from_next_grpc_instructions(
                 swap_data.to_amount = amount;
             }
             (s, d) if s == user_from_token && d == to_vault => {
-                swap_data.from_mint = from_mint;
+                swap_data.from_mint = to_mint;
                 swap_data.from_amount = amount;
             }
             (s, d) if s == from_vault && d == user_to_token => {
-                swap_data.to_mint = to_mint;
+                swap_data.to_mint = from_mint;
                 swap_data.to_amount = amount;
             }
             _ => {}
@@ -753,6 +1245,103 @@ pub fn parse_swap_data_from_next_grpc_instructions(
         None
     }
 }
+/// Whether the event is a Raydium CLMM v1 / AMM v4 swap whose mints are still unknown
+pub fn swap_mints_unresolved(event: &dyn UnifiedEvent) -> bool {
+    let any = event.as_any();
+    if !any.is::<RaydiumClmmSwapEvent>()
+        && !any.is::<RaydiumAmmV4SwapEvent>()
+        && !any.is::<OrcaWhirlpoolSwapEvent>()
+    {
+        return false;
+    }
+    match &event.metadata().swap_data {
+        Some(swap_data) => {
+            swap_data.from_mint == Pubkey::default() || swap_data.to_mint == Pubkey::default()
+        }
+        None => true,
+    }
+}
+
+/// Resolve missing mints of Raydium CLMM v1 / AMM v4 / Orca Whirlpool v1 swaps
+///
+/// Vault mints come from the transaction's token balances, falling back to the
+/// pool mint cache fed by `PoolState`/`AmmInfo` account events. Returns true if
+/// the swap data was completed.
+pub fn resolve_swap_mints(
+    event: &mut dyn UnifiedEvent,
+    token_balances: Option<&TokenAccountBalances>,
+) -> bool {
+    let vault_mint = |vault: &Pubkey| {
+        token_balances.and_then(|balances| balances.mint(vault)).or_else(|| get_pool_vault_mint(vault))
+    };
+    let vault_change = |vault: &Pubkey| {
+        token_balances.and_then(|balances| balances.get(vault)).map(|b| b.change()).unwrap_or(0)
+    };
+
+    let (input_vault, output_vault) =
+        if let Some(e) = event.as_any().downcast_ref::<RaydiumClmmSwapEvent>() {
+            (e.input_vault, e.output_vault)
+        } else if let Some(e) = event.as_any().downcast_ref::<RaydiumAmmV4SwapEvent>() {
+            let coin_vault = e.pool_coin_token_account;
+            let pc_vault = e.pool_pc_token_account;
+            // 优先使用 ray_log 中的方向，其次是用户转入的 vault，最后看 vault 余额的增减
+            let input_vault = e
+                .ray_log_decoded
+                .then_some(if e.coin_to_pc { coin_vault } else { pc_vault })
+                .or_else(|| {
+                    e.metadata
+                        .transfer_datas
+                        .iter()
+                        .find(|transfer| {
+                            transfer.source == e.user_source_token_account
+                                && (transfer.destination == coin_vault
+                                    || transfer.destination == pc_vault)
+                        })
+                        .map(|transfer| transfer.destination)
+                })
+                .or_else(|| {
+                    let (coin_change, pc_change) = (vault_change(&coin_vault), vault_change(&pc_vault));
+                    if coin_change > 0 && pc_change < 0 {
+                        Some(coin_vault)
+                    } else if pc_change > 0 && coin_change < 0 {
+                        Some(pc_vault)
+                    } else {
+                        None
+                    }
+                });
+            match input_vault {
+                Some(vault) if vault == coin_vault => (coin_vault, pc_vault),
+                Some(_) => (pc_vault, coin_vault),
+                None => return false,
+            }
+        } else if let Some(e) = event.as_any().downcast_ref::<OrcaWhirlpoolSwapEvent>() {
+            if e.a_to_b {
+                (e.token_vault_a, e.token_vault_b)
+            } else {
+                (e.token_vault_b, e.token_vault_a)
+            }
+        } else {
+            return false;
+        };
+
+    let (Some(from_mint), Some(to_mint)) = (vault_mint(&input_vault), vault_mint(&output_vault))
+    else {
+        return false;
+    };
+    let mut swap_data = event.metadata().swap_data.clone().unwrap_or_default();
+    swap_data.from_mint = from_mint;
+    swap_data.to_mint = to_mint;
+    if swap_data.from_amount == 0 {
+        swap_data.from_amount = vault_change(&input_vault).max(0) as u64;
+    }
+    if swap_data.to_amount == 0 {
+        swap_data.to_amount = (-vault_change(&output_vault)).max(0) as u64;
+    }
+    swap_data.description = None;
+    event.set_swap_data(swap_data);
+    true
+}
+
 /// Extension trait for Signature to add is_empty method
 pub trait SignatureExt {
     fn is_empty(&self) -> bool;
@@ -763,3 +1352,80 @@ impl SignatureExt for solana_sdk::signature::Signature {
         self.as_ref() == [0u8; 64]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::core::pool_mint_cache::add_pool_vault_mint;
+    use yellowstone_grpc_proto::prelude::{TokenBalance, UiTokenAmount};
+
+    fn amm_v4_swap() -> RaydiumAmmV4SwapEvent {
+        RaydiumAmmV4SwapEvent {
+            pool_coin_token_account: Pubkey::new_unique(),
+            pool_pc_token_account: Pubkey::new_unique(),
+            user_source_token_account: Pubkey::new_unique(),
+            ..Default::default()
+        }
+    }
+
+    fn token_balance(index: u32, mint: &Pubkey, amount: u64) -> TokenBalance {
+        TokenBalance {
+            account_index: index,
+            mint: mint.to_string(),
+            ui_token_amount: Some(UiTokenAmount {
+                decimals: 6,
+                amount: amount.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn swap_mints_from_token_balances() {
+        let mut event = amm_v4_swap();
+        let (coin_mint, pc_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let accounts = [event.pool_coin_token_account, event.pool_pc_token_account];
+        // coin vault 增加、pc vault 减少，推断为 coin -> pc
+        let meta = TransactionStatusMeta {
+            pre_token_balances: vec![
+                token_balance(0, &coin_mint, 1_000),
+                token_balance(1, &pc_mint, 5_000),
+            ],
+            post_token_balances: vec![
+                token_balance(0, &coin_mint, 1_100),
+                token_balance(1, &pc_mint, 4_750),
+            ],
+            ..Default::default()
+        };
+        let token_balances = TokenAccountBalances::from_grpc_meta(&accounts, &meta);
+
+        assert!(resolve_swap_mints(&mut event, Some(&token_balances)));
+        let swap_data = event.metadata.swap_data.unwrap();
+        assert_eq!(swap_data.from_mint, coin_mint);
+        assert_eq!(swap_data.to_mint, pc_mint);
+        assert_eq!(swap_data.from_amount, 100);
+        assert_eq!(swap_data.to_amount, 250);
+    }
+
+    #[test]
+    fn swap_mints_from_pool_cache() {
+        let mut event = amm_v4_swap();
+        let (coin_mint, pc_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(!resolve_swap_mints(&mut event, None));
+
+        add_pool_vault_mint(event.pool_coin_token_account, coin_mint);
+        add_pool_vault_mint(event.pool_pc_token_account, pc_mint);
+        // 没有余额时由用户转入的 vault 推断方向
+        event.metadata.transfer_datas = vec![TransferData {
+            source: event.user_source_token_account,
+            destination: event.pool_pc_token_account,
+            amount: 300,
+            ..Default::default()
+        }];
+        assert!(resolve_swap_mints(&mut event, None));
+        let swap_data = event.metadata.swap_data.unwrap();
+        assert_eq!(swap_data.from_mint, pc_mint);
+        assert_eq!(swap_data.to_mint, coin_mint);
+    }
+}
@@ -1,5 +1,9 @@
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use solana_sdk::pubkey::Pubkey;
+
 /// 获取当前时间戳
 pub fn current_timestamp() -> i64 {
     SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards").as_secs() as i64
@@ -13,9 +17,33 @@ pub fn extract_discriminator(length: usize, data: &[u8]) -> Option<(&[u8], &[u8]
     Some((&data[..length], &data[length..]))
 }
 
+/// 按布局版本解码事件日志
+///
+/// `layout_sizes` 为各版本的大小（升序），按长度匹配版本：旧版本缺失的尾部字段以零值填充，
+/// 超出最新版本的尾部字段被忽略。返回 (解码结果, 布局版本)，版本从 1 开始，
+/// 长于最新版本时为 `layout_sizes.len() + 1`
+pub fn decode_versioned_layout<T: borsh::BorshDeserialize>(
+    data: &[u8],
+    layout_sizes: &[usize],
+) -> Option<(T, u8)> {
+    let version = layout_sizes.iter().rposition(|size| data.len() >= *size)?;
+    let latest = *layout_sizes.last()?;
+    let value = if data.len() >= latest {
+        borsh::from_slice(&data[..latest]).ok()?
+    } else {
+        let mut padded = data[..layout_sizes[version]].to_vec();
+        padded.resize(latest, 0);
+        borsh::from_slice(&padded).ok()?
+    };
+    let version = if data.len() > latest { layout_sizes.len() + 1 } else { version + 1 };
+    Some((value, version as u8))
+}
+
+/// Anchor `emit!` 事件日志前缀
+pub const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
 /// 从日志中提取程序数据
 pub fn extract_program_data(log: &str) -> Option<&str> {
-    const PROGRAM_DATA_PREFIX: &str = "Program data: ";
     log.strip_prefix(PROGRAM_DATA_PREFIX)
 }
 
@@ -24,6 +52,162 @@ pub fn extract_program_log<'a>(log: &'a str, prefix: &str) -> Option<&'a str> {
     log.strip_prefix(prefix)
 }
 
+/// Anchor 指令开始时输出的指令名日志前缀
+pub const ANCHOR_INSTRUCTION_LOG_PREFIX: &str = "Program log: Instruction: ";
+
+/// 按指令位置归集指定程序输出的、以 `prefix` 开头的日志行
+///
+/// 通过 `invoke [n]` / `success` / `failed` 行还原调用栈，返回
+/// (外层指令索引, 内层指令索引, 去掉前缀后的日志内容)，与指令事件的
+/// `outer_index` / `inner_index` 对应。日志被截断时后续位置可能无法归集。
+pub fn collect_program_logs<'a>(
+    log_messages: &'a [String],
+    program_id: &str,
+    prefix: &str,
+) -> Vec<(i64, Option<i64>, &'a str)> {
+    collect_invoked_program_logs(log_messages, prefix)
+        .into_iter()
+        .filter(|(program, ..)| *program == program_id)
+        .map(|(_, outer_index, inner_index, content)| (outer_index, inner_index, content))
+        .collect()
+}
+
+/// 按指令位置归集所有程序输出的、以 `prefix` 开头的日志行
+///
+/// 返回 (输出日志的程序ID, 外层指令索引, 内层指令索引, 去掉前缀后的日志内容)
+pub fn collect_invoked_program_logs<'a>(
+    log_messages: &'a [String],
+    prefix: &str,
+) -> Vec<(&'a str, i64, Option<i64>, &'a str)> {
+    let mut logs = Vec::new();
+    let mut outer_index = -1i64;
+    let mut inner_count = -1i64;
+    // 调用栈：(程序ID, 内层指令索引)
+    let mut stack: Vec<(&str, Option<i64>)> = Vec::new();
+    for log in log_messages {
+        if let Some(content) = log.strip_prefix(prefix) {
+            if let Some(&(program, inner_index)) = stack.last() {
+                logs.push((program, outer_index, inner_index, content));
+            }
+            continue;
+        }
+        let Some(rest) = log.strip_prefix("Program ") else {
+            continue;
+        };
+        let mut parts = rest.split_whitespace();
+        let (Some(program), Some(action)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        match action {
+            "invoke" => {
+                if parts.next() == Some("[1]") {
+                    outer_index += 1;
+                    inner_count = -1;
+                    stack.clear();
+                    stack.push((program, None));
+                } else {
+                    inner_count += 1;
+                    stack.push((program, Some(inner_count)));
+                }
+            }
+            "success" | "failed:" => {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+    logs
+}
+
+/// 指令位置 (外层指令索引, 内层指令索引) -> (调用栈高度, 调用链)
+pub type InvocationChains = HashMap<(i64, Option<i64>), (u32, Vec<Pubkey>)>;
+
+/// 由内层指令的 `stack_height` 还原调用链
+///
+/// 每项为 (外层指令索引, 外层指令程序, [(内层指令程序, stack_height)])；
+/// 任一内层指令缺少 `stack_height` 时返回 None，调用方可改用日志还原
+pub fn invocation_chains_from_stack_heights(
+    inner_instructions: impl IntoIterator<Item = (i64, Pubkey, Vec<(Pubkey, Option<u32>)>)>,
+) -> Option<InvocationChains> {
+    let mut chains = InvocationChains::new();
+    for (outer_index, outer_program, instructions) in inner_instructions {
+        let mut stack = vec![outer_program];
+        for (inner_index, (program, stack_height)) in instructions.into_iter().enumerate() {
+            let stack_height = stack_height?.max(2);
+            stack.truncate(stack_height as usize - 1);
+            chains.insert((outer_index, Some(inner_index as i64)), (stack_height, stack.clone()));
+            stack.push(program);
+        }
+    }
+    Some(chains)
+}
+
+/// 由日志中的 `invoke [n]` 嵌套还原调用链，日志被截断时后续位置无法还原
+pub fn invocation_chains_from_logs(log_messages: &[String]) -> InvocationChains {
+    let mut chains = InvocationChains::new();
+    let mut outer_index = -1i64;
+    let mut inner_count = -1i64;
+    let mut stack: Vec<Pubkey> = Vec::new();
+    for log in log_messages {
+        let Some(rest) = log.strip_prefix("Program ") else {
+            continue;
+        };
+        let mut parts = rest.split_whitespace();
+        let (Some(program), Some(action)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        match action {
+            "invoke" => {
+                let Ok(program) = Pubkey::from_str(program) else {
+                    continue;
+                };
+                let stack_height = parts
+                    .next()
+                    .and_then(|depth| depth.trim_matches(['[', ']']).parse::<u32>().ok())
+                    .unwrap_or(stack.len() as u32 + 1);
+                if stack_height <= 1 {
+                    outer_index += 1;
+                    inner_count = -1;
+                    stack.clear();
+                } else {
+                    inner_count += 1;
+                    chains.insert((outer_index, Some(inner_count)), (stack_height, stack.clone()));
+                }
+                stack.push(program);
+            }
+            "success" | "failed:" => {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+    chains
+}
+
+/// Anchor 鉴别器：sha256(`{namespace}:{name}`) 的前 8 字节
+pub fn anchor_discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = solana_sdk::hash::hashv(&[namespace.as_bytes(), b":", name.as_bytes()]);
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash.to_bytes()[..8]);
+    discriminator
+}
+
+/// 将 camelCase / PascalCase 名称转为 snake_case，用于计算 Anchor 指令鉴别器
+pub fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_ascii_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.push(ch.to_ascii_lowercase());
+        } else {
+            snake.push(ch);
+        }
+    }
+    snake
+}
+
 /// 安全地从字节数组中读取u64
 pub fn read_u64_le(data: &[u8], offset: usize) -> Option<u64> {
     if data.len() < offset + 8 {
@@ -41,6 +225,14 @@ pub fn read_i32_le(data: &[u8], offset: usize) -> Option<i32> {
     Some(i32::from_le_bytes(bytes))
 }
 
+pub fn read_i64_le(data: &[u8], offset: usize) -> Option<i64> {
+    if data.len() < offset + 8 {
+        return None;
+    }
+    let bytes: [u8; 8] = data[offset..offset + 8].try_into().ok()?;
+    Some(i64::from_le_bytes(bytes))
+}
+
 pub fn read_u128_le(data: &[u8], offset: usize) -> Option<u128> {
     if data.len() < offset + 16 {
         return None;
@@ -49,6 +241,12 @@ pub fn read_u128_le(data: &[u8], offset: usize) -> Option<u128> {
     Some(u128::from_le_bytes(bytes))
 }
 
+/// 安全地从字节数组中读取Pubkey
+pub fn read_pubkey(data: &[u8], offset: usize) -> Option<solana_sdk::pubkey::Pubkey> {
+    let bytes: [u8; 32] = data.get(offset..offset + 32)?.try_into().ok()?;
+    Some(solana_sdk::pubkey::Pubkey::new_from_array(bytes))
+}
+
 pub fn read_u8_le(data: &[u8], offset: usize) -> Option<u8> {
     if data.len() < offset + 1 {
         return None;
@@ -80,6 +278,14 @@ pub fn read_u32_le(data: &[u8], offset: usize) -> Option<u32> {
     Some(u32::from_le_bytes(bytes))
 }
 
+/// 读取 borsh 编码的字符串（u32 长度前缀），并将 `offset` 移到字符串之后
+pub fn read_borsh_string(data: &[u8], offset: &mut usize) -> Option<String> {
+    let len = read_u32_le(data, *offset)? as usize;
+    let bytes = data.get(*offset + 4..(*offset + 4).checked_add(len)?)?;
+    *offset += 4 + len;
+    Some(String::from_utf8_lossy(bytes).into_owned())
+}
+
 /// 安全地从字节数组中读取u16
 pub fn read_u16_le(data: &[u8], offset: usize) -> Option<u16> {
     if data.len() < offset + 2 {
@@ -108,3 +314,106 @@ pub fn format_pubkey_short(pubkey: &solana_sdk::pubkey::Pubkey) -> String {
         format!("{}...{}", &s[..4], &s[s.len() - 4..])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JUPITER: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+    const AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+    const CPMM: &str = "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C";
+    const TOKEN: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+    // 按运行时日志格式构造：路由嵌套调用 AMM，随后一条失败的顶层指令
+    fn routed_swap_logs() -> Vec<String> {
+        [
+            "Program ComputeBudget111111111111111111111111111111 invoke [1]".to_string(),
+            "Program ComputeBudget111111111111111111111111111111 success".to_string(),
+            format!("Program {JUPITER} invoke [1]"),
+            "Program log: Instruction: Route".to_string(),
+            format!("Program {AMM_V4} invoke [2]"),
+            "Program log: ray_log: AwBlzR0AAAAA".to_string(),
+            format!("Program {TOKEN} invoke [3]"),
+            "Program log: Instruction: Transfer".to_string(),
+            format!("Program {TOKEN} consumed 4645 of 180000 compute units"),
+            format!("Program {TOKEN} success"),
+            format!("Program {AMM_V4} success"),
+            format!("Program {TOKEN} invoke [2]"),
+            format!("Program {TOKEN} success"),
+            format!("Program data: {JUPITER}"),
+            format!("Program {JUPITER} success"),
+            format!("Program {CPMM} invoke [1]"),
+            format!("Program {TOKEN} invoke [2]"),
+            format!("Program {TOKEN} failed: custom program error: 0x1"),
+            "Program log: swap failed".to_string(),
+            format!("Program {CPMM} failed: custom program error: 0x1"),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn invocation_chains_from_logs_rebuilds_invoke_stack() {
+        let jupiter = Pubkey::from_str(JUPITER).unwrap();
+        let amm = Pubkey::from_str(AMM_V4).unwrap();
+        let cpmm = Pubkey::from_str(CPMM).unwrap();
+
+        let chains = invocation_chains_from_logs(&routed_swap_logs());
+        assert_eq!(chains.len(), 4);
+        assert_eq!(chains[&(1, Some(0))], (2, vec![jupiter]));
+        assert_eq!(chains[&(1, Some(1))], (3, vec![jupiter, amm]));
+        assert_eq!(chains[&(1, Some(2))], (2, vec![jupiter]));
+        assert_eq!(chains[&(2, Some(0))], (2, vec![cpmm]));
+        assert!(!chains.contains_key(&(1, None)));
+    }
+
+    #[test]
+    fn invocation_chains_from_logs_stops_at_truncation() {
+        let mut logs = routed_swap_logs();
+        logs.truncate(7);
+        logs.push("Log truncated".to_string());
+        let chains = invocation_chains_from_logs(&logs);
+        assert_eq!(chains.len(), 2);
+        assert!(!chains.contains_key(&(1, Some(2))));
+    }
+
+    #[test]
+    fn collect_invoked_program_logs_attributes_to_invoker() {
+        let logs = routed_swap_logs();
+        let program_logs = collect_invoked_program_logs(&logs, "Program log: ");
+        assert_eq!(
+            program_logs,
+            vec![
+                (JUPITER, 1, None, "Instruction: Route"),
+                (AMM_V4, 1, Some(0), "ray_log: AwBlzR0AAAAA"),
+                (TOKEN, 1, Some(1), "Instruction: Transfer"),
+                (CPMM, 2, None, "swap failed"),
+            ]
+        );
+        assert_eq!(
+            collect_program_logs(&logs, AMM_V4, "Program log: ray_log: "),
+            vec![(1, Some(0), "AwBlzR0AAAAA")]
+        );
+        assert_eq!(
+            collect_program_logs(&logs, JUPITER, PROGRAM_DATA_PREFIX),
+            vec![(1, None, JUPITER)]
+        );
+    }
+
+    #[test]
+    fn read_borsh_string_advances_offset() {
+        let mut data = vec![0xff];
+        for value in ["Pepe", ""] {
+            data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            data.extend_from_slice(value.as_bytes());
+        }
+        let mut offset = 1;
+        assert_eq!(read_borsh_string(&data, &mut offset).as_deref(), Some("Pepe"));
+        assert_eq!(read_borsh_string(&data, &mut offset).as_deref(), Some(""));
+        assert_eq!(offset, data.len());
+
+        // 长度超出数据时不移动偏移
+        let mut offset = 0;
+        assert!(read_borsh_string(&[5, 0, 0, 0, b'a'], &mut offset).is_none());
+        assert_eq!(offset, 0);
+    }
+}
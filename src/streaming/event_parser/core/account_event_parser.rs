@@ -11,14 +11,28 @@ use crate::impl_unified_event;
 use crate::streaming::common::SimdUtils;
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::common::{EventMetadata, EventType, ProtocolType};
+use crate::streaming::event_parser::core::pool_mint_cache::get_pool_mint_cache;
+use crate::streaming::event_parser::core::program_upgrade::{
+    parse_program_data_account, BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+    PROGRAM_DATA_ACCOUNT_DISCRIMINATOR,
+};
 use crate::streaming::event_parser::core::traits::{elapsed_micros_since, UnifiedEvent};
 use crate::streaming::event_parser::protocols::bonk::parser::BONK_PROGRAM_ID;
-use crate::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::meteora_dlmm::parser::METEORA_DLMM_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::meteora_dbc::parser::METEORA_DBC_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::moonshot::parser::MOONSHOT_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::openbook_v2::parser::OPENBOOK_V2_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::orca_whirlpool::parser::ORCA_WHIRLPOOL_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::phoenix::parser::PHOENIX_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::pumpfun::parser::{
+    PUMPFUN_PROGRAM_ID, PUMP_FEES_PROGRAM_ID,
+};
 use crate::streaming::event_parser::protocols::pumpswap::parser::PUMPSWAP_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID;
-use crate::streaming::event_parser::Protocol;
+use crate::streaming::event_parser::{EventParserFactory, Protocol};
 use crate::streaming::grpc::AccountPretty;
 
 /// 通用事件解析器配置
@@ -82,6 +96,8 @@ static PROTOCOL_CONFIGS_CACHE: OnceLock<HashMap<Protocol, Vec<AccountEventParseC
 static COMMON_CONFIG: OnceLock<AccountEventParseConfig> = OnceLock::new();
 // Nonce account config
 static NONCE_CONFIG: OnceLock<AccountEventParseConfig> = OnceLock::new();
+// ProgramData account config
+static PROGRAM_DATA_CONFIG: OnceLock<AccountEventParseConfig> = OnceLock::new();
 
 pub struct AccountEventParser {}
 
@@ -107,6 +123,27 @@ impl AccountEventParser {
                     account_discriminator: crate::streaming::event_parser::protocols::pumpswap::discriminators::POOL_ACCOUNT,
                     account_parser: crate::streaming::event_parser::protocols::pumpswap::types::pool_parser,
                 },
+                AccountEventParseConfig {
+                    program_id: PUMPSWAP_PROGRAM_ID,
+                    protocol_type: ProtocolType::PumpSwap,
+                    event_type: EventType::AccountPumpSwapGlobalVolumeAccumulator,
+                    account_discriminator: crate::streaming::event_parser::protocols::pumpswap::discriminators::GLOBAL_VOLUME_ACCUMULATOR_ACCOUNT,
+                    account_parser: crate::streaming::event_parser::protocols::pumpswap::types::global_volume_accumulator_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: PUMPSWAP_PROGRAM_ID,
+                    protocol_type: ProtocolType::PumpSwap,
+                    event_type: EventType::AccountPumpSwapUserVolumeAccumulator,
+                    account_discriminator: crate::streaming::event_parser::protocols::pumpswap::discriminators::USER_VOLUME_ACCUMULATOR_ACCOUNT,
+                    account_parser: crate::streaming::event_parser::protocols::pumpswap::types::user_volume_accumulator_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: PUMP_FEES_PROGRAM_ID,
+                    protocol_type: ProtocolType::PumpSwap,
+                    event_type: EventType::AccountPumpSwapFeeConfig,
+                    account_discriminator: crate::streaming::event_parser::protocols::pumpswap::discriminators::FEE_CONFIG_ACCOUNT,
+                    account_parser: crate::streaming::event_parser::protocols::pumpswap::types::fee_config_parser,
+                },
             ]);
             map.insert(Protocol::PumpFun, vec![
                 AccountEventParseConfig {
@@ -123,6 +160,27 @@ impl AccountEventParser {
                     account_discriminator: crate::streaming::event_parser::protocols::pumpfun::discriminators::GLOBAL_ACCOUNT,
                     account_parser: crate::streaming::event_parser::protocols::pumpfun::types::global_parser,
                 },
+                AccountEventParseConfig {
+                    program_id: PUMPFUN_PROGRAM_ID,
+                    protocol_type: ProtocolType::PumpFun,
+                    event_type: EventType::AccountPumpFunGlobalVolumeAccumulator,
+                    account_discriminator: crate::streaming::event_parser::protocols::pumpfun::discriminators::GLOBAL_VOLUME_ACCUMULATOR_ACCOUNT,
+                    account_parser: crate::streaming::event_parser::protocols::pumpfun::types::global_volume_accumulator_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: PUMPFUN_PROGRAM_ID,
+                    protocol_type: ProtocolType::PumpFun,
+                    event_type: EventType::AccountPumpFunUserVolumeAccumulator,
+                    account_discriminator: crate::streaming::event_parser::protocols::pumpfun::discriminators::USER_VOLUME_ACCUMULATOR_ACCOUNT,
+                    account_parser: crate::streaming::event_parser::protocols::pumpfun::types::user_volume_accumulator_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: PUMP_FEES_PROGRAM_ID,
+                    protocol_type: ProtocolType::PumpFun,
+                    event_type: EventType::AccountPumpFunFeeConfig,
+                    account_discriminator: crate::streaming::event_parser::protocols::pumpfun::discriminators::FEE_CONFIG_ACCOUNT,
+                    account_parser: crate::streaming::event_parser::protocols::pumpfun::types::fee_config_parser,
+                },
             ]);
             map.insert(Protocol::Bonk, vec![
                 AccountEventParseConfig {
@@ -146,6 +204,13 @@ impl AccountEventParser {
                     account_discriminator: crate::streaming::event_parser::protocols::bonk::discriminators::PLATFORM_CONFIG_ACCOUNT,
                     account_parser: crate::streaming::event_parser::protocols::bonk::types::platform_config_parser,
                 },
+                AccountEventParseConfig {
+                    program_id: BONK_PROGRAM_ID,
+                    protocol_type: ProtocolType::Bonk,
+                    event_type: EventType::AccountBonkVestingRecord,
+                    account_discriminator: crate::streaming::event_parser::protocols::bonk::discriminators::VESTING_RECORD_ACCOUNT,
+                    account_parser: crate::streaming::event_parser::protocols::bonk::types::vesting_record_parser,
+                },
             ]);
             map.insert(Protocol::RaydiumCpmm, vec![
                 AccountEventParseConfig {
@@ -185,6 +250,27 @@ impl AccountEventParser {
                     account_discriminator: crate::streaming::event_parser::protocols::raydium_clmm::discriminators::TICK_ARRAY_STATE,
                     account_parser: crate::streaming::event_parser::protocols::raydium_clmm::types::tick_array_state_parser,
                 },
+                AccountEventParseConfig {
+                    program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                    protocol_type: ProtocolType::RaydiumClmm,
+                    event_type: EventType::AccountRaydiumClmmPersonalPositionState,
+                    account_discriminator: crate::streaming::event_parser::protocols::raydium_clmm::discriminators::PERSONAL_POSITION_STATE,
+                    account_parser: crate::streaming::event_parser::protocols::raydium_clmm::types::personal_position_state_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                    protocol_type: ProtocolType::RaydiumClmm,
+                    event_type: EventType::AccountRaydiumClmmProtocolPositionState,
+                    account_discriminator: crate::streaming::event_parser::protocols::raydium_clmm::discriminators::PROTOCOL_POSITION_STATE,
+                    account_parser: crate::streaming::event_parser::protocols::raydium_clmm::types::protocol_position_state_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                    protocol_type: ProtocolType::RaydiumClmm,
+                    event_type: EventType::AccountRaydiumClmmObservationState,
+                    account_discriminator: crate::streaming::event_parser::protocols::raydium_clmm::discriminators::OBSERVATION_STATE,
+                    account_parser: crate::streaming::event_parser::protocols::raydium_clmm::types::observation_state_parser,
+                },
             ]);
             map.insert(Protocol::RaydiumAmmV4, vec![
                 AccountEventParseConfig {
@@ -195,6 +281,104 @@ impl AccountEventParser {
                     account_parser: crate::streaming::event_parser::protocols::raydium_amm_v4::types::amm_info_parser,
                 },
             ]);
+            map.insert(Protocol::MeteoraDlmm, vec![
+                AccountEventParseConfig {
+                    program_id: METEORA_DLMM_PROGRAM_ID,
+                    protocol_type: ProtocolType::MeteoraDlmm,
+                    event_type: EventType::AccountMeteoraDlmmLbPair,
+                    account_discriminator: crate::streaming::event_parser::protocols::meteora_dlmm::discriminators::LB_PAIR,
+                    account_parser: crate::streaming::event_parser::protocols::meteora_dlmm::types::lb_pair_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: METEORA_DLMM_PROGRAM_ID,
+                    protocol_type: ProtocolType::MeteoraDlmm,
+                    event_type: EventType::AccountMeteoraDlmmBinArray,
+                    account_discriminator: crate::streaming::event_parser::protocols::meteora_dlmm::discriminators::BIN_ARRAY,
+                    account_parser: crate::streaming::event_parser::protocols::meteora_dlmm::types::bin_array_parser,
+                },
+            ]);
+            map.insert(Protocol::MeteoraDammV2, vec![
+                AccountEventParseConfig {
+                    program_id: METEORA_DAMM_V2_PROGRAM_ID,
+                    protocol_type: ProtocolType::MeteoraDammV2,
+                    event_type: EventType::AccountMeteoraDammV2Pool,
+                    account_discriminator: crate::streaming::event_parser::protocols::meteora_damm_v2::discriminators::POOL,
+                    account_parser: crate::streaming::event_parser::protocols::meteora_damm_v2::types::pool_parser,
+                },
+            ]);
+            map.insert(Protocol::MeteoraDbc, vec![
+                AccountEventParseConfig {
+                    program_id: METEORA_DBC_PROGRAM_ID,
+                    protocol_type: ProtocolType::MeteoraDbc,
+                    event_type: EventType::AccountMeteoraDbcVirtualPool,
+                    account_discriminator: crate::streaming::event_parser::protocols::meteora_dbc::discriminators::VIRTUAL_POOL,
+                    account_parser: crate::streaming::event_parser::protocols::meteora_dbc::types::virtual_pool_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: METEORA_DBC_PROGRAM_ID,
+                    protocol_type: ProtocolType::MeteoraDbc,
+                    event_type: EventType::AccountMeteoraDbcPoolConfig,
+                    account_discriminator: crate::streaming::event_parser::protocols::meteora_dbc::discriminators::POOL_CONFIG,
+                    account_parser: crate::streaming::event_parser::protocols::meteora_dbc::types::pool_config_parser,
+                },
+            ]);
+            map.insert(Protocol::OrcaWhirlpool, vec![
+                AccountEventParseConfig {
+                    program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                    protocol_type: ProtocolType::OrcaWhirlpool,
+                    event_type: EventType::AccountOrcaWhirlpool,
+                    account_discriminator: crate::streaming::event_parser::protocols::orca_whirlpool::discriminators::WHIRLPOOL,
+                    account_parser: crate::streaming::event_parser::protocols::orca_whirlpool::types::whirlpool_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                    protocol_type: ProtocolType::OrcaWhirlpool,
+                    event_type: EventType::AccountOrcaWhirlpoolTickArray,
+                    account_discriminator: crate::streaming::event_parser::protocols::orca_whirlpool::discriminators::TICK_ARRAY,
+                    account_parser: crate::streaming::event_parser::protocols::orca_whirlpool::types::tick_array_parser,
+                },
+            ]);
+            map.insert(Protocol::OpenBookV2, vec![
+                AccountEventParseConfig {
+                    program_id: OPENBOOK_V2_PROGRAM_ID,
+                    protocol_type: ProtocolType::OpenBookV2,
+                    event_type: EventType::AccountOpenBookV2Market,
+                    account_discriminator: crate::streaming::event_parser::protocols::openbook_v2::discriminators::MARKET,
+                    account_parser: crate::streaming::event_parser::protocols::openbook_v2::types::market_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: OPENBOOK_V2_PROGRAM_ID,
+                    protocol_type: ProtocolType::OpenBookV2,
+                    event_type: EventType::AccountOpenBookV2BookSide,
+                    account_discriminator: crate::streaming::event_parser::protocols::openbook_v2::discriminators::BOOK_SIDE,
+                    account_parser: crate::streaming::event_parser::protocols::openbook_v2::types::book_side_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: OPENBOOK_V2_PROGRAM_ID,
+                    protocol_type: ProtocolType::OpenBookV2,
+                    event_type: EventType::AccountOpenBookV2EventHeap,
+                    account_discriminator: crate::streaming::event_parser::protocols::openbook_v2::discriminators::EVENT_HEAP,
+                    account_parser: crate::streaming::event_parser::protocols::openbook_v2::types::event_heap_parser,
+                },
+            ]);
+            map.insert(Protocol::Phoenix, vec![
+                AccountEventParseConfig {
+                    program_id: PHOENIX_PROGRAM_ID,
+                    protocol_type: ProtocolType::Phoenix,
+                    event_type: EventType::AccountPhoenixMarket,
+                    account_discriminator: crate::streaming::event_parser::protocols::phoenix::discriminators::MARKET,
+                    account_parser: crate::streaming::event_parser::protocols::phoenix::types::market_parser,
+                },
+            ]);
+            map.insert(Protocol::Moonshot, vec![
+                AccountEventParseConfig {
+                    program_id: MOONSHOT_PROGRAM_ID,
+                    protocol_type: ProtocolType::Moonshot,
+                    event_type: EventType::AccountMoonshotCurve,
+                    account_discriminator: crate::streaming::event_parser::protocols::moonshot::discriminators::CURVE_ACCOUNT,
+                    account_parser: crate::streaming::event_parser::protocols::moonshot::types::curve_account_parser,
+                },
+            ]);
             map
         });
 
@@ -206,7 +390,14 @@ impl AccountEventParser {
         configs.reserve(estimated_capacity);
 
         for protocol in protocols {
-            let protocol_configs = protocols_map.get(protocol).unwrap_or(&empty_vec);
+            let custom_configs;
+            let protocol_configs = match protocol {
+                Protocol::Custom(_) => {
+                    custom_configs = EventParserFactory::custom_account_configs(protocol);
+                    &custom_configs
+                }
+                _ => protocols_map.get(protocol).unwrap_or(&empty_vec),
+            };
             // 如果没有过滤器，直接扩展所有配置
             if event_type_filter.is_none() {
                 configs.extend(protocol_configs.iter().cloned());
@@ -235,6 +426,19 @@ impl AccountEventParser {
             configs.push(nonce_config.clone());
         }
 
+        if event_type_filter.is_none()
+            || event_type_filter.unwrap().include.contains(&EventType::ProgramUpgrade)
+        {
+            let program_data_config = PROGRAM_DATA_CONFIG.get_or_init(|| AccountEventParseConfig {
+                program_id: BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+                protocol_type: ProtocolType::Common,
+                event_type: EventType::ProgramUpgrade,
+                account_discriminator: PROGRAM_DATA_ACCOUNT_DISCRIMINATOR,
+                account_parser: parse_program_data_account,
+            });
+            configs.push(program_data_config.clone());
+        }
+
         let common_config = COMMON_CONFIG.get_or_init(|| AccountEventParseConfig {
             program_id: Pubkey::default(),
             protocol_type: ProtocolType::Common,
@@ -274,6 +478,8 @@ impl AccountEventParser {
                     },
                 );
                 if let Some(mut event) = event {
+                    // 池子账户顺带更新 vault -> mint 缓存
+                    get_pool_mint_cache().update_from_event(&*event);
                     event.set_handle_us(elapsed_micros_since(account.recv_us));
                     return Some(event);
                 }
@@ -0,0 +1,235 @@
+//! Address lookup table cache used to resolve v0 message account keys.
+//!
+//! The cache is only fed by account updates when the account filter of the subscription includes
+//! owner `AddressLookupTab1e1111111111111111111111111`, or by calling
+//! [`preload_address_lookup_tables`]. Lookups of tables that were never fed stay unresolved.
+
+use dashmap::DashMap;
+use solana_sdk::address_lookup_table::state::AddressLookupTable;
+use solana_sdk::message::v0::MessageAddressTableLookup;
+use solana_sdk::pubkey::Pubkey;
+use std::sync::Arc;
+
+use crate::common::{AnyResult, SolanaRpcClient};
+
+/// 地址查找表程序ID
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("AddressLookupTab1e1111111111111111111111111");
+
+/// getMultipleAccounts 单次请求的最大账户数
+const RPC_MULTIPLE_ACCOUNTS_LIMIT: usize = 100;
+
+/// 地址查找表解析结果
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedAddresses {
+    /// 可写地址（按查找表顺序）
+    pub writable: Vec<Pubkey>,
+    /// 只读地址（按查找表顺序）
+    pub readonly: Vec<Pubkey>,
+    /// 未能解析的查找表（缓存缺失或索引越界）
+    pub unresolved_tables: Vec<Pubkey>,
+}
+
+impl ResolvedAddresses {
+    /// 是否全部解析成功
+    pub fn is_complete(&self) -> bool {
+        self.unresolved_tables.is_empty()
+    }
+}
+
+/// Address lookup table cache, fed by account updates or RPC preload
+pub struct AddressLookupTableCache {
+    /// Lookup table address -> stored addresses
+    tables: DashMap<Pubkey, Arc<Vec<Pubkey>>>,
+}
+
+impl AddressLookupTableCache {
+    /// Create a new empty cache
+    pub fn new() -> Self {
+        Self { tables: DashMap::new() }
+    }
+
+    /// Insert or replace the addresses of a lookup table
+    pub fn insert(&self, table: Pubkey, addresses: Vec<Pubkey>) {
+        self.tables.insert(table, Arc::new(addresses));
+    }
+
+    /// Decode raw lookup table account data and update the cache
+    pub fn update_from_account_data(&self, table: Pubkey, data: &[u8]) -> bool {
+        match AddressLookupTable::deserialize(data) {
+            Ok(lookup_table) => {
+                self.insert(table, lookup_table.addresses.to_vec());
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Get the cached addresses of a lookup table
+    pub fn get(&self, table: &Pubkey) -> Option<Arc<Vec<Pubkey>>> {
+        self.tables.get(table).map(|entry| entry.value().clone())
+    }
+
+    /// Remove a lookup table from the cache
+    pub fn remove(&self, table: &Pubkey) -> Option<Arc<Vec<Pubkey>>> {
+        self.tables.remove(table).map(|(_, addresses)| addresses)
+    }
+
+    /// Number of cached lookup tables
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// Clear all cached lookup tables
+    pub fn clear(&self) {
+        self.tables.clear();
+    }
+
+    /// Resolve message address table lookups into writable and readonly addresses.
+    ///
+    /// Unresolvable entries are padded with `Pubkey::default()` so that account
+    /// indexes of the message stay aligned, and the table is reported in
+    /// `unresolved_tables`.
+    pub fn resolve(&self, lookups: &[MessageAddressTableLookup]) -> ResolvedAddresses {
+        let mut resolved = ResolvedAddresses::default();
+        let writable_len = lookups.iter().map(|l| l.writable_indexes.len()).sum();
+        let readonly_len = lookups.iter().map(|l| l.readonly_indexes.len()).sum();
+        resolved.writable.reserve(writable_len);
+        resolved.readonly.reserve(readonly_len);
+
+        for lookup in lookups {
+            let table = self.get(&lookup.account_key);
+            let mut complete = table.is_some();
+            for (indexes, output) in [
+                (&lookup.writable_indexes, &mut resolved.writable),
+                (&lookup.readonly_indexes, &mut resolved.readonly),
+            ] {
+                for &index in indexes.iter() {
+                    match table.as_ref().and_then(|addresses| addresses.get(index as usize)) {
+                        Some(address) => output.push(*address),
+                        None => {
+                            complete = false;
+                            output.push(Pubkey::default());
+                        }
+                    }
+                }
+            }
+            if !complete && !resolved.unresolved_tables.contains(&lookup.account_key) {
+                resolved.unresolved_tables.push(lookup.account_key);
+            }
+        }
+        resolved
+    }
+
+    /// Preload lookup tables through RPC, returns the number of tables loaded
+    pub async fn preload(&self, rpc: &SolanaRpcClient, tables: &[Pubkey]) -> AnyResult<usize> {
+        let mut loaded = 0;
+        for chunk in tables.chunks(RPC_MULTIPLE_ACCOUNTS_LIMIT) {
+            let accounts = rpc.get_multiple_accounts(chunk).await?;
+            for (table, account) in chunk.iter().zip(accounts) {
+                let Some(account) = account else {
+                    continue;
+                };
+                if account.owner == ADDRESS_LOOKUP_TABLE_PROGRAM_ID
+                    && self.update_from_account_data(*table, &account.data)
+                {
+                    loaded += 1;
+                }
+            }
+        }
+        Ok(loaded)
+    }
+}
+
+impl Default for AddressLookupTableCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Global address lookup table cache instance
+static ADDRESS_LOOKUP_TABLE_CACHE: once_cell::sync::Lazy<AddressLookupTableCache> =
+    once_cell::sync::Lazy::new(AddressLookupTableCache::new);
+
+/// Get global address lookup table cache instance
+pub fn get_address_lookup_table_cache() -> &'static AddressLookupTableCache {
+    &ADDRESS_LOOKUP_TABLE_CACHE
+}
+
+/// Convenience function: Update a lookup table from raw account data
+pub fn update_address_lookup_table(table: Pubkey, data: &[u8]) -> bool {
+    get_address_lookup_table_cache().update_from_account_data(table, data)
+}
+
+/// Convenience function: Resolve message address table lookups
+pub fn resolve_address_table_lookups(lookups: &[MessageAddressTableLookup]) -> ResolvedAddresses {
+    get_address_lookup_table_cache().resolve(lookups)
+}
+
+/// Convenience function: Preload lookup tables through RPC
+pub async fn preload_address_lookup_tables(
+    rpc: &SolanaRpcClient,
+    tables: &[Pubkey],
+) -> AnyResult<usize> {
+    get_address_lookup_table_cache().preload(rpc, tables).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(table: Pubkey, writable: &[u8], readonly: &[u8]) -> MessageAddressTableLookup {
+        MessageAddressTableLookup {
+            account_key: table,
+            writable_indexes: writable.to_vec(),
+            readonly_indexes: readonly.to_vec(),
+        }
+    }
+
+    #[test]
+    fn resolve_cached_table() {
+        let cache = AddressLookupTableCache::new();
+        let table = Pubkey::new_unique();
+        let addresses: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        cache.insert(table, addresses.clone());
+
+        let resolved = cache.resolve(&[lookup(table, &[2, 0], &[3])]);
+        assert!(resolved.is_complete());
+        assert_eq!(resolved.writable, vec![addresses[2], addresses[0]]);
+        assert_eq!(resolved.readonly, vec![addresses[3]]);
+    }
+
+    #[test]
+    fn resolve_missing_table_pads_addresses() {
+        let cache = AddressLookupTableCache::new();
+        let cached = Pubkey::new_unique();
+        let addresses: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        cache.insert(cached, addresses.clone());
+        let missing = Pubkey::new_unique();
+
+        let resolved = cache.resolve(&[lookup(missing, &[0, 1], &[5]), lookup(cached, &[1], &[0])]);
+        assert_eq!(resolved.unresolved_tables, vec![missing]);
+        // 缺失的表以默认地址占位，后续表的地址位置保持不变
+        assert_eq!(resolved.writable, vec![Pubkey::default(), Pubkey::default(), addresses[1]]);
+        assert_eq!(resolved.readonly, vec![Pubkey::default(), addresses[0]]);
+    }
+
+    #[test]
+    fn resolve_index_past_cached_length() {
+        let cache = AddressLookupTableCache::new();
+        let table = Pubkey::new_unique();
+        let addresses: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        cache.insert(table, addresses.clone());
+
+        // 查找表在缓存后又追加了地址
+        let resolved = cache.resolve(&[lookup(table, &[1, 2], &[0, 7])]);
+        assert_eq!(resolved.unresolved_tables, vec![table]);
+        assert_eq!(resolved.writable, vec![addresses[1], Pubkey::default()]);
+        assert_eq!(resolved.readonly, vec![addresses[0], Pubkey::default()]);
+    }
+}
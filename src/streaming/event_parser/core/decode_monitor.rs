@@ -0,0 +1,92 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use dashmap::DashMap;
+use solana_sdk::pubkey::Pubkey;
+
+/// Decode failures of one discriminator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeFailureCount {
+    pub program_id: Pubkey,
+    pub discriminator: Vec<u8>,
+    pub count: u64,
+}
+
+/// Unknown-instruction capture switch and per-discriminator decode-failure counters
+pub struct DecodeMonitor {
+    /// Emit `RawInstructionEvent` for unknown or undecodable instructions
+    capture_raw_instructions: AtomicBool,
+    /// (program id, discriminator) -> failure count
+    failures: DashMap<(Pubkey, Vec<u8>), u64>,
+}
+
+impl DecodeMonitor {
+    /// Create a new monitor with capture disabled
+    pub fn new() -> Self {
+        Self { capture_raw_instructions: AtomicBool::new(false), failures: DashMap::new() }
+    }
+
+    /// Enable or disable `RawInstructionEvent` capture
+    pub fn set_capture_raw_instructions(&self, enabled: bool) {
+        self.capture_raw_instructions.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether `RawInstructionEvent` capture is enabled
+    pub fn capture_raw_instructions(&self) -> bool {
+        self.capture_raw_instructions.load(Ordering::Relaxed)
+    }
+
+    /// Record a decode failure of a matched discriminator
+    pub fn record_failure(&self, program_id: Pubkey, discriminator: &[u8]) {
+        *self.failures.entry((program_id, discriminator.to_vec())).or_insert(0) += 1;
+    }
+
+    /// Failure count of a single discriminator
+    pub fn failure_count(&self, program_id: &Pubkey, discriminator: &[u8]) -> u64 {
+        self.failures.get(&(*program_id, discriminator.to_vec())).map(|count| *count).unwrap_or(0)
+    }
+
+    /// All failure counters, highest count first
+    pub fn failure_counts(&self) -> Vec<DecodeFailureCount> {
+        let mut counts: Vec<DecodeFailureCount> = self
+            .failures
+            .iter()
+            .map(|entry| DecodeFailureCount {
+                program_id: entry.key().0,
+                discriminator: entry.key().1.clone(),
+                count: *entry.value(),
+            })
+            .collect();
+        counts.sort_by_key(|count| std::cmp::Reverse(count.count));
+        counts
+    }
+
+    /// Reset all failure counters
+    pub fn reset(&self) {
+        self.failures.clear();
+    }
+}
+
+impl Default for DecodeMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Global decode monitor instance
+static DECODE_MONITOR: once_cell::sync::Lazy<DecodeMonitor> =
+    once_cell::sync::Lazy::new(DecodeMonitor::new);
+
+/// Get global decode monitor instance
+pub fn get_decode_monitor() -> &'static DecodeMonitor {
+    &DECODE_MONITOR
+}
+
+/// Convenience function: Enable or disable `RawInstructionEvent` capture
+pub fn set_raw_instruction_capture(enabled: bool) {
+    get_decode_monitor().set_capture_raw_instructions(enabled);
+}
+
+/// Convenience function: Get all decode failure counters
+pub fn get_decode_failure_counts() -> Vec<DecodeFailureCount> {
+    get_decode_monitor().failure_counts()
+}
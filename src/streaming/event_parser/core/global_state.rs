@@ -13,6 +13,10 @@ struct SlotAddresses {
     dev_addresses: BTreeSet<Pubkey>,
     /// Bonk developer addresses for this slot  
     bonk_dev_addresses: BTreeSet<Pubkey>,
+    /// Meteora DBC developer addresses for this slot
+    meteora_dbc_dev_addresses: BTreeSet<Pubkey>,
+    /// Moonshot developer addresses for this slot
+    moonshot_dev_addresses: BTreeSet<Pubkey>,
 }
 
 /// High-performance global state with lock-free slot-based storage
@@ -99,6 +103,38 @@ impl GlobalState {
             });
     }
 
+    /// Add Meteora DBC developer address for a specific slot (lock-free)
+    pub fn add_meteora_dbc_dev_address(&self, slot: u64, address: Pubkey) {
+        self.maybe_cleanup();
+        
+        self.slot_data.entry(slot)
+            .and_modify(|addresses| {
+                addresses.meteora_dbc_dev_addresses.insert(address);
+            })
+            .or_insert_with(|| {
+                self.slot_count.fetch_add(1, Ordering::Relaxed);
+                let mut slot_addr = SlotAddresses::default();
+                slot_addr.meteora_dbc_dev_addresses.insert(address);
+                slot_addr
+            });
+    }
+
+    /// Add Moonshot developer address for a specific slot (lock-free)
+    pub fn add_moonshot_dev_address(&self, slot: u64, address: Pubkey) {
+        self.maybe_cleanup();
+        
+        self.slot_data.entry(slot)
+            .and_modify(|addresses| {
+                addresses.moonshot_dev_addresses.insert(address);
+            })
+            .or_insert_with(|| {
+                self.slot_count.fetch_add(1, Ordering::Relaxed);
+                let mut slot_addr = SlotAddresses::default();
+                slot_addr.moonshot_dev_addresses.insert(address);
+                slot_addr
+            });
+    }
+
     /// High-performance: Check if address is a developer address in specific slot (O(log m))
     pub fn is_dev_address_in_slot(&self, slot: u64, address: &Pubkey) -> bool {
         self.slot_data.get(&slot)
@@ -113,6 +149,20 @@ impl GlobalState {
             .unwrap_or(false)
     }
 
+    /// High-performance: Check if address is a Meteora DBC developer address in specific slot (O(log m))
+    pub fn is_meteora_dbc_dev_address_in_slot(&self, slot: u64, address: &Pubkey) -> bool {
+        self.slot_data.get(&slot)
+            .map(|entry| entry.meteora_dbc_dev_addresses.contains(address))
+            .unwrap_or(false)
+    }
+
+    /// High-performance: Check if address is a Moonshot developer address in specific slot (O(log m))
+    pub fn is_moonshot_dev_address_in_slot(&self, slot: u64, address: &Pubkey) -> bool {
+        self.slot_data.get(&slot)
+            .map(|entry| entry.moonshot_dev_addresses.contains(address))
+            .unwrap_or(false)
+    }
+
     /// Check if address is a developer address in any slot (lock-free scan, slower)
     pub fn is_dev_address(&self, address: &Pubkey) -> bool {
         self.slot_data.iter().any(|entry| entry.dev_addresses.contains(address))
@@ -123,6 +173,16 @@ impl GlobalState {
         self.slot_data.iter().any(|entry| entry.bonk_dev_addresses.contains(address))
     }
 
+    /// Check if address is a Meteora DBC developer address in any slot (lock-free scan, slower)
+    pub fn is_meteora_dbc_dev_address(&self, address: &Pubkey) -> bool {
+        self.slot_data.iter().any(|entry| entry.meteora_dbc_dev_addresses.contains(address))
+    }
+
+    /// Check if address is a Moonshot developer address in any slot (lock-free scan, slower)
+    pub fn is_moonshot_dev_address(&self, address: &Pubkey) -> bool {
+        self.slot_data.iter().any(|entry| entry.moonshot_dev_addresses.contains(address))
+    }
+
     /// Get all developer addresses from all slots (lock-free aggregation)
     pub fn get_dev_addresses(&self) -> Vec<Pubkey> {
         let mut all_addresses = BTreeSet::new();
@@ -145,6 +205,28 @@ impl GlobalState {
         all_addresses.into_iter().collect()
     }
 
+    /// Get all Meteora DBC developer addresses from all slots (lock-free aggregation)
+    pub fn get_meteora_dbc_dev_addresses(&self) -> Vec<Pubkey> {
+        let mut all_addresses = BTreeSet::new();
+        for entry in self.slot_data.iter() {
+            for addr in &entry.meteora_dbc_dev_addresses {
+                all_addresses.insert(*addr);
+            }
+        }
+        all_addresses.into_iter().collect()
+    }
+
+    /// Get all Moonshot developer addresses from all slots (lock-free aggregation)
+    pub fn get_moonshot_dev_addresses(&self) -> Vec<Pubkey> {
+        let mut all_addresses = BTreeSet::new();
+        for entry in self.slot_data.iter() {
+            for addr in &entry.moonshot_dev_addresses {
+                all_addresses.insert(*addr);
+            }
+        }
+        all_addresses.into_iter().collect()
+    }
+
     /// Get developer addresses for a specific slot
     pub fn get_dev_addresses_for_slot(&self, slot: u64) -> Vec<Pubkey> {
         self.slot_data.get(&slot)
@@ -159,6 +241,20 @@ impl GlobalState {
             .unwrap_or_default()
     }
 
+    /// Get Meteora DBC developer addresses for a specific slot
+    pub fn get_meteora_dbc_dev_addresses_for_slot(&self, slot: u64) -> Vec<Pubkey> {
+        self.slot_data.get(&slot)
+            .map(|entry| entry.meteora_dbc_dev_addresses.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Get Moonshot developer addresses for a specific slot
+    pub fn get_moonshot_dev_addresses_for_slot(&self, slot: u64) -> Vec<Pubkey> {
+        self.slot_data.get(&slot)
+            .map(|entry| entry.moonshot_dev_addresses.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
     /// Get current slot count
     pub fn get_slot_count(&self) -> usize {
         self.slot_count.load(Ordering::Relaxed)
@@ -241,3 +337,53 @@ pub fn is_dev_address_in_slot(slot: u64, address: &Pubkey) -> bool {
 pub fn is_bonk_dev_address_in_slot(slot: u64, address: &Pubkey) -> bool {
     get_global_state().is_bonk_dev_address_in_slot(slot, address)
 }
+
+/// Convenience function: Add Meteora DBC developer address for a specific slot
+pub fn add_meteora_dbc_dev_address(slot: u64, address: Pubkey) {
+    get_global_state().add_meteora_dbc_dev_address(slot, address);
+}
+
+/// Convenience function: Check if address is a Meteora DBC developer address
+pub fn is_meteora_dbc_dev_address(address: &Pubkey) -> bool {
+    get_global_state().is_meteora_dbc_dev_address(address)
+}
+
+/// Convenience function: Get all Meteora DBC developer addresses
+pub fn get_meteora_dbc_dev_addresses() -> Vec<Pubkey> {
+    get_global_state().get_meteora_dbc_dev_addresses()
+}
+
+/// Convenience function: Get Meteora DBC developer addresses for a specific slot
+pub fn get_meteora_dbc_dev_addresses_for_slot(slot: u64) -> Vec<Pubkey> {
+    get_global_state().get_meteora_dbc_dev_addresses_for_slot(slot)
+}
+
+/// High-performance: Check if address is a Meteora DBC developer address in specific slot
+pub fn is_meteora_dbc_dev_address_in_slot(slot: u64, address: &Pubkey) -> bool {
+    get_global_state().is_meteora_dbc_dev_address_in_slot(slot, address)
+}
+
+/// Convenience function: Add Moonshot developer address for a specific slot
+pub fn add_moonshot_dev_address(slot: u64, address: Pubkey) {
+    get_global_state().add_moonshot_dev_address(slot, address);
+}
+
+/// Convenience function: Check if address is a Moonshot developer address
+pub fn is_moonshot_dev_address(address: &Pubkey) -> bool {
+    get_global_state().is_moonshot_dev_address(address)
+}
+
+/// Convenience function: Get all Moonshot developer addresses
+pub fn get_moonshot_dev_addresses() -> Vec<Pubkey> {
+    get_global_state().get_moonshot_dev_addresses()
+}
+
+/// Convenience function: Get Moonshot developer addresses for a specific slot
+pub fn get_moonshot_dev_addresses_for_slot(slot: u64) -> Vec<Pubkey> {
+    get_global_state().get_moonshot_dev_addresses_for_slot(slot)
+}
+
+/// High-performance: Check if address is a Moonshot developer address in specific slot
+pub fn is_moonshot_dev_address_in_slot(slot: u64, address: &Pubkey) -> bool {
+    get_global_state().is_moonshot_dev_address_in_slot(slot, address)
+}
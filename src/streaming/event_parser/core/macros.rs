@@ -121,6 +121,19 @@ macro_rules! impl_event_parser_delegate {
                 self.inner.parse_events_from_grpc_instruction(instruction, accounts, signature, slot, block_time, recv_us, outer_index, inner_index, bot_wallet, transaction_index, inner_instructions, callback)
             }
 
+            fn parse_events_from_logs(
+                &self,
+                log_messages: &[String],
+                signature: solana_sdk::signature::Signature,
+                slot: u64,
+                block_time: Option<prost_types::Timestamp>,
+                recv_us: i64,
+                bot_wallet: Option<solana_sdk::pubkey::Pubkey>,
+                callback: std::sync::Arc<dyn for<'a> Fn(&'a Box<dyn $crate::streaming::event_parser::core::traits::UnifiedEvent>) + Send + Sync>,
+            ) -> anyhow::Result<()> {
+                self.inner.parse_events_from_logs(log_messages, signature, slot, block_time, recv_us, bot_wallet, callback)
+            }
+
             fn should_handle(&self, program_id: &solana_sdk::pubkey::Pubkey) -> bool {
                 self.inner.should_handle(program_id)
             }
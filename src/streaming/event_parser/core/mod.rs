@@ -3,4 +3,8 @@ pub mod traits;
 pub mod account_event_parser;
 pub mod macros;
 pub mod global_state;
+pub mod address_lookup_table;
+pub mod pool_mint_cache;
+pub mod decode_monitor;
+pub mod program_upgrade;
 pub use traits::{EventParser, UnifiedEvent};
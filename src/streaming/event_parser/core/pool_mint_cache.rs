@@ -0,0 +1,133 @@
+use dashmap::DashMap;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::match_event;
+use crate::streaming::event_parser::core::traits::UnifiedEvent;
+use crate::streaming::event_parser::protocols::{
+    meteora_damm_v2::MeteoraDammV2PoolAccountEvent, meteora_dlmm::MeteoraDlmmLbPairAccountEvent,
+    orca_whirlpool::OrcaWhirlpoolAccountEvent, raydium_amm_v4::RaydiumAmmV4AmmInfoAccountEvent,
+    raydium_clmm::RaydiumClmmPoolStateAccountEvent,
+};
+
+/// Pool vault -> mint cache, fed by pool account events
+pub struct PoolMintCache {
+    /// Vault token account -> mint
+    vault_mints: DashMap<Pubkey, Pubkey>,
+}
+
+impl PoolMintCache {
+    /// Create a new empty cache
+    pub fn new() -> Self {
+        Self { vault_mints: DashMap::new() }
+    }
+
+    /// Record the mint held by a pool vault
+    pub fn insert(&self, vault: Pubkey, mint: Pubkey) {
+        if vault != Pubkey::default() && mint != Pubkey::default() {
+            self.vault_mints.insert(vault, mint);
+        }
+    }
+
+    /// Get the mint held by a pool vault
+    pub fn get(&self, vault: &Pubkey) -> Option<Pubkey> {
+        self.vault_mints.get(vault).map(|entry| *entry.value())
+    }
+
+    /// Number of cached vaults
+    pub fn len(&self) -> usize {
+        self.vault_mints.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.vault_mints.is_empty()
+    }
+
+    /// Clear all cached vaults
+    pub fn clear(&self) {
+        self.vault_mints.clear();
+    }
+
+    /// Update the cache from a pool account event (Raydium CLMM PoolState, AMM v4 AmmInfo,
+    /// Meteora DLMM LbPair, Meteora DAMM v2 Pool, Orca Whirlpool)
+    pub fn update_from_event(&self, event: &dyn UnifiedEvent) {
+        match_event!(event, {
+            RaydiumClmmPoolStateAccountEvent => |e: RaydiumClmmPoolStateAccountEvent| {
+                self.insert(e.pool_state.token_vault0, e.pool_state.token_mint0);
+                self.insert(e.pool_state.token_vault1, e.pool_state.token_mint1);
+            },
+            RaydiumAmmV4AmmInfoAccountEvent => |e: RaydiumAmmV4AmmInfoAccountEvent| {
+                self.insert(e.amm_info.token_coin, e.amm_info.coin_mint);
+                self.insert(e.amm_info.token_pc, e.amm_info.pc_mint);
+            },
+            MeteoraDlmmLbPairAccountEvent => |e: MeteoraDlmmLbPairAccountEvent| {
+                self.insert(e.lb_pair.reserve_x, e.lb_pair.token_x_mint);
+                self.insert(e.lb_pair.reserve_y, e.lb_pair.token_y_mint);
+            },
+            MeteoraDammV2PoolAccountEvent => |e: MeteoraDammV2PoolAccountEvent| {
+                self.insert(e.pool.token_a_vault, e.pool.token_a_mint);
+                self.insert(e.pool.token_b_vault, e.pool.token_b_mint);
+            },
+            OrcaWhirlpoolAccountEvent => |e: OrcaWhirlpoolAccountEvent| {
+                self.insert(e.whirlpool.token_vault_a, e.whirlpool.token_mint_a);
+                self.insert(e.whirlpool.token_vault_b, e.whirlpool.token_mint_b);
+            },
+        });
+    }
+}
+
+impl Default for PoolMintCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Global pool mint cache instance
+static POOL_MINT_CACHE: once_cell::sync::Lazy<PoolMintCache> =
+    once_cell::sync::Lazy::new(PoolMintCache::new);
+
+/// Get global pool mint cache instance
+pub fn get_pool_mint_cache() -> &'static PoolMintCache {
+    &POOL_MINT_CACHE
+}
+
+/// Convenience function: Record the mint held by a pool vault
+pub fn add_pool_vault_mint(vault: Pubkey, mint: Pubkey) {
+    get_pool_mint_cache().insert(vault, mint);
+}
+
+/// Convenience function: Get the mint held by a pool vault
+pub fn get_pool_vault_mint(vault: &Pubkey) -> Option<Pubkey> {
+    get_pool_mint_cache().get(vault)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::raydium_amm_v4::types::AmmInfo;
+
+    #[test]
+    fn vault_mints_from_amm_info() {
+        let cache = PoolMintCache::new();
+        let amm_info = AmmInfo {
+            token_coin: Pubkey::new_unique(),
+            token_pc: Pubkey::new_unique(),
+            coin_mint: Pubkey::new_unique(),
+            pc_mint: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let event =
+            RaydiumAmmV4AmmInfoAccountEvent { amm_info: amm_info.clone(), ..Default::default() };
+
+        cache.update_from_event(&event);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&amm_info.token_coin), Some(amm_info.coin_mint));
+        assert_eq!(cache.get(&amm_info.token_pc), Some(amm_info.pc_mint));
+        assert_eq!(cache.get(&amm_info.coin_mint), None);
+
+        // 未初始化的 vault 不写入缓存
+        cache.insert(Pubkey::default(), Pubkey::new_unique());
+        cache.insert(Pubkey::new_unique(), Pubkey::default());
+        assert_eq!(cache.len(), 2);
+    }
+}
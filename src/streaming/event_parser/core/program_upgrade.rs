@@ -0,0 +1,238 @@
+use dashmap::DashMap;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::{
+    read_pubkey, read_u32_le, read_u64_le, EventMetadata,
+};
+use crate::streaming::event_parser::core::traits::UnifiedEvent;
+use crate::streaming::event_parser::protocols::program_upgrade::program_upgrade_event::{
+    ProgramUpgradeEvent, ProgramUpgradeKind,
+};
+use crate::streaming::event_parser::{EventParserFactory, Protocol};
+use crate::streaming::grpc::AccountPretty;
+
+/// BPF Upgradeable Loader program id
+pub const BPF_LOADER_UPGRADEABLE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// `UpgradeableLoaderState::ProgramData` account discriminator
+pub const PROGRAM_DATA_ACCOUNT_DISCRIMINATOR: &[u8] = &[3, 0, 0, 0];
+
+/// ProgramData metadata size, program bytes follow it
+const PROGRAM_DATA_METADATA_SIZE: usize = 45;
+
+/// Loader instruction tags
+const UPGRADE_IX: u32 = 3;
+const SET_AUTHORITY_IX: u32 = 4;
+const SET_AUTHORITY_CHECKED_IX: u32 = 7;
+
+/// Program id -> ProgramData address cache
+static PROGRAM_DATA_ADDRESSES: once_cell::sync::Lazy<DashMap<Pubkey, Pubkey>> =
+    once_cell::sync::Lazy::new(DashMap::new);
+
+/// ProgramData address of an upgradeable program
+pub fn program_data_address(program_id: &Pubkey) -> Pubkey {
+    *PROGRAM_DATA_ADDRESSES.entry(*program_id).or_insert_with(|| {
+        Pubkey::find_program_address(&[program_id.as_ref()], &BPF_LOADER_UPGRADEABLE_PROGRAM_ID).0
+    })
+}
+
+/// Whether the instruction is handled by the built-in program upgrade monitor
+pub fn is_program_upgrade_instruction(program_id: &Pubkey) -> bool {
+    *program_id == BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+}
+
+/// ProgramData addresses of the given protocols, to be added to account subscriptions
+pub fn program_data_accounts(protocols: &[Protocol]) -> Vec<Pubkey> {
+    protocols
+        .iter()
+        .flat_map(|protocol| protocol.get_program_id())
+        .map(|program_id| program_data_address(&program_id))
+        .collect()
+}
+
+/// Supported protocol program matching the predicate
+fn find_watched_program(matches: impl Fn(&Pubkey) -> bool) -> Option<(Protocol, Pubkey)> {
+    EventParserFactory::supported_protocols().into_iter().find_map(|protocol| {
+        let program_id = protocol.get_program_id().into_iter().find(|id| matches(id))?;
+        Some((protocol, program_id))
+    })
+}
+
+/// Parse an `Upgrade` / `SetAuthority` / `SetAuthorityChecked` instruction that touches a
+/// supported protocol program
+pub fn parse_program_upgrade_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    let (kind, program_data, authority, buffer) = match read_u32_le(data, 0)? {
+        UPGRADE_IX if accounts.len() >= 7 => {
+            (ProgramUpgradeKind::Upgrade, accounts[0], Some(accounts[6]), Some(accounts[2]))
+        }
+        SET_AUTHORITY_IX | SET_AUTHORITY_CHECKED_IX if accounts.len() >= 2 => {
+            (ProgramUpgradeKind::SetAuthority, accounts[0], accounts.get(2).copied(), None)
+        }
+        _ => return None,
+    };
+    let (protocol, program_id) = match kind {
+        ProgramUpgradeKind::Upgrade => find_watched_program(|id| *id == accounts[1])?,
+        _ => find_watched_program(|id| program_data_address(id) == program_data)?,
+    };
+    Some(Box::new(ProgramUpgradeEvent {
+        metadata,
+        kind,
+        protocol: protocol.to_string(),
+        program_id,
+        program_data,
+        authority,
+        buffer,
+        ..Default::default()
+    }))
+}
+
+/// Parse a ProgramData account of a supported protocol program
+pub fn parse_program_data_account(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    let (protocol, program_id) =
+        find_watched_program(|id| program_data_address(id) == account.pubkey)?;
+    let deploy_slot = read_u64_le(&account.data, 4)?;
+    let authority = match account.data.get(12)? {
+        1 => Some(read_pubkey(&account.data, 13)?),
+        _ => None,
+    };
+    let program_bytes = account.data.get(PROGRAM_DATA_METADATA_SIZE..).unwrap_or_default();
+    Some(Box::new(ProgramUpgradeEvent {
+        metadata,
+        kind: ProgramUpgradeKind::ProgramData,
+        protocol: protocol.to_string(),
+        program_id,
+        program_data: account.pubkey,
+        authority,
+        buffer: None,
+        deploy_slot: Some(deploy_slot),
+        data_hash: Some(solana_sdk::hash::hash(program_bytes).to_string()),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+
+    // ProgramData account built from the `UpgradeableLoaderState::ProgramData` layout: tag,
+    // deploy slot, optional authority, then the program bytes
+    const PROGRAM_DATA: &str = concat!(
+        "AwAAAMuvLhYAAAAAAQV13dKdj5FPWDYgs3ai9v1haRYK4ld3H+/DGpd+Pk3Uf0VMRgIBAQAA",
+        "AQIDBAUGBwgJCgsMDQ4PEBESExQVFhc=",
+    );
+    const IMMUTABLE_PROGRAM_DATA: &str = concat!(
+        "AwAAAMuvLhYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAf0VMRgIBAQAA",
+        "AQIDBAUGBwgJCgsMDQ4PEBESExQVFhc=",
+    );
+    const AUTHORITY: &str = "NKHFeD5JPZ8FGer72xiDVVFwRdzLmrQwFe1aohVP1tP";
+
+    fn program_upgrade_event(event: Option<Box<dyn UnifiedEvent>>) -> ProgramUpgradeEvent {
+        event.unwrap().as_any().downcast_ref::<ProgramUpgradeEvent>().unwrap().clone()
+    }
+
+    fn program_data_account(data: &str) -> AccountPretty {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        AccountPretty {
+            pubkey: program_data_address(&PUMPFUN_PROGRAM_ID),
+            owner: BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+            data: STANDARD.decode(data).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn program_data_account_reports_deploy_slot_and_hash() {
+        let account = program_data_account(PROGRAM_DATA);
+        assert!(account.data.starts_with(PROGRAM_DATA_ACCOUNT_DISCRIMINATOR));
+        let event =
+            program_upgrade_event(parse_program_data_account(&account, EventMetadata::default()));
+        assert_eq!(event.kind, ProgramUpgradeKind::ProgramData);
+        assert_eq!(event.protocol, Protocol::PumpFun.to_string());
+        assert_eq!(event.program_id, PUMPFUN_PROGRAM_ID);
+        assert_eq!(event.program_data, account.pubkey);
+        assert_eq!(event.authority, Some(Pubkey::from_str(AUTHORITY).unwrap()));
+        assert_eq!(event.deploy_slot, Some(372_158_411));
+        assert_eq!(
+            event.data_hash.as_deref(),
+            Some("FsxLDtAqmim5caN75opPiu6zFtQA8tXirQS5TF3WenkE")
+        );
+
+        let immutable = program_upgrade_event(parse_program_data_account(
+            &program_data_account(IMMUTABLE_PROGRAM_DATA),
+            EventMetadata::default(),
+        ));
+        assert_eq!(immutable.authority, None);
+        assert_eq!(immutable.data_hash, event.data_hash);
+
+        let unwatched = AccountPretty { pubkey: Pubkey::new_unique(), ..account };
+        assert!(parse_program_data_account(&unwatched, EventMetadata::default()).is_none());
+    }
+
+    #[test]
+    fn loader_instructions_touching_watched_programs() {
+        let program_data = program_data_address(&PUMPFUN_PROGRAM_ID);
+        let authority = Pubkey::from_str(AUTHORITY).unwrap();
+        let buffer = Pubkey::new_unique();
+        // Upgrade: programdata, program, buffer, spill, rent, clock, authority
+        let upgrade_accounts = [
+            program_data,
+            PUMPFUN_PROGRAM_ID,
+            buffer,
+            Pubkey::new_unique(),
+            solana_sdk::sysvar::rent::ID,
+            solana_sdk::sysvar::clock::ID,
+            authority,
+        ];
+        let event = program_upgrade_event(parse_program_upgrade_instruction(
+            &[3, 0, 0, 0],
+            &upgrade_accounts,
+            EventMetadata::default(),
+        ));
+        assert_eq!(event.kind, ProgramUpgradeKind::Upgrade);
+        assert_eq!(event.protocol, Protocol::PumpFun.to_string());
+        assert_eq!((event.program_id, event.program_data), (PUMPFUN_PROGRAM_ID, program_data));
+        assert_eq!((event.authority, event.buffer), (Some(authority), Some(buffer)));
+
+        // SetAuthority: programdata, current authority, optional new authority
+        let new_authority = Pubkey::new_unique();
+        let event = program_upgrade_event(parse_program_upgrade_instruction(
+            &[4, 0, 0, 0],
+            &[program_data, authority, new_authority],
+            EventMetadata::default(),
+        ));
+        assert_eq!(event.kind, ProgramUpgradeKind::SetAuthority);
+        assert_eq!(event.program_id, PUMPFUN_PROGRAM_ID);
+        assert_eq!(event.authority, Some(new_authority));
+        let event = program_upgrade_event(parse_program_upgrade_instruction(
+            &[4, 0, 0, 0],
+            &[program_data, authority],
+            EventMetadata::default(),
+        ));
+        assert_eq!(event.authority, None);
+
+        let mut unwatched = upgrade_accounts;
+        unwatched[1] = Pubkey::new_unique();
+        let ignored = [
+            ([3, 0, 0, 0], &unwatched[..]),
+            // Other loader instructions such as Write are ignored
+            ([1, 0, 0, 0], &upgrade_accounts[..]),
+            // Upgrade with missing accounts
+            ([3, 0, 0, 0], &upgrade_accounts[..6]),
+        ];
+        for (data, accounts) in ignored {
+            let event =
+                parse_program_upgrade_instruction(&data, accounts, EventMetadata::default());
+            assert!(event.is_none());
+        }
+    }
+}
@@ -5,29 +5,50 @@ use solana_sdk::{
     instruction::CompiledInstruction, pubkey::Pubkey, transaction::VersionedTransaction,
 };
 use solana_transaction_status::{
-    EncodedConfirmedTransactionWithStatusMeta, EncodedTransactionWithStatusMeta, InnerInstruction, InnerInstructions,
+    EncodedConfirmedTransactionWithStatusMeta, InnerInstruction, InnerInstructions,
     UiInstruction,
 };
-use std::collections::HashMap;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
+use super::address_lookup_table::resolve_address_table_lookups;
+use super::decode_monitor::get_decode_monitor;
+use super::program_upgrade::{
+    is_program_upgrade_instruction, parse_program_upgrade_instruction,
+    BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+};
 use super::global_state::{
-    add_bonk_dev_address, add_dev_address, is_bonk_dev_address, is_dev_address,
+    add_bonk_dev_address, add_dev_address, add_meteora_dbc_dev_address, add_moonshot_dev_address,
+    is_bonk_dev_address, is_dev_address, is_meteora_dbc_dev_address, is_moonshot_dev_address,
 };
 
 use crate::streaming::common::simd_utils::SimdUtils;
 use crate::streaming::event_parser::common::{
-    parse_swap_data_from_next_grpc_instructions, parse_swap_data_from_next_instructions, SwapData,
+    anchor_discriminator, collect_invoked_program_logs, invocation_chains_from_logs,
+    invocation_chains_from_stack_heights, parse_swap_data_from_next_grpc_instructions,
+    parse_swap_data_from_next_instructions, parse_transfer_datas_from_next_grpc_instructions,
+    parse_transfer_datas_from_next_instructions, resolve_swap_mints, swap_mints_unresolved,
+    to_snake_case, BalanceChanges, InvocationChains, RawTransaction, SwapData,
+    TokenAccountBalances, ANCHOR_INSTRUCTION_LOG_PREFIX, PROGRAM_DATA_PREFIX, TRADE_EVENT_TYPES,
 };
 use crate::streaming::event_parser::protocols::pumpswap::{PumpSwapBuyEvent, PumpSwapSellEvent};
 use crate::streaming::event_parser::{
     common::{EventMetadata, EventType, ProtocolType},
     protocols::{
         bonk::{BonkPoolCreateEvent, BonkTradeEvent},
+        jupiter_v6::parser::{link_route_child_events, link_route_child_events_grpc},
+        meteora_dbc::{MeteoraDbcInitializePoolEvent, MeteoraDbcSwapEvent},
+        moonshot::{MoonshotTokenMintEvent, MoonshotTradeEvent},
         pumpfun::{PumpFunCreateTokenEvent, PumpFunTradeEvent},
+        raydium_amm_v4::types::{apply_ray_log, collect_ray_logs, RayLog},
+        raydium_cpmm::{apply_swap_event_log, collect_swap_event_logs, RaydiumCpmmSwapEvent},
+        idl::{collect_idl_log_events, IdlEvent, IdlEventKind, IdlLogEvent, EVENT_IX_TAG},
+        raw::raw_instruction_event::{RawInstructionEvent, RawInstructionReason},
     },
 };
 
@@ -269,6 +290,12 @@ pub trait UnifiedEvent: Debug + Send + Sync {
 
     /// Get transaction index in slot
     fn transaction_index(&self) -> Option<u64>;
+
+    /// Get event metadata
+    fn metadata(&self) -> &EventMetadata;
+
+    /// Get mutable event metadata
+    fn metadata_mut(&mut self) -> &mut EventMetadata;
 }
 
 /// 事件解析器trait - 定义了事件解析的核心方法
@@ -467,11 +494,9 @@ pub trait EventParser: Send + Sync {
         inner_instructions: &[InnerInstructions],
         callback: Arc<dyn Fn(Box<dyn UnifiedEvent>) + Send + Sync>,
     ) -> anyhow::Result<()> {
-        // 创建适配器回调，将所有权回调转换为引用回调
-        let adapter_callback = Arc::new(move |event: &Box<dyn UnifiedEvent>| {
-            callback(event.clone_boxed());
-        });
-        self.parse_versioned_transaction(
+        // 所有权回调直接接收附加了交易上下文的事件副本
+        parse_versioned_transaction_with_sink(
+            self,
             &versioned_tx,
             signature,
             slot,
@@ -480,10 +505,9 @@ pub trait EventParser: Send + Sync {
             bot_wallet,
             transaction_index,
             inner_instructions,
-            adapter_callback,
+            EventSink::Owned(callback),
         )
-        .await?;
-        Ok(())
+        .await
     }
 
     async fn parse_versioned_transaction(
@@ -498,21 +522,19 @@ pub trait EventParser: Send + Sync {
         inner_instructions: &[InnerInstructions],
         callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
     ) -> anyhow::Result<()> {
-        let accounts: Vec<Pubkey> = versioned_tx.message.static_account_keys().to_vec();
-        self.parse_instruction_events_from_versioned_transaction(
+        parse_versioned_transaction_with_sink(
+            self,
             versioned_tx,
             signature,
             slot,
             block_time,
             recv_us,
-            &accounts,
-            inner_instructions,
             bot_wallet,
             transaction_index,
-            callback,
+            inner_instructions,
+            EventSink::Ref(callback),
         )
-        .await?;
-        Ok(())
+        .await
     }
 
     async fn parse_grpc_transaction_owned(
@@ -526,12 +548,9 @@ pub trait EventParser: Send + Sync {
         transaction_index: Option<u64>,
         callback: Arc<dyn Fn(Box<dyn UnifiedEvent>) + Send + Sync>,
     ) -> anyhow::Result<()> {
-        // 创建适配器回调，将所有权回调转换为引用回调
-        let adapter_callback = Arc::new(move |event: &Box<dyn UnifiedEvent>| {
-            callback(event.clone_boxed());
-        });
-        // 调用原始方法
-        self.parse_grpc_transaction(
+        // 所有权回调直接接收附加了交易上下文的事件副本
+        parse_grpc_transaction_with_sink(
+            self,
             grpc_tx,
             signature,
             slot,
@@ -539,7 +558,7 @@ pub trait EventParser: Send + Sync {
             recv_us,
             bot_wallet,
             transaction_index,
-            adapter_callback,
+            EventSink::Owned(callback),
         )
         .await
     }
@@ -555,90 +574,18 @@ pub trait EventParser: Send + Sync {
         transaction_index: Option<u64>,
         callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
     ) -> anyhow::Result<()> {
-        if let Some(transition) = grpc_tx.transaction {
-            if let Some(message) = &transition.message {
-                let mut address_table_lookups: Vec<Vec<u8>> = vec![];
-                let mut inner_instructions: Vec<
-                    yellowstone_grpc_proto::solana::storage::confirmed_block::InnerInstructions,
-                > = vec![];
-
-                if let Some(meta) = grpc_tx.meta {
-                    inner_instructions = meta.inner_instructions;
-                    address_table_lookups.reserve(
-                        meta.loaded_writable_addresses.len() + meta.loaded_writable_addresses.len(),
-                    );
-                    let loaded_writable_addresses = meta.loaded_writable_addresses;
-                    let loaded_readonly_addresses = meta.loaded_readonly_addresses;
-                    address_table_lookups.extend(
-                        loaded_writable_addresses.into_iter().chain(loaded_readonly_addresses),
-                    );
-                }
-
-                let mut accounts_bytes: Vec<Vec<u8>> =
-                    Vec::with_capacity(message.account_keys.len() + address_table_lookups.len());
-                accounts_bytes.extend_from_slice(&message.account_keys);
-                accounts_bytes.extend(address_table_lookups);
-                // 转换为 Pubkey
-                let accounts: Vec<Pubkey> = accounts_bytes
-                    .iter()
-                    .filter_map(|account| {
-                        if account.len() == 32 {
-                            Some(Pubkey::try_from(account.as_slice()).unwrap_or_default())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-                // 使用 Arc 包装共享数据，避免不必要的克隆
-                let accounts_arc = Arc::new(accounts);
-                let inner_instructions_arc = Arc::new(inner_instructions);
-                // 解析指令事件
-                let instructions = &message.instructions;
-                self.parse_instruction_events_from_grpc_transaction(
-                    &instructions,
-                    signature,
-                    slot,
-                    block_time,
-                    recv_us,
-                    &accounts_arc,
-                    &inner_instructions_arc,
-                    bot_wallet,
-                    transaction_index,
-                    callback.clone(),
-                )
-                .await?;
-
-                // 解析嵌套指令事件
-                for inner_instruction in inner_instructions_arc.iter() {
-                    for (index, instruction) in inner_instruction.instructions.iter().enumerate() {
-                        let accounts = &instruction.accounts;
-                        let data = &instruction.data;
-                        let instruction = yellowstone_grpc_proto::prelude::CompiledInstruction {
-                            program_id_index: instruction.program_id_index,
-                            accounts: accounts.to_vec(),
-                            data: data.to_vec(),
-                        };
-                        self.parse_grpc_instruction(
-                            &instruction,
-                            &accounts_arc,
-                            signature,
-                            slot,
-                            block_time,
-                            recv_us,
-                            inner_instruction.index as i64,
-                            Some(index as i64),
-                            bot_wallet,
-                            transaction_index,
-                            Some(&inner_instruction),
-                            callback.clone(),
-                        )
-                        .await?;
-                    }
-                }
-            }
-        }
-
-        Ok(())
+        parse_grpc_transaction_with_sink(
+            self,
+            grpc_tx,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            bot_wallet,
+            transaction_index,
+            EventSink::Ref(callback),
+        )
+        .await
     }
 
     async fn parse_encoded_confirmed_transaction_with_status_meta(
@@ -699,7 +646,7 @@ pub trait EventParser: Send + Sync {
 
         let meta = transaction.transaction.meta;
         let mut address_table_lookups: Vec<Pubkey> = vec![];
-        if let Some(meta) = meta {
+        if let Some(meta) = &meta {
             if let solana_transaction_status::option_serializer::OptionSerializer::Some(
                 loaded_addresses,
             ) = &meta.loaded_addresses
@@ -725,9 +672,39 @@ pub trait EventParser: Send + Sync {
         );
         accounts.extend_from_slice(versioned_tx.message.static_account_keys());
         accounts.extend(address_table_lookups);
+        let invocation_chains = invocation_chains_from_inner_instructions(
+            versioned_tx.message.instructions(),
+            &accounts,
+            inner_instructions,
+        )
+        .unwrap_or_else(|| match meta.as_ref().map(|meta| &meta.log_messages) {
+            Some(solana_transaction_status::option_serializer::OptionSerializer::Some(logs)) => {
+                invocation_chains_from_logs(logs)
+            }
+            _ => InvocationChains::new(),
+        });
         // 使用 Arc 包装共享数据，避免不必要的克隆
         let accounts_arc = Arc::new(accounts);
         let inner_instructions_arc = Arc::new(inner_instructions);
+        // 签名者余额变化与代币余额在事件需要时才解析
+        let meta = meta.map(Arc::new);
+        let (balance_meta, balance_accounts) = (meta.clone(), accounts_arc.clone());
+        let (token_meta, token_accounts) = (meta, accounts_arc.clone());
+        let callback = with_transaction_context(
+            EventSink::Ref(callback),
+            TransactionContext {
+                balance_changes: lazy_context(move || {
+                    balance_meta
+                        .and_then(|meta| BalanceChanges::from_ui_meta(&balance_accounts, 0, &meta))
+                }),
+                token_balances: lazy_context(move || {
+                    token_meta
+                        .map(|meta| TokenAccountBalances::from_ui_meta(&token_accounts, &meta))
+                }),
+                invocation_chains: lazy_context(move || invocation_chains),
+                ..Default::default()
+            },
+        );
 
         let slot = transaction.slot;
         let block_time = transaction.block_time.map(|t| Timestamp { seconds: t as i64, nanos: 0 });
@@ -866,56 +843,53 @@ pub trait EventParser: Send + Sync {
         )
     }
 
+    /// 仅凭交易日志解析事件，适用于 `logsSubscribe` 等只提供日志的数据源
+    ///
+    /// 按调用栈还原每条 `Program data:` 日志所属的指令位置，用事件鉴别器匹配解析配置；
+    /// 日志中的事件不含账户，账户字段保持默认值
+    #[allow(clippy::too_many_arguments)]
+    fn parse_events_from_logs(
+        &self,
+        log_messages: &[String],
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        bot_wallet: Option<Pubkey>,
+        callback: EventRefCallback,
+    ) -> anyhow::Result<()> {
+        parse_log_events(
+            &self.instruction_configs(),
+            &|program_id| self.should_handle(program_id),
+            log_messages,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            bot_wallet,
+            callback,
+        )
+    }
+
     /// 检查是否应该处理此程序ID
     fn should_handle(&self, program_id: &Pubkey) -> bool;
 
     /// 获取支持的程序ID列表
     fn supported_program_ids(&self) -> Vec<Pubkey>;
 
-    /// Process events with selective raw transaction data inclusion
-    /// Only includes raw transaction data for Raydium AMM V4 events to optimize performance
+    /// Process events and attach the shared raw transaction handle to each of them
     fn process_events_with_raw_data(
         &self,
         events: Vec<Box<dyn UnifiedEvent>>,
         bot_wallet: Option<Pubkey>,
-        complete_tx: Option<EncodedTransactionWithStatusMeta>,
+        raw_transaction: Option<RawTransaction>,
     ) -> Vec<Box<dyn UnifiedEvent>> {
-        // First, process events normally
-        let processed_events = self.process_events(events, bot_wallet);
-        
-        // Then, selectively add raw transaction data for Raydium AMM V4 events
-        if let Some(tx) = complete_tx {
-            self.add_selective_raw_data(processed_events, tx)
-        } else {
-            processed_events
-        }
-    }
-
-    /// Add raw transaction data selectively only for Raydium AMM V4 events
-    fn add_selective_raw_data(
-        &self,
-        mut events: Vec<Box<dyn UnifiedEvent>>,
-        tx: EncodedTransactionWithStatusMeta,
-    ) -> Vec<Box<dyn UnifiedEvent>> {
-        use crate::streaming::event_parser::protocols::raydium_amm_v4::RaydiumAmmV4SwapEvent;
-        use crate::streaming::event_parser::common::types::RawTransactionWrapper;
-        
-        // Check if we have any Raydium AMM V4 events
-        let has_raydium_amm_v4 = events.iter().any(|event| {
-            event.as_any().downcast_ref::<RaydiumAmmV4SwapEvent>().is_some()
-        });
-        
-        if has_raydium_amm_v4 {
-            let raw_wrapper = RawTransactionWrapper::new(tx);
-            
-            // Only add raw transaction data to Raydium AMM V4 events
+        let mut events = self.process_events(events, bot_wallet);
+        if let Some(raw_transaction) = raw_transaction {
             for event in &mut events {
-                if let Some(raydium_event) = event.as_any_mut().downcast_mut::<RaydiumAmmV4SwapEvent>() {
-                    raydium_event.metadata.raw_transaction = Some(raw_wrapper.clone());
-                }
+                event.metadata_mut().raw_transaction = Some(raw_transaction.clone());
             }
         }
-        
         events
     }
 
@@ -962,8 +936,12 @@ pub struct GenericEventParser {
     pub program_ids: Vec<Pubkey>,
     // pub inner_instruction_configs: HashMap<Vec<u8>, Vec<GenericEventParseConfig>>,
     pub instruction_configs: HashMap<Vec<u8>, Vec<GenericEventParseConfig>>,
+    /// 已知的 (程序ID, 指令或 CPI 事件鉴别器)，包含被事件类型过滤掉的配置，用于识别未知指令
+    pub known_discriminators: HashSet<(Pubkey, &'static [u8])>,
     /// 账户公钥缓存，避免重复分配
     pub account_cache: parking_lot::Mutex<AccountPubkeyCache>,
+    /// 是否解析可升级加载器指令并生成程序升级事件，默认关闭，由订阅按事件类型过滤开启
+    pub program_upgrade_enabled: bool,
 }
 
 impl GenericEventParser {
@@ -971,8 +949,10 @@ impl GenericEventParser {
     pub fn new(program_ids: Vec<Pubkey>, configs: Vec<GenericEventParseConfig>) -> Self {
         // 预分配容量，避免动态扩容
         let mut instruction_configs = HashMap::with_capacity(configs.len());
+        let mut known_discriminators = HashSet::with_capacity(configs.len());
 
         for config in configs {
+            known_discriminators.extend(Self::config_discriminators(&config));
             instruction_configs
                 .entry(config.instruction_discriminator.to_vec())
                 .or_insert_with(Vec::new)
@@ -982,7 +962,130 @@ impl GenericEventParser {
         // 初始化账户缓存
         let account_cache = parking_lot::Mutex::new(AccountPubkeyCache::new());
 
-        Self { program_ids, instruction_configs, account_cache }
+        Self {
+            program_ids,
+            instruction_configs,
+            known_discriminators,
+            account_cache,
+            program_upgrade_enabled: false,
+        }
+    }
+
+    /// 配置对应的 (程序ID, 鉴别器)
+    pub fn config_discriminators(
+        config: &GenericEventParseConfig,
+    ) -> impl Iterator<Item = (Pubkey, &'static [u8])> {
+        let program_id = config.program_id;
+        [config.instruction_discriminator, config.inner_instruction_discriminator]
+            .into_iter()
+            .filter(|discriminator| !discriminator.is_empty())
+            .map(move |discriminator| (program_id, discriminator))
+    }
+
+    /// 可升级加载器指令：升级协议程序或变更其升级权限时生成程序升级事件
+    #[allow(clippy::too_many_arguments)]
+    fn parse_program_upgrade_event(
+        &self,
+        data: &[u8],
+        account_indices: &[u8],
+        accounts: &[Pubkey],
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        outer_index: i64,
+        inner_index: Option<i64>,
+        transaction_index: Option<u64>,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let account_pubkeys: Vec<Pubkey> = account_indices
+            .iter()
+            .filter_map(|index| accounts.get(*index as usize).copied())
+            .collect();
+        let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
+        let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
+        let metadata = EventMetadata::new(
+            signature,
+            slot,
+            timestamp.seconds,
+            block_time_ms,
+            ProtocolType::Common,
+            EventType::ProgramUpgrade,
+            BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+            outer_index,
+            inner_index,
+            recv_us,
+            transaction_index,
+        );
+        parse_program_upgrade_instruction(data, &account_pubkeys, metadata)
+    }
+
+    /// 未能解析为事件的指令：鉴别器匹配但全部解码失败时计入失败次数，
+    /// 开启捕获时生成原始指令事件；匹配 CPI 事件鉴别器的内联指令不视为未知
+    #[allow(clippy::too_many_arguments)]
+    fn parse_raw_instruction_event(
+        &self,
+        program_id: Pubkey,
+        data: &[u8],
+        account_pubkeys: &[Pubkey],
+        matched_configs: &[(&Vec<u8>, &GenericEventParseConfig)],
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        outer_index: i64,
+        inner_index: Option<i64>,
+        transaction_index: Option<u64>,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let monitor = get_decode_monitor();
+        let decoded_by = matched_configs
+            .iter()
+            .filter(|(_, config)| config.instruction_parser.is_some())
+            .collect::<Vec<_>>();
+        for (disc, _) in &decoded_by {
+            monitor.record_failure(program_id, disc);
+        }
+        if !monitor.capture_raw_instructions() {
+            return None;
+        }
+        let (reason, protocol, discriminator) = match decoded_by.first() {
+            Some((disc, config)) => {
+                (RawInstructionReason::DecodeFailed, config.protocol_type.clone(), disc.to_vec())
+            }
+            None if matched_configs.is_empty()
+                && !self.known_discriminators.iter().any(|(program, disc)| {
+                    *program == program_id && data.starts_with(disc)
+                }) =>
+            {
+                // CPI 事件取事件标记之后的 8 字节
+                let len = if data.starts_with(EVENT_IX_TAG) { 16 } else { 8 };
+                let discriminator = data[..data.len().min(len)].to_vec();
+                (RawInstructionReason::UnknownInstruction, ProtocolType::Common, discriminator)
+            }
+            None => return None,
+        };
+        let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
+        let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
+        let metadata = EventMetadata::new(
+            signature,
+            slot,
+            timestamp.seconds,
+            block_time_ms,
+            protocol,
+            EventType::RawInstruction,
+            program_id,
+            outer_index,
+            inner_index,
+            recv_us,
+            transaction_index,
+        );
+        Some(Box::new(RawInstructionEvent {
+            metadata,
+            reason,
+            program_id,
+            discriminator,
+            data: data.to_vec(),
+            accounts: account_pubkeys.to_vec(),
+        }))
     }
 
     /// 通用的内联指令解析方法
@@ -1082,6 +1185,12 @@ impl EventParser for GenericEventParser {
         if !SimdUtils::validate_instruction_data_simd(&inner_instruction.data, 16, 0) {
             return Vec::new();
         }
+        // 只解析与配置的事件鉴别器一致的 CPI 日志
+        if !config.inner_instruction_discriminator.is_empty()
+            && !inner_instruction.data.starts_with(config.inner_instruction_discriminator)
+        {
+            return Vec::new();
+        }
         let data = &inner_instruction.data[16..];
         let mut events = Vec::new();
         if let Some(event) = self.parse_inner_instruction_event(
@@ -1096,6 +1205,11 @@ impl EventParser for GenericEventParser {
             transaction_index,
         ) {
             events.push(event);
+        } else if !config.inner_instruction_discriminator.is_empty()
+            && config.inner_instruction_parser.is_some()
+        {
+            get_decode_monitor()
+                .record_failure(config.program_id, config.inner_instruction_discriminator);
         }
         events
     }
@@ -1118,6 +1232,12 @@ impl EventParser for GenericEventParser {
         if !SimdUtils::validate_instruction_data_simd(&inner_instruction.data, 16, 0) {
             return Vec::new();
         }
+        // 只解析与配置的事件鉴别器一致的 CPI 日志
+        if !config.inner_instruction_discriminator.is_empty()
+            && !inner_instruction.data.starts_with(config.inner_instruction_discriminator)
+        {
+            return Vec::new();
+        }
         let data = &inner_instruction.data[16..];
         let mut events = Vec::new();
         if let Some(event) = self.parse_inner_instruction_event(
@@ -1132,6 +1252,11 @@ impl EventParser for GenericEventParser {
             transaction_index,
         ) {
             events.push(event);
+        } else if !config.inner_instruction_discriminator.is_empty()
+            && config.inner_instruction_parser.is_some()
+        {
+            get_decode_monitor()
+                .record_failure(config.program_id, config.inner_instruction_discriminator);
         }
         events
     }
@@ -1154,6 +1279,25 @@ impl EventParser for GenericEventParser {
         callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
     ) -> anyhow::Result<()> {
         let program_id = accounts[instruction.program_id_index as usize];
+        // 内置程序升级监控
+        if self.program_upgrade_enabled && is_program_upgrade_instruction(&program_id) {
+            if let Some(mut event) = self.parse_program_upgrade_event(
+                &instruction.data,
+                &instruction.accounts,
+                accounts,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                transaction_index,
+            ) {
+                event.set_handle_us(elapsed_micros_since(recv_us));
+                callback(&event);
+            }
+            return Ok(());
+        }
         if !self.should_handle(&program_id) {
             return Ok(());
         }
@@ -1206,6 +1350,27 @@ impl EventParser for GenericEventParser {
             })
             .collect();
 
+        // 未匹配或解码失败的指令
+        if all_results.is_empty() {
+            if let Some(mut event) = self.parse_raw_instruction_event(
+                program_id,
+                &instruction.data,
+                &account_pubkeys,
+                &all_processing_params,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                transaction_index,
+            ) {
+                event.set_handle_us(elapsed_micros_since(recv_us));
+                callback(&event);
+            }
+            return Ok(());
+        }
+
         for (_disc, config, mut event) in all_results {
             // 阻塞处理：原有的同步逻辑
             let mut inner_instruction_event: Option<Box<dyn UnifiedEvent>> = None;
@@ -1255,6 +1420,19 @@ impl EventParser for GenericEventParser {
                 if let Some(swap_data) = swap_data_result {
                     event.set_swap_data(swap_data);
                 }
+                // 记录事件 CPI 子树中的转账
+                event.metadata_mut().transfer_datas = parse_transfer_datas_from_next_instructions(
+                    inner_instructions_ref,
+                    inner_index.map(|index| index as usize),
+                    accounts,
+                );
+                // 聚合器路由关联其调用的 AMM 事件
+                link_route_child_events(
+                    &mut *event,
+                    inner_instructions_ref,
+                    inner_index.map(|index| index as usize),
+                    accounts,
+                );
             }
             // 合并事件
             if let Some(inner_instruction_event) = inner_instruction_event {
@@ -1289,6 +1467,25 @@ impl EventParser for GenericEventParser {
         callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
     ) -> anyhow::Result<()> {
         let program_id = accounts[instruction.program_id_index as usize];
+        // 内置程序升级监控
+        if self.program_upgrade_enabled && is_program_upgrade_instruction(&program_id) {
+            if let Some(mut event) = self.parse_program_upgrade_event(
+                &instruction.data,
+                &instruction.accounts,
+                accounts,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                transaction_index,
+            ) {
+                event.set_handle_us(elapsed_micros_since(recv_us));
+                callback(&event);
+            }
+            return Ok(());
+        }
         if !self.should_handle(&program_id) {
             return Ok(());
         }
@@ -1341,6 +1538,27 @@ impl EventParser for GenericEventParser {
             })
             .collect();
 
+        // 未匹配或解码失败的指令
+        if all_results.is_empty() {
+            if let Some(mut event) = self.parse_raw_instruction_event(
+                program_id,
+                &instruction.data,
+                &account_pubkeys,
+                &all_processing_params,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                transaction_index,
+            ) {
+                event.set_handle_us(elapsed_micros_since(recv_us));
+                callback(&event);
+            }
+            return Ok(());
+        }
+
         for (_disc, config, mut event) in all_results {
             // 阻塞处理：原有的同步逻辑
             let mut inner_instruction_event: Option<Box<dyn UnifiedEvent>> = None;
@@ -1390,6 +1608,20 @@ impl EventParser for GenericEventParser {
                 if let Some(swap_data) = swap_data_result {
                     event.set_swap_data(swap_data);
                 }
+                // 记录事件 CPI 子树中的转账
+                event.metadata_mut().transfer_datas =
+                    parse_transfer_datas_from_next_grpc_instructions(
+                        inner_instructions_ref,
+                        inner_index.map(|index| index as usize),
+                        accounts,
+                    );
+                // 聚合器路由关联其调用的 AMM 事件
+                link_route_child_events_grpc(
+                    &mut *event,
+                    inner_instructions_ref,
+                    inner_index.map(|index| index as usize),
+                    accounts,
+                );
             }
             // 合并事件
             if let Some(inner_instruction_event) = inner_instruction_event {
@@ -1405,8 +1637,33 @@ impl EventParser for GenericEventParser {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn parse_events_from_logs(
+        &self,
+        log_messages: &[String],
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        bot_wallet: Option<Pubkey>,
+        callback: EventRefCallback,
+    ) -> anyhow::Result<()> {
+        parse_log_events(
+            &self.instruction_configs,
+            &|program_id| self.should_handle(program_id),
+            log_messages,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            bot_wallet,
+            callback,
+        )
+    }
+
     fn should_handle(&self, program_id: &Pubkey) -> bool {
         self.program_ids.contains(program_id)
+            || (self.program_upgrade_enabled && is_program_upgrade_instruction(program_id))
     }
 
     fn supported_program_ids(&self) -> Vec<Pubkey> {
@@ -1414,6 +1671,484 @@ impl EventParser for GenericEventParser {
     }
 }
 
+/// 配置对应的日志事件鉴别器：CPI 事件配置取事件鉴别器，
+/// `emit_cpi!` 事件指令配置取去掉前缀后的部分
+fn log_event_discriminator(config: &GenericEventParseConfig) -> Option<&'static [u8]> {
+    if config.inner_instruction_parser.is_some()
+        && config.inner_instruction_discriminator.len() == 16
+    {
+        return Some(&config.inner_instruction_discriminator[8..]);
+    }
+    if config.instruction_parser.is_some()
+        && config.instruction_discriminator.len() == 16
+        && config.instruction_discriminator.starts_with(EVENT_IX_TAG)
+    {
+        return Some(&config.instruction_discriminator[8..]);
+    }
+    None
+}
+
+/// 从日志中解析事件，优先选用 Anchor `Instruction:` 日志对应指令下的配置，
+/// 以区分共享同一事件的指令（如买入与卖出）
+#[allow(clippy::too_many_arguments)]
+fn parse_log_events(
+    instruction_configs: &HashMap<Vec<u8>, Vec<GenericEventParseConfig>>,
+    should_handle: &dyn Fn(&Pubkey) -> bool,
+    log_messages: &[String],
+    signature: Signature,
+    slot: u64,
+    block_time: Option<Timestamp>,
+    recv_us: i64,
+    bot_wallet: Option<Pubkey>,
+    callback: EventRefCallback,
+) -> anyhow::Result<()> {
+    let data_logs = collect_invoked_program_logs(log_messages, PROGRAM_DATA_PREFIX);
+    if data_logs.is_empty() {
+        return Ok(());
+    }
+    let instruction_names: HashMap<(i64, Option<i64>), &str> =
+        collect_invoked_program_logs(log_messages, ANCHOR_INSTRUCTION_LOG_PREFIX)
+            .into_iter()
+            .map(|(_, outer_index, inner_index, name)| ((outer_index, inner_index), name))
+            .collect();
+    let invocation_chains = invocation_chains_from_logs(log_messages);
+    let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
+    let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
+
+    for (program, outer_index, inner_index, log) in data_logs {
+        let Ok(program_id) = Pubkey::from_str(program) else {
+            continue;
+        };
+        if !should_handle(&program_id) {
+            continue;
+        }
+        let Ok(data) = STANDARD.decode(log) else {
+            continue;
+        };
+        let matches = |config: &&GenericEventParseConfig| {
+            config.program_id == program_id
+                && log_event_discriminator(config).is_some_and(|disc| data.starts_with(disc))
+        };
+        let instruction_config =
+            instruction_names.get(&(outer_index, inner_index)).and_then(|name| {
+                let discriminator = anchor_discriminator("global", &to_snake_case(name));
+                instruction_configs.get(discriminator.as_slice())?.iter().find(matches)
+            });
+        // 无指令名日志时按指令鉴别器取第一个匹配的配置，保证结果稳定
+        let Some(config) = instruction_config.or_else(|| {
+            instruction_configs
+                .values()
+                .flatten()
+                .filter(matches)
+                .min_by_key(|config| config.instruction_discriminator)
+        }) else {
+            continue;
+        };
+        let mut metadata = EventMetadata::new(
+            signature,
+            slot,
+            timestamp.seconds,
+            block_time_ms,
+            config.protocol_type.clone(),
+            config.event_type.clone(),
+            config.program_id,
+            outer_index,
+            inner_index,
+            recv_us,
+            None,
+        );
+        if let Some((stack_height, invoking_programs)) =
+            invocation_chains.get(&(outer_index, inner_index))
+        {
+            metadata.stack_height = Some(*stack_height);
+            metadata.invoking_programs = invoking_programs.clone();
+        }
+        let event = match (config.inner_instruction_parser, config.instruction_parser) {
+            (Some(parser), _) if config.inner_instruction_discriminator.len() == 16 => {
+                parser(&data[8..], metadata)
+            }
+            (_, Some(parser)) => parser(&data[8..], &[], metadata),
+            _ => None,
+        };
+        match event {
+            Some(mut event) => {
+                event.set_handle_us(elapsed_micros_since(recv_us));
+                callback(&process_event(event, bot_wallet));
+            }
+            None => get_decode_monitor()
+                .record_failure(program_id, log_event_discriminator(config).unwrap_or_default()),
+        }
+    }
+    Ok(())
+}
+
+/// 事件引用回调
+type EventRefCallback = Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>;
+
+/// 事件所有权回调
+type EventOwnedCallback = Arc<dyn Fn(Box<dyn UnifiedEvent>) + Send + Sync>;
+
+/// 附加交易级上下文后的事件去向
+enum EventSink {
+    /// 引用回调：每个事件都会复制一份写入上下文后再交给回调
+    Ref(EventRefCallback),
+    /// 所有权回调：上下文直接写入交给回调的那一份副本
+    Owned(EventOwnedCallback),
+}
+
+/// 按需计算的交易级数据，首个用到它的事件触发计算
+type LazyContext<T> = once_cell::sync::Lazy<T, Box<dyn FnOnce() -> T + Send>>;
+
+/// 按 (外层指令索引, 内层指令索引) 归集的数据
+type ByPosition<T> = HashMap<(i64, Option<i64>), T>;
+
+fn lazy_context<T>(init: impl FnOnce() -> T + Send + 'static) -> LazyContext<T> {
+    once_cell::sync::Lazy::new(Box::new(init))
+}
+
+/// 交易级上下文，解析完成后附加到事件
+struct TransactionContext {
+    raw_transaction: Option<RawTransaction>,
+    balance_changes: LazyContext<Option<BalanceChanges>>,
+    /// 代币账户余额，用于补全交易事件缺失的 mint
+    token_balances: LazyContext<Option<TokenAccountBalances>>,
+    unresolved_lookup_tables: Vec<Pubkey>,
+    /// Raydium AMM v4 的 `ray_log`，按 (外层指令索引, 内层指令索引) 归集
+    ray_logs: LazyContext<ByPosition<RayLog>>,
+    /// Raydium CPMM 日志中的 SwapEvent，按 (外层指令索引, 内层指令索引) 归集
+    cpmm_swap_logs: LazyContext<ByPosition<RaydiumCpmmSwapEvent>>,
+    /// 已加载 IDL 的程序以 `Program data:` 输出的事件，按 (外层指令索引, 内层指令索引) 归集
+    idl_log_events: LazyContext<ByPosition<Vec<IdlLogEvent>>>,
+    /// 内层指令的调用栈高度与调用链，按 (外层指令索引, 内层指令索引) 归集
+    invocation_chains: LazyContext<InvocationChains>,
+}
+
+impl Default for TransactionContext {
+    fn default() -> Self {
+        Self {
+            raw_transaction: None,
+            balance_changes: lazy_context(|| None),
+            token_balances: lazy_context(|| None),
+            unresolved_lookup_tables: Vec::new(),
+            ray_logs: lazy_context(HashMap::new),
+            cpmm_swap_logs: lazy_context(HashMap::new),
+            idl_log_events: lazy_context(HashMap::new),
+            invocation_chains: lazy_context(InvocationChains::new),
+        }
+    }
+}
+
+impl TransactionContext {
+    /// gRPC 交易的上下文，余额与日志均从共享的原始交易按需解析
+    fn from_grpc(raw_transaction: RawTransaction, accounts: Arc<Vec<Pubkey>>) -> Self {
+        let raw = raw_transaction.clone();
+        let keys = accounts.clone();
+        let balance_changes = lazy_context(move || {
+            raw.meta().and_then(|meta| BalanceChanges::from_grpc_meta(&keys, 0, meta))
+        });
+        let raw = raw_transaction.clone();
+        let keys = accounts.clone();
+        let token_balances = lazy_context(move || {
+            raw.meta().map(|meta| TokenAccountBalances::from_grpc_meta(&keys, meta))
+        });
+        let raw = raw_transaction.clone();
+        let ray_logs = lazy_context(move || collect_ray_logs(raw.log_messages()));
+        let raw = raw_transaction.clone();
+        let cpmm_swap_logs = lazy_context(move || collect_swap_event_logs(raw.log_messages()));
+        let raw = raw_transaction.clone();
+        let idl_log_events = lazy_context(move || collect_idl_log_events(raw.log_messages()));
+        let raw = raw_transaction.clone();
+        // 旧节点不返回 stack_height 时由日志嵌套还原调用链
+        let invocation_chains = lazy_context(move || {
+            let instructions = raw
+                .inner()
+                .transaction
+                .as_ref()
+                .and_then(|transaction| transaction.message.as_ref())
+                .map(|message| message.instructions.as_slice())
+                .unwrap_or_default();
+            invocation_chains_from_grpc_inner_instructions(
+                instructions,
+                &accounts,
+                raw.inner_instructions(),
+            )
+            .unwrap_or_else(|| invocation_chains_from_logs(raw.log_messages()))
+        });
+        Self {
+            raw_transaction: Some(raw_transaction),
+            balance_changes,
+            token_balances,
+            ray_logs,
+            cpmm_swap_logs,
+            idl_log_events,
+            invocation_chains,
+            ..Default::default()
+        }
+    }
+
+    /// 写入上下文：原始交易句柄、签名者余额变化、未解析的查找表，AMM v4 `ray_log` /
+    /// CPMM SwapEvent 的实际数量、IDL 指令的日志事件、内层指令的调用链，
+    /// 并补全 Raydium CLMM v1 / AMM v4 交易缺失的 mint；日志与余额仅在事件类型需要时解析
+    fn apply(&self, event: &mut dyn UnifiedEvent) {
+        let position = (event.outer_index(), event.inner_index());
+        match &event.metadata().protocol {
+            ProtocolType::RaydiumAmmV4 => {
+                if let Some(ray_log) = self.ray_logs.get(&position) {
+                    apply_ray_log(event, ray_log);
+                }
+            }
+            ProtocolType::RaydiumCpmm => {
+                if let Some(cpmm_swap_log) = self.cpmm_swap_logs.get(&position) {
+                    apply_swap_event_log(event, cpmm_swap_log);
+                }
+            }
+            ProtocolType::Custom(_) => {
+                if let Some(e) = event.as_any_mut().downcast_mut::<IdlEvent>() {
+                    if e.kind == IdlEventKind::Instruction {
+                        if let Some(idl_log_events) = self.idl_log_events.get(&position) {
+                            e.log_events = idl_log_events.clone();
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        let needs_mints = swap_mints_unresolved(event);
+        let metadata = event.metadata_mut();
+        if self.raw_transaction.is_some() {
+            metadata.raw_transaction = self.raw_transaction.clone();
+        }
+        if TRADE_EVENT_TYPES.contains(&metadata.event_type) {
+            metadata.balance_changes = (*self.balance_changes).clone();
+        }
+        if !self.unresolved_lookup_tables.is_empty() {
+            metadata.unresolved_lookup_tables = self.unresolved_lookup_tables.clone();
+        }
+        if position.1.is_some() {
+            if let Some((stack_height, invoking_programs)) = self.invocation_chains.get(&position)
+            {
+                metadata.stack_height = Some(*stack_height);
+                metadata.invoking_programs = invoking_programs.clone();
+            }
+        }
+        if needs_mints {
+            resolve_swap_mints(event, self.token_balances.as_ref());
+        }
+    }
+}
+
+/// 由内层指令还原调用链，缺少 `stack_height` 时返回 None
+fn invocation_chains_from_inner_instructions(
+    instructions: &[CompiledInstruction],
+    accounts: &[Pubkey],
+    inner_instructions: &[InnerInstructions],
+) -> Option<InvocationChains> {
+    let program_at = |index: u8| accounts.get(index as usize).copied().unwrap_or_default();
+    invocation_chains_from_stack_heights(inner_instructions.iter().map(|inner| {
+        let outer_program = instructions
+            .get(inner.index as usize)
+            .map(|instruction| program_at(instruction.program_id_index))
+            .unwrap_or_default();
+        let programs = inner
+            .instructions
+            .iter()
+            .map(|ix| (program_at(ix.instruction.program_id_index), ix.stack_height))
+            .collect();
+        (inner.index as i64, outer_program, programs)
+    }))
+}
+
+/// 由 gRPC 内层指令还原调用链，缺少 `stack_height` 时返回 None
+fn invocation_chains_from_grpc_inner_instructions(
+    instructions: &[yellowstone_grpc_proto::prelude::CompiledInstruction],
+    accounts: &[Pubkey],
+    inner_instructions: &[yellowstone_grpc_proto::prelude::InnerInstructions],
+) -> Option<InvocationChains> {
+    let program_at = |index: u32| accounts.get(index as usize).copied().unwrap_or_default();
+    invocation_chains_from_stack_heights(inner_instructions.iter().map(|inner| {
+        let outer_program = instructions
+            .get(inner.index as usize)
+            .map(|instruction| program_at(instruction.program_id_index))
+            .unwrap_or_default();
+        let programs = inner
+            .instructions
+            .iter()
+            .map(|ix| (program_at(ix.program_id_index), ix.stack_height))
+            .collect();
+        (inner.index as i64, outer_program, programs)
+    }))
+}
+
+/// 解析 VersionedTransaction 并将附加了交易上下文的事件交给回调
+#[allow(clippy::too_many_arguments)]
+async fn parse_versioned_transaction_with_sink<P: EventParser + ?Sized>(
+    parser: &P,
+    versioned_tx: &VersionedTransaction,
+    signature: Signature,
+    slot: Option<u64>,
+    block_time: Option<Timestamp>,
+    recv_us: i64,
+    bot_wallet: Option<Pubkey>,
+    transaction_index: Option<u64>,
+    inner_instructions: &[InnerInstructions],
+    sink: EventSink,
+) -> anyhow::Result<()> {
+    let static_account_keys = versioned_tx.message.static_account_keys();
+    let mut context = TransactionContext::default();
+    let accounts: Vec<Pubkey> = match versioned_tx.message.address_table_lookups() {
+        Some(lookups) if !lookups.is_empty() => {
+            // 通过地址查找表缓存解析v0交易的动态账户
+            let resolved = resolve_address_table_lookups(lookups);
+            let mut accounts = Vec::with_capacity(
+                static_account_keys.len() + resolved.writable.len() + resolved.readonly.len(),
+            );
+            accounts.extend_from_slice(static_account_keys);
+            accounts.extend(resolved.writable);
+            accounts.extend(resolved.readonly);
+            // 标记事件中存在未解析的地址
+            context.unresolved_lookup_tables = resolved.unresolved_tables;
+            accounts
+        }
+        _ => static_account_keys.to_vec(),
+    };
+    if !inner_instructions.is_empty() {
+        let invocation_chains = invocation_chains_from_inner_instructions(
+            versioned_tx.message.instructions(),
+            &accounts,
+            inner_instructions,
+        )
+        .unwrap_or_default();
+        context.invocation_chains = lazy_context(move || invocation_chains);
+    }
+    let callback = with_transaction_context(sink, context);
+    parser
+        .parse_instruction_events_from_versioned_transaction(
+            versioned_tx,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            &accounts,
+            inner_instructions,
+            bot_wallet,
+            transaction_index,
+            callback,
+        )
+        .await
+}
+
+/// 解析 gRPC 交易并将附加了交易上下文的事件交给回调
+#[allow(clippy::too_many_arguments)]
+async fn parse_grpc_transaction_with_sink<P: EventParser + ?Sized>(
+    parser: &P,
+    grpc_tx: SubscribeUpdateTransactionInfo,
+    signature: Signature,
+    slot: Option<u64>,
+    block_time: Option<Timestamp>,
+    recv_us: i64,
+    bot_wallet: Option<Pubkey>,
+    transaction_index: Option<u64>,
+    sink: EventSink,
+) -> anyhow::Result<()> {
+    // 原始交易只包装一次，事件通过共享句柄按需访问
+    let raw_transaction = RawTransaction::new(Arc::new(grpc_tx));
+    let grpc_tx = raw_transaction.inner();
+    let Some(message) = grpc_tx.transaction.as_ref().and_then(|tx| tx.message.as_ref()) else {
+        return Ok(());
+    };
+    let inner_instructions = raw_transaction.inner_instructions();
+    let loaded_addresses = raw_transaction
+        .meta()
+        .map(|meta| {
+            meta.loaded_writable_addresses.iter().chain(meta.loaded_readonly_addresses.iter())
+        })
+        .into_iter()
+        .flatten();
+
+    // 转换为 Pubkey
+    let accounts: Arc<Vec<Pubkey>> = Arc::new(
+        message
+            .account_keys
+            .iter()
+            .chain(loaded_addresses)
+            .filter_map(|account| {
+                if account.len() == 32 {
+                    Some(Pubkey::try_from(account.as_slice()).unwrap_or_default())
+                } else {
+                    None
+                }
+            })
+            .collect(),
+    );
+    // 余额、日志与调用链在事件需要时才解析
+    let callback = with_transaction_context(
+        sink,
+        TransactionContext::from_grpc(raw_transaction.clone(), accounts.clone()),
+    );
+    // 解析指令事件
+    parser
+        .parse_instruction_events_from_grpc_transaction(
+            &message.instructions,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            &accounts,
+            inner_instructions,
+            bot_wallet,
+            transaction_index,
+            callback.clone(),
+        )
+        .await?;
+
+    // 解析嵌套指令事件
+    for inner_instruction in inner_instructions.iter() {
+        for (index, instruction) in inner_instruction.instructions.iter().enumerate() {
+            let instruction = yellowstone_grpc_proto::prelude::CompiledInstruction {
+                program_id_index: instruction.program_id_index,
+                accounts: instruction.accounts.to_vec(),
+                data: instruction.data.to_vec(),
+            };
+            parser
+                .parse_grpc_instruction(
+                    &instruction,
+                    &accounts,
+                    signature,
+                    slot,
+                    block_time,
+                    recv_us,
+                    inner_instruction.index as i64,
+                    Some(index as i64),
+                    bot_wallet,
+                    transaction_index,
+                    Some(inner_instruction),
+                    callback.clone(),
+                )
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+/// 包装回调，为事件附加交易级上下文
+///
+/// gRPC 交易的每个事件都会写入原始交易句柄，因此引用回调同样总是复制事件
+#[allow(clippy::borrowed_box)]
+fn with_transaction_context(sink: EventSink, context: TransactionContext) -> EventRefCallback {
+    Arc::new(move |event: &Box<dyn UnifiedEvent>| match &sink {
+        EventSink::Ref(callback) => {
+            let mut event = event.clone_boxed();
+            context.apply(&mut *event);
+            callback(&event);
+        }
+        EventSink::Owned(callback) => {
+            let mut event = event.clone_boxed();
+            context.apply(&mut *event);
+            callback(event);
+        }
+    })
+}
+
 fn process_event(
     mut event: Box<dyn UnifiedEvent>,
     bot_wallet: Option<Pubkey>,
@@ -1460,6 +2195,39 @@ fn process_event(
         } else {
             trade_info.is_dev_create_token_trade = false;
         }
+    } else if let Some(pool_info) = event.as_any().downcast_ref::<MeteoraDbcInitializePoolEvent>()
+    {
+        add_meteora_dbc_dev_address(slot, pool_info.creator);
+    } else if let Some(trade_info) = event.as_any_mut().downcast_mut::<MeteoraDbcSwapEvent>() {
+        if is_meteora_dbc_dev_address(&trade_info.payer) {
+            trade_info.is_dev_create_token_trade = true;
+        } else if Some(trade_info.payer) == bot_wallet {
+            trade_info.is_bot = true;
+        } else {
+            trade_info.is_dev_create_token_trade = false;
+        }
+    } else if let Some(token_info) = event.as_any().downcast_ref::<MoonshotTokenMintEvent>() {
+        add_moonshot_dev_address(slot, token_info.sender);
+    } else if let Some(trade_info) = event.as_any_mut().downcast_mut::<MoonshotTradeEvent>() {
+        if is_moonshot_dev_address(&trade_info.sender) {
+            trade_info.is_dev_create_token_trade = true;
+        } else if Some(trade_info.sender) == bot_wallet {
+            trade_info.is_bot = true;
+        } else {
+            trade_info.is_dev_create_token_trade = false;
+        }
+        if let Some(swap_data) = trade_info.metadata.swap_data.as_mut() {
+            swap_data.from_amount = if trade_info.is_buy {
+                trade_info.collateral_amount
+            } else {
+                trade_info.token_amount
+            };
+            swap_data.to_amount = if trade_info.is_buy {
+                trade_info.token_amount
+            } else {
+                trade_info.collateral_amount
+            };
+        }
     }
     event
 }
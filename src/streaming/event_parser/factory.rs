@@ -1,13 +1,14 @@
 use anyhow::{anyhow, Result};
+use parking_lot::RwLock;
 use solana_sdk::pubkey::Pubkey;
 use std::{collections::HashMap, sync::{Arc, LazyLock}};
 
 use crate::streaming::event_parser::protocols::{
-    bonk::parser::BONK_PROGRAM_ID, pumpfun::parser::PUMPFUN_PROGRAM_ID, pumpswap::parser::PUMPSWAP_PROGRAM_ID, raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID, raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID, raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID, BonkEventParser, RaydiumAmmV4EventParser, RaydiumClmmEventParser, RaydiumCpmmEventParser
+    bonk::parser::BONK_PROGRAM_ID, pumpfun::parser::PUMPFUN_PROGRAM_ID, pumpswap::parser::PUMPSWAP_PROGRAM_ID, raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID, raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID, raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID, meteora_dlmm::parser::METEORA_DLMM_PROGRAM_ID, meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID, meteora_dbc::parser::METEORA_DBC_PROGRAM_ID, orca_whirlpool::parser::ORCA_WHIRLPOOL_PROGRAM_ID, jupiter_v6::parser::JUPITER_V6_PROGRAM_ID, openbook_v2::parser::OPENBOOK_V2_PROGRAM_ID, phoenix::parser::PHOENIX_PROGRAM_ID, moonshot::parser::MOONSHOT_PROGRAM_ID, BonkEventParser, JupiterV6EventParser, MeteoraDammV2EventParser, MeteoraDbcEventParser, MeteoraDlmmEventParser, MoonshotEventParser, OpenBookV2EventParser, OrcaWhirlpoolEventParser, PhoenixEventParser, RaydiumAmmV4EventParser, RaydiumClmmEventParser, RaydiumCpmmEventParser
 };
 
 use super::{
-    core::traits::EventParser,
+    core::{account_event_parser::AccountEventParseConfig, traits::EventParser},
     protocols::{pumpfun::PumpFunEventParser, pumpswap::PumpSwapEventParser},
 };
 
@@ -20,8 +21,36 @@ pub enum Protocol {
     RaydiumCpmm,
     RaydiumClmm,
     RaydiumAmmV4,
+    MeteoraDlmm,
+    MeteoraDammV2,
+    MeteoraDbc,
+    OrcaWhirlpool,
+    JupiterV6,
+    OpenBookV2,
+    Phoenix,
+    Moonshot,
+    /// 通过 [`EventParserFactory::register_custom_protocol`] 注册的自定义协议
+    Custom(String),
 }
 
+/// 内置协议列表
+const BUILTIN_PROTOCOLS: &[Protocol] = &[
+    Protocol::PumpSwap,
+    Protocol::PumpFun,
+    Protocol::Bonk,
+    Protocol::RaydiumCpmm,
+    Protocol::RaydiumClmm,
+    Protocol::RaydiumAmmV4,
+    Protocol::MeteoraDlmm,
+    Protocol::MeteoraDammV2,
+    Protocol::MeteoraDbc,
+    Protocol::OrcaWhirlpool,
+    Protocol::JupiterV6,
+    Protocol::OpenBookV2,
+    Protocol::Phoenix,
+    Protocol::Moonshot,
+];
+
 impl Protocol {
     pub fn get_program_id(&self) -> Vec<Pubkey> {
         match self {
@@ -31,6 +60,19 @@ impl Protocol {
             Protocol::RaydiumCpmm => vec![RAYDIUM_CPMM_PROGRAM_ID],
             Protocol::RaydiumClmm => vec![RAYDIUM_CLMM_PROGRAM_ID],
             Protocol::RaydiumAmmV4 => vec![RAYDIUM_AMM_V4_PROGRAM_ID],
+            Protocol::MeteoraDlmm => vec![METEORA_DLMM_PROGRAM_ID],
+            Protocol::MeteoraDammV2 => vec![METEORA_DAMM_V2_PROGRAM_ID],
+            Protocol::MeteoraDbc => vec![METEORA_DBC_PROGRAM_ID],
+            Protocol::OrcaWhirlpool => vec![ORCA_WHIRLPOOL_PROGRAM_ID],
+            Protocol::JupiterV6 => vec![JUPITER_V6_PROGRAM_ID],
+            Protocol::OpenBookV2 => vec![OPENBOOK_V2_PROGRAM_ID],
+            Protocol::Phoenix => vec![PHOENIX_PROGRAM_ID],
+            Protocol::Moonshot => vec![MOONSHOT_PROGRAM_ID],
+            Protocol::Custom(name) => CUSTOM_PROTOCOLS
+                .read()
+                .get(name)
+                .map(|custom| custom.parser.supported_program_ids())
+                .unwrap_or_default(),
         }
     }
 }
@@ -44,6 +86,15 @@ impl std::fmt::Display for Protocol {
             Protocol::RaydiumCpmm => write!(f, "RaydiumCpmm"),
             Protocol::RaydiumClmm => write!(f, "RaydiumClmm"),
             Protocol::RaydiumAmmV4 => write!(f, "RaydiumAmmV4"),
+            Protocol::MeteoraDlmm => write!(f, "MeteoraDlmm"),
+            Protocol::MeteoraDammV2 => write!(f, "MeteoraDammV2"),
+            Protocol::MeteoraDbc => write!(f, "MeteoraDbc"),
+            Protocol::OrcaWhirlpool => write!(f, "OrcaWhirlpool"),
+            Protocol::JupiterV6 => write!(f, "JupiterV6"),
+            Protocol::OpenBookV2 => write!(f, "OpenBookV2"),
+            Protocol::Phoenix => write!(f, "Phoenix"),
+            Protocol::Moonshot => write!(f, "Moonshot"),
+            Protocol::Custom(name) => write!(f, "{name}"),
         }
     }
 }
@@ -59,6 +110,15 @@ impl std::str::FromStr for Protocol {
             "raydiumcpmm" => Ok(Protocol::RaydiumCpmm),
             "raydiumclmm" => Ok(Protocol::RaydiumClmm),
             "raydiumammv4" => Ok(Protocol::RaydiumAmmV4),
+            "meteoradlmm" => Ok(Protocol::MeteoraDlmm),
+            "meteoradammv2" => Ok(Protocol::MeteoraDammV2),
+            "meteoradbc" => Ok(Protocol::MeteoraDbc),
+            "orcawhirlpool" => Ok(Protocol::OrcaWhirlpool),
+            "jupiterv6" => Ok(Protocol::JupiterV6),
+            "openbookv2" => Ok(Protocol::OpenBookV2),
+            "phoenix" => Ok(Protocol::Phoenix),
+            "moonshot" => Ok(Protocol::Moonshot),
+            _ if CUSTOM_PROTOCOLS.read().contains_key(s) => Ok(Protocol::Custom(s.to_string())),
             _ => Err(anyhow!("Unsupported protocol: {}", s)),
         }
     }
@@ -66,44 +126,115 @@ impl std::str::FromStr for Protocol {
 
 static EVENT_PARSERS: LazyLock<HashMap<Protocol, Arc<dyn EventParser>>> = LazyLock::new(|| {
     // 预分配容量，避免动态扩容
-    let mut parsers: HashMap<Protocol, Arc<dyn EventParser>> = HashMap::with_capacity(6);
+    let mut parsers: HashMap<Protocol, Arc<dyn EventParser>> = HashMap::with_capacity(14);
     parsers.insert(Protocol::PumpSwap, Arc::new(PumpSwapEventParser::new()));
     parsers.insert(Protocol::PumpFun, Arc::new(PumpFunEventParser::new()));
     parsers.insert(Protocol::Bonk, Arc::new(BonkEventParser::new()));
     parsers.insert(Protocol::RaydiumCpmm, Arc::new(RaydiumCpmmEventParser::new()));
     parsers.insert(Protocol::RaydiumClmm, Arc::new(RaydiumClmmEventParser::new()));
     parsers.insert(Protocol::RaydiumAmmV4, Arc::new(RaydiumAmmV4EventParser::new()));
+    parsers.insert(Protocol::MeteoraDlmm, Arc::new(MeteoraDlmmEventParser::new()));
+    parsers.insert(Protocol::MeteoraDammV2, Arc::new(MeteoraDammV2EventParser::new()));
+    parsers.insert(Protocol::MeteoraDbc, Arc::new(MeteoraDbcEventParser::new()));
+    parsers.insert(Protocol::OrcaWhirlpool, Arc::new(OrcaWhirlpoolEventParser::new()));
+    parsers.insert(Protocol::JupiterV6, Arc::new(JupiterV6EventParser::new()));
+    parsers.insert(Protocol::OpenBookV2, Arc::new(OpenBookV2EventParser::new()));
+    parsers.insert(Protocol::Phoenix, Arc::new(PhoenixEventParser::new()));
+    parsers.insert(Protocol::Moonshot, Arc::new(MoonshotEventParser::new()));
     parsers
 });
 
+/// 自定义协议的解析器与账户解析配置
+#[derive(Clone)]
+pub struct CustomProtocol {
+    pub parser: Arc<dyn EventParser>,
+    pub account_configs: Vec<AccountEventParseConfig>,
+}
+
+/// 运行时注册的自定义协议，按协议名索引
+static CUSTOM_PROTOCOLS: LazyLock<RwLock<HashMap<String, CustomProtocol>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
 
 /// 事件解析器工厂 - 用于创建不同协议的事件解析器
 pub struct EventParserFactory;
 
 impl EventParserFactory {
 
-    /// 创建指定协议的事件解析器
+    /// 创建指定协议的事件解析器，协议不存在时 panic
     pub fn create_parser(protocol: Protocol) -> Arc<dyn EventParser> {
-        EVENT_PARSERS.get(&protocol).cloned().unwrap_or_else(|| {
-            panic!("Parser for protocol {protocol} not found");
-        })
+        Self::try_create_parser(protocol).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// 创建指定协议的事件解析器，自定义协议未注册或已注销时返回错误
+    pub fn try_create_parser(protocol: Protocol) -> Result<Arc<dyn EventParser>> {
+        if let Protocol::Custom(name) = &protocol {
+            if let Some(custom) = CUSTOM_PROTOCOLS.read().get(name) {
+                return Ok(custom.parser.clone());
+            }
+        }
+        EVENT_PARSERS
+            .get(&protocol)
+            .cloned()
+            .ok_or_else(|| anyhow!("Parser for protocol {} not found", protocol))
     }
 
     /// 创建所有协议的事件解析器
     pub fn create_all_parsers() -> Vec<Arc<dyn EventParser>> {
         Self::supported_protocols()
             .into_iter()
-            .map(Self::create_parser)
+            .filter_map(|protocol| Self::try_create_parser(protocol).ok())
             .collect()
     }
 
     /// 获取所有支持的协议
     pub fn supported_protocols() -> Vec<Protocol> {
-        vec![Protocol::PumpSwap]
+        let mut protocols = BUILTIN_PROTOCOLS.to_vec();
+        protocols.extend(CUSTOM_PROTOCOLS.read().keys().cloned().map(Protocol::Custom));
+        protocols
+    }
+
+    /// 注册自定义协议，返回可传给订阅接口的 [`Protocol::Custom`]
+    ///
+    /// 同名协议重复注册时覆盖旧的解析器；需在订阅前完成注册
+    pub fn register_custom_protocol(
+        name: impl Into<String>,
+        parser: Arc<dyn EventParser>,
+        account_configs: Vec<AccountEventParseConfig>,
+    ) -> Result<Protocol> {
+        let name = name.into();
+        if name.is_empty() {
+            return Err(anyhow!("Custom protocol name must not be empty"));
+        }
+        if BUILTIN_PROTOCOLS.iter().any(|p| p.to_string().eq_ignore_ascii_case(&name)) {
+            return Err(anyhow!("Protocol name {} conflicts with a builtin protocol", name));
+        }
+        CUSTOM_PROTOCOLS.write().insert(name.clone(), CustomProtocol { parser, account_configs });
+        Ok(Protocol::Custom(name))
+    }
+
+    /// 注销自定义协议，返回该协议此前是否已注册
+    pub fn unregister_custom_protocol(name: &str) -> bool {
+        CUSTOM_PROTOCOLS.write().remove(name).is_some()
+    }
+
+    /// 获取自定义协议的账户解析配置
+    pub fn custom_account_configs(protocol: &Protocol) -> Vec<AccountEventParseConfig> {
+        match protocol {
+            Protocol::Custom(name) => CUSTOM_PROTOCOLS
+                .read()
+                .get(name)
+                .map(|custom| custom.account_configs.clone())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
     }
 
     /// 检查协议是否支持
     pub fn is_supported(protocol: &Protocol) -> bool {
-        Self::supported_protocols().contains(protocol)
+        match protocol {
+            Protocol::Custom(name) => CUSTOM_PROTOCOLS.read().contains_key(name),
+            _ => BUILTIN_PROTOCOLS.contains(protocol),
+        }
     }
 }
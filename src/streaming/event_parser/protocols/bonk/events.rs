@@ -4,7 +4,7 @@ use crate::streaming::event_parser::protocols::bonk::types::{
     CurveParams, MintParams, PoolStatus, TradeDirection, VestingParams,
 };
 use crate::streaming::event_parser::protocols::bonk::{
-    AmmFeeOn, GlobalConfig, PlatformConfig, PoolState,
+    AmmFeeOn, GlobalConfig, PlatformConfig, PoolState, VestingRecord,
 };
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
@@ -277,6 +277,81 @@ pub struct BonkMigrateToCpswapEvent {
 // Macro to generate UnifiedEvent implementation, specifying the fields to be merged
 impl_unified_event!(BonkMigrateToCpswapEvent,);
 
+/// Create vesting account event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BonkCreateVestingAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub share_amount: u64,
+    #[borsh(skip)]
+    pub creator: Pubkey,
+    #[borsh(skip)]
+    pub beneficiary: Pubkey,
+    #[borsh(skip)]
+    pub pool_state: Pubkey,
+    #[borsh(skip)]
+    pub vesting_record: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+}
+
+impl_unified_event!(BonkCreateVestingAccountEvent,);
+
+/// Claim vested token event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BonkClaimVestedTokenEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub beneficiary: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub vesting_record: Pubkey,
+    pub base_vault: Pubkey,
+    pub user_base_token: Pubkey,
+    pub base_token_mint: Pubkey,
+    pub base_token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub associated_token_program: Pubkey,
+}
+
+impl_unified_event!(BonkClaimVestedTokenEvent,);
+
+/// Claim platform fee event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BonkClaimPlatformFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub platform_fee_wallet: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub platform_config: Pubkey,
+    pub quote_vault: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub quote_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub associated_token_program: Pubkey,
+}
+
+impl_unified_event!(BonkClaimPlatformFeeEvent,);
+
+/// Claim creator fee event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BonkClaimCreatorFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub creator: Pubkey,
+    pub fee_vault_authority: Pubkey,
+    pub creator_fee_vault: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub quote_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub associated_token_program: Pubkey,
+}
+
+impl_unified_event!(BonkClaimCreatorFeeEvent,);
+
 /// 池状态
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BonkPoolStateAccountEvent {
@@ -316,6 +391,19 @@ pub struct BonkPlatformConfigAccountEvent {
 }
 impl_unified_event!(BonkPlatformConfigAccountEvent,);
 
+/// 锁仓记录
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BonkVestingRecordAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub vesting_record: VestingRecord,
+}
+impl_unified_event!(BonkVestingRecordAccountEvent,);
+
 /// Event discriminator constants
 pub mod discriminators {
     // Event discriminators
@@ -335,11 +423,16 @@ pub mod discriminators {
     pub const INITIALIZE_V2: &[u8] = &[67, 153, 175, 39, 218, 16, 38, 32];
     pub const MIGRATE_TO_AMM: &[u8] = &[207, 82, 192, 145, 254, 207, 145, 223];
     pub const MIGRATE_TO_CP_SWAP: &[u8] = &[136, 92, 200, 103, 28, 218, 144, 140];
+    pub const CREATE_VESTING_ACCOUNT: &[u8] = &[129, 178, 2, 13, 217, 172, 230, 218];
+    pub const CLAIM_VESTED_TOKEN: &[u8] = &[49, 33, 104, 30, 189, 157, 79, 35];
+    pub const CLAIM_PLATFORM_FEE: &[u8] = &[156, 39, 208, 135, 76, 237, 61, 72];
+    pub const CLAIM_CREATOR_FEE: &[u8] = &[26, 97, 138, 203, 132, 171, 141, 252];
 
     // 账户鉴别器
     pub const POOL_STATE_ACCOUNT: &[u8] = &[247, 237, 227, 245, 215, 195, 222, 70];
     pub const GLOBAL_CONFIG_ACCOUNT: &[u8] = &[149, 8, 156, 202, 160, 252, 176, 217];
     pub const PLATFORM_CONFIG_ACCOUNT: &[u8] = &[160, 78, 128, 0, 248, 83, 230, 160];
+    pub const VESTING_RECORD_ACCOUNT: &[u8] = &[106, 243, 221, 205, 230, 126, 85, 83];
 }
 
 use crate::streaming::event_parser::protocols::raydium_cpmm::types::{
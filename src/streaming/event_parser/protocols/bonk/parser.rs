@@ -7,9 +7,10 @@ use crate::{
         core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
         protocols::bonk::{
             bonk_pool_create_event_log_decode, bonk_trade_event_log_decode, discriminators,
-            AmmFeeOn, BonkMigrateToAmmEvent, BonkMigrateToCpswapEvent, BonkPoolCreateEvent,
-            BonkTradeEvent, ConstantCurve, CurveParams, FixedCurve, LinearCurve, MintParams,
-            TradeDirection, VestingParams,
+            AmmFeeOn, BonkClaimCreatorFeeEvent, BonkClaimPlatformFeeEvent,
+            BonkClaimVestedTokenEvent, BonkCreateVestingAccountEvent, BonkMigrateToAmmEvent,
+            BonkMigrateToCpswapEvent, BonkPoolCreateEvent, BonkTradeEvent, ConstantCurve,
+            CurveParams, FixedCurve, LinearCurve, MintParams, TradeDirection, VestingParams,
         },
     },
 };
@@ -105,6 +106,42 @@ impl BonkEventParser {
                 inner_instruction_parser: None,
                 instruction_parser: Some(Self::parse_migrate_to_cpswap_instruction),
             },
+            GenericEventParseConfig {
+                program_id: BONK_PROGRAM_ID,
+                protocol_type: ProtocolType::Bonk,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CREATE_VESTING_ACCOUNT,
+                event_type: EventType::BonkCreateVestingAccount,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_create_vesting_account_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: BONK_PROGRAM_ID,
+                protocol_type: ProtocolType::Bonk,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CLAIM_VESTED_TOKEN,
+                event_type: EventType::BonkClaimVestedToken,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_claim_vested_token_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: BONK_PROGRAM_ID,
+                protocol_type: ProtocolType::Bonk,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CLAIM_PLATFORM_FEE,
+                event_type: EventType::BonkClaimPlatformFee,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_claim_platform_fee_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: BONK_PROGRAM_ID,
+                protocol_type: ProtocolType::Bonk,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CLAIM_CREATOR_FEE,
+                event_type: EventType::BonkClaimCreatorFee,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_claim_creator_fee_instruction),
+            },
         ];
 
         let inner = GenericEventParser::new(vec![BONK_PROGRAM_ID], configs);
@@ -573,6 +610,96 @@ impl BonkEventParser {
             ..Default::default()
         }))
     }
+
+    /// Parse create vesting account event
+    fn parse_create_vesting_account_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 8 || accounts.len() < 5 {
+            return None;
+        }
+        Some(Box::new(BonkCreateVestingAccountEvent {
+            metadata,
+            share_amount: read_u64_le(data, 0)?,
+            creator: accounts[0],
+            beneficiary: accounts[1],
+            pool_state: accounts[2],
+            vesting_record: accounts[3],
+            system_program: accounts[4],
+        }))
+    }
+
+    /// Parse claim vested token event
+    fn parse_claim_vested_token_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 10 {
+            return None;
+        }
+        Some(Box::new(BonkClaimVestedTokenEvent {
+            metadata,
+            beneficiary: accounts[0],
+            authority: accounts[1],
+            pool_state: accounts[2],
+            vesting_record: accounts[3],
+            base_vault: accounts[4],
+            user_base_token: accounts[5],
+            base_token_mint: accounts[6],
+            base_token_program: accounts[7],
+            system_program: accounts[8],
+            associated_token_program: accounts[9],
+        }))
+    }
+
+    /// Parse claim platform fee event
+    fn parse_claim_platform_fee_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 10 {
+            return None;
+        }
+        Some(Box::new(BonkClaimPlatformFeeEvent {
+            metadata,
+            platform_fee_wallet: accounts[0],
+            authority: accounts[1],
+            pool_state: accounts[2],
+            platform_config: accounts[3],
+            quote_vault: accounts[4],
+            recipient_token_account: accounts[5],
+            quote_mint: accounts[6],
+            token_program: accounts[7],
+            system_program: accounts[8],
+            associated_token_program: accounts[9],
+        }))
+    }
+
+    /// Parse claim creator fee event
+    fn parse_claim_creator_fee_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 8 {
+            return None;
+        }
+        Some(Box::new(BonkClaimCreatorFeeEvent {
+            metadata,
+            creator: accounts[0],
+            fee_vault_authority: accounts[1],
+            creator_fee_vault: accounts[2],
+            recipient_token_account: accounts[3],
+            quote_mint: accounts[4],
+            token_program: accounts[5],
+            system_program: accounts[6],
+            associated_token_program: accounts[7],
+        }))
+    }
 }
 
 impl_event_parser_delegate!(BonkEventParser);
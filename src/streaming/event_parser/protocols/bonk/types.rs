@@ -7,6 +7,7 @@ use crate::streaming::{
         common::EventMetadata,
         protocols::bonk::{
             BonkGlobalConfigAccountEvent, BonkPlatformConfigAccountEvent, BonkPoolStateAccountEvent,
+            BonkVestingRecordAccountEvent,
         },
         UnifiedEvent,
     },
@@ -252,3 +253,82 @@ pub fn platform_config_parser(
         None
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VestingRecord {
+    pub epoch: u64,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub claimed_amount: u64,
+    pub token_share_amount: u64,
+    pub padding: [u64; 8],
+}
+
+pub const VESTING_RECORD_SIZE: usize = 8 + 32 * 2 + 8 * 2 + 8 * 8;
+
+pub fn vesting_record_decode(data: &[u8]) -> Option<VestingRecord> {
+    if data.len() < VESTING_RECORD_SIZE {
+        return None;
+    }
+    borsh::from_slice::<VestingRecord>(&data[..VESTING_RECORD_SIZE]).ok()
+}
+
+pub fn vesting_record_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < VESTING_RECORD_SIZE + 8 {
+        return None;
+    }
+    if let Some(vesting_record) = vesting_record_decode(&account.data[8..VESTING_RECORD_SIZE + 8])
+    {
+        Some(Box::new(BonkVestingRecordAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            vesting_record,
+        }))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按账户布局偏移写入字段，偏移包含 8 字节鉴别器
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[8 + offset..8 + offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[test]
+    fn vesting_record_layout() {
+        let pool = Pubkey::new_unique();
+        let beneficiary = Pubkey::new_unique();
+        let mut data = vec![0u8; VESTING_RECORD_SIZE + 8];
+        put(&mut data, 0, &815u64.to_le_bytes());
+        put(&mut data, 8, pool.as_ref());
+        put(&mut data, 40, beneficiary.as_ref());
+        put(&mut data, 72, &250_000u64.to_le_bytes());
+        put(&mut data, 80, &1_000_000u64.to_le_bytes());
+        let account = AccountPretty { data, ..Default::default() };
+
+        let event = vesting_record_parser(&account, EventMetadata::default()).unwrap();
+        let vesting_record =
+            &event.as_any().downcast_ref::<BonkVestingRecordAccountEvent>().unwrap().vesting_record;
+        assert_eq!(vesting_record.epoch, 815);
+        assert_eq!(vesting_record.pool, pool);
+        assert_eq!(vesting_record.beneficiary, beneficiary);
+        assert_eq!(vesting_record.claimed_amount, 250_000);
+        assert_eq!(vesting_record.token_share_amount, 1_000_000);
+        assert_eq!(vesting_record.padding, [0; 8]);
+
+        // 数据不足一个完整记录时不解析
+        let short = AccountPretty { data: account.data[..VESTING_RECORD_SIZE].to_vec(), ..account };
+        assert!(vesting_record_parser(&short, EventMetadata::default()).is_none());
+    }
+}
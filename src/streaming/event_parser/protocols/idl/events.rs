@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::impl_unified_event;
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::idl::IdlValue;
+
+/// IDL 事件来源
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IdlEventKind {
+    /// 程序指令，`data` 为指令参数
+    #[default]
+    Instruction,
+    /// `emit_cpi!` 发出的事件，`data` 为事件字段
+    CpiEvent,
+}
+
+/// `Program data:` 日志中的事件
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IdlLogEvent {
+    pub name: String,
+    pub data: IdlValue,
+}
+
+/// IDL 动态解析出的指令或事件
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IdlEvent {
+    pub metadata: EventMetadata,
+    /// IDL 中的程序名
+    pub program_name: String,
+    /// 指令名或事件名
+    pub name: String,
+    pub kind: IdlEventKind,
+    pub data: IdlValue,
+    /// IDL 声明的具名账户
+    pub accounts: Vec<(String, Pubkey)>,
+    /// IDL 未声明的剩余账户
+    pub remaining_accounts: Vec<Pubkey>,
+    /// 该指令执行期间输出的 `Program data:` 事件
+    pub log_events: Vec<IdlLogEvent>,
+}
+
+impl IdlEvent {
+    /// 按名称取账户
+    pub fn account(&self, name: &str) -> Option<Pubkey> {
+        self.accounts.iter().find(|(account, _)| account == name).map(|(_, pubkey)| *pubkey)
+    }
+}
+
+impl_unified_event!(IdlEvent,);
+
+/// IDL 动态解析出的账户
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IdlAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    /// IDL 中的账户类型名
+    pub name: String,
+    pub data: IdlValue,
+}
+impl_unified_event!(IdlAccountEvent,);
@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+use std::sync::{Arc, LazyLock};
+
+use anyhow::Result;
+use parking_lot::Mutex;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_event_parser_delegate,
+    streaming::{
+        event_parser::{
+            common::{EventMetadata, EventType, ProtocolType},
+            core::{
+                account_event_parser::AccountEventParseConfig,
+                traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
+            },
+            protocols::idl::{
+                get_idl_program, register_idl_program, IdlAccountEvent, IdlEvent, IdlEventKind,
+                IdlProgram, EVENT_IX_TAG,
+            },
+            EventParserFactory, Protocol,
+        },
+        grpc::AccountPretty,
+    },
+};
+
+/// Anchor IDL 动态事件解析器
+///
+/// 指令、`emit_cpi!` 事件与账户的鉴别器在加载时计算并常驻内存，
+/// 相同字节内容的鉴别器全局只驻留一份，重复加载同一 IDL 不会再次分配
+pub struct IdlEventParser {
+    inner: GenericEventParser,
+    program: Arc<IdlProgram>,
+}
+
+impl IdlEventParser {
+    /// 从 IDL JSON 创建解析器；`program_id` 为空时使用 IDL 中的地址
+    pub fn from_json(json: &str, program_id: Option<Pubkey>) -> Result<Self> {
+        Ok(Self::new(IdlProgram::from_json(json, program_id)?))
+    }
+
+    pub fn new(program: IdlProgram) -> Self {
+        let program = Arc::new(program);
+        register_idl_program(program.clone());
+        let protocol_type = ProtocolType::Custom(program.name.clone());
+
+        // 配置所有事件类型
+        let mut configs = Vec::with_capacity(program.instructions.len() + program.events.len());
+        for instruction in &program.instructions {
+            configs.push(GenericEventParseConfig {
+                program_id: program.program_id,
+                protocol_type: protocol_type.clone(),
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: leak_discriminator(instruction.discriminator.clone()),
+                event_type: EventType::Custom(instruction.name.clone()),
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_instruction),
+            });
+        }
+        for event in &program.events {
+            configs.push(GenericEventParseConfig {
+                program_id: program.program_id,
+                protocol_type: protocol_type.clone(),
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: leak_discriminator(
+                    [EVENT_IX_TAG, event.discriminator.as_slice()].concat(),
+                ),
+                event_type: EventType::Custom(event.name.clone()),
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_cpi_event),
+            });
+        }
+        let inner = GenericEventParser::new(vec![program.program_id], configs);
+        Self { inner, program }
+    }
+
+    pub fn program(&self) -> &IdlProgram {
+        &self.program
+    }
+
+    /// IDL 中账户的解析配置，可交给 `AccountEventParser` 使用
+    pub fn account_configs(&self) -> Vec<AccountEventParseConfig> {
+        self.program
+            .accounts
+            .iter()
+            .map(|account| AccountEventParseConfig {
+                program_id: self.program.program_id,
+                protocol_type: ProtocolType::Custom(self.program.name.clone()),
+                event_type: EventType::Custom(account.name.clone()),
+                account_discriminator: leak_discriminator(account.discriminator.clone()),
+                account_parser: Self::parse_account,
+            })
+            .collect()
+    }
+
+    /// 以 IDL 中的程序名注册为自定义协议
+    pub fn register(self) -> Result<Protocol> {
+        let name = self.program.name.clone();
+        let account_configs = self.account_configs();
+        EventParserFactory::register_custom_protocol(name, Arc::new(self), account_configs)
+    }
+
+    /// 解析指令参数与具名账户
+    fn parse_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let program = get_idl_program(&metadata.program_id)?;
+        let EventType::Custom(name) = &metadata.event_type else {
+            return None;
+        };
+        let instruction = program.instruction(name)?;
+        let args = program.decode_args(instruction, data)?;
+        let named_accounts =
+            instruction.accounts.iter().cloned().zip(accounts.iter().copied()).collect();
+        let remaining_accounts =
+            accounts.get(instruction.accounts.len()..).unwrap_or_default().to_vec();
+        Some(Box::new(IdlEvent {
+            name: name.clone(),
+            program_name: program.name.clone(),
+            kind: IdlEventKind::Instruction,
+            data: args,
+            accounts: named_accounts,
+            remaining_accounts,
+            metadata,
+            ..Default::default()
+        }))
+    }
+
+    /// 解析 `emit_cpi!` 事件
+    fn parse_cpi_event(
+        data: &[u8],
+        _accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let program = get_idl_program(&metadata.program_id)?;
+        let EventType::Custom(name) = &metadata.event_type else {
+            return None;
+        };
+        let event = program.event(name)?;
+        let value = program.decode_defined(&event.name, data)?;
+        Some(Box::new(IdlEvent {
+            name: name.clone(),
+            program_name: program.name.clone(),
+            kind: IdlEventKind::CpiEvent,
+            data: value,
+            metadata,
+            ..Default::default()
+        }))
+    }
+
+    /// 解析 IDL 中声明的账户
+    fn parse_account(
+        account: &AccountPretty,
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let program = get_idl_program(&account.owner)?;
+        let account_def = program.account_by_discriminator(&account.data)?;
+        let data = program
+            .decode_defined(&account_def.name, &account.data[account_def.discriminator.len()..])?;
+        Some(Box::new(IdlAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            name: account_def.name.clone(),
+            data,
+        }))
+    }
+}
+
+/// 已常驻内存的动态鉴别器，按字节内容去重
+static INTERNED_DISCRIMINATORS: LazyLock<Mutex<HashSet<&'static [u8]>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// 配置要求 `'static` 鉴别器，动态加载的鉴别器需常驻内存；
+/// 相同内容只分配一次，重复构建或注册解析器不会增加内存
+fn leak_discriminator(discriminator: Vec<u8>) -> &'static [u8] {
+    let mut interned = INTERNED_DISCRIMINATORS.lock();
+    if let Some(existing) = interned.get(discriminator.as_slice()) {
+        return existing;
+    }
+    let leaked: &'static [u8] = Box::leak(discriminator.into_boxed_slice());
+    interned.insert(leaked);
+    leaked
+}
+
+impl_event_parser_delegate!(IdlEventParser);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leak_discriminator_reuses_interned_bytes() {
+        let first = leak_discriminator(vec![0xfe, 1, 2, 3, 4, 5, 6, 7]);
+        let second = leak_discriminator(vec![0xfe, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(first, [0xfe, 1, 2, 3, 4, 5, 6, 7]);
+        assert!(std::ptr::eq(first, second));
+        assert!(!std::ptr::eq(first, leak_discriminator(vec![0xfe, 1, 2, 3, 4, 5, 6, 8])));
+    }
+}
@@ -0,0 +1,551 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock},
+};
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::{
+    common::{anchor_discriminator, collect_program_logs, to_snake_case, PROGRAM_DATA_PREFIX},
+    protocols::idl::IdlLogEvent,
+};
+
+/// `emit_cpi!` 事件指令的固定前缀
+pub const EVENT_IX_TAG: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29];
+
+/// 解码嵌套类型的最大深度，防止自引用类型无限递归
+const MAX_DECODE_DEPTH: usize = 64;
+
+/// 解码后的动态值
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum IdlValue {
+    #[default]
+    Unit,
+    Bool(bool),
+    Unsigned(u128),
+    Signed(i128),
+    Float(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Pubkey(Pubkey),
+    Option(Option<Box<IdlValue>>),
+    Array(Vec<IdlValue>),
+    Struct(Vec<(String, IdlValue)>),
+    Enum(String, Box<IdlValue>),
+}
+
+impl IdlValue {
+    /// 按字段名取结构体字段
+    pub fn get(&self, field: &str) -> Option<&IdlValue> {
+        match self {
+            IdlValue::Struct(fields) => {
+                fields.iter().find(|(name, _)| name == field).map(|(_, value)| value)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            IdlValue::Unsigned(v) => u64::try_from(*v).ok(),
+            IdlValue::Signed(v) => u64::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            IdlValue::Unsigned(v) => i64::try_from(*v).ok(),
+            IdlValue::Signed(v) => i64::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_pubkey(&self) -> Option<Pubkey> {
+        match self {
+            IdlValue::Pubkey(pubkey) => Some(*pubkey),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            IdlValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// IDL 中的字段类型
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdlType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    F32,
+    U64,
+    I64,
+    F64,
+    U128,
+    I128,
+    U256,
+    I256,
+    String,
+    Bytes,
+    Pubkey,
+    Vec(Box<IdlType>),
+    Option(Box<IdlType>),
+    COption(Box<IdlType>),
+    Array(Box<IdlType>, usize),
+    Defined(String),
+    /// 暂不支持的类型（如泛型），解码时失败
+    Unsupported(String),
+}
+
+/// 结构体或枚举变体的字段
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdlFields {
+    Named(Vec<(String, IdlType)>),
+    Tuple(Vec<IdlType>),
+}
+
+/// IDL 中的自定义类型定义
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdlTypeDef {
+    Struct(IdlFields),
+    Enum(Vec<(String, IdlFields)>),
+    Alias(IdlType),
+}
+
+/// 指令定义，账户按顺序展开，嵌套账户组以 `.` 连接
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdlInstructionDef {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+    pub accounts: Vec<String>,
+    pub args: Vec<(String, IdlType)>,
+}
+
+/// 事件或账户定义，数据布局由同名类型描述
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdlTypedDef {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+}
+
+/// 解析后的 Anchor IDL，同时支持 0.30 之前的旧格式与新格式
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdlProgram {
+    pub name: String,
+    pub program_id: Pubkey,
+    pub instructions: Vec<IdlInstructionDef>,
+    pub events: Vec<IdlTypedDef>,
+    pub accounts: Vec<IdlTypedDef>,
+    pub types: HashMap<String, IdlTypeDef>,
+}
+
+impl IdlProgram {
+    /// 从 IDL JSON 加载；`program_id` 为空时使用 IDL 中的地址
+    pub fn from_json(json: &str, program_id: Option<Pubkey>) -> Result<Self> {
+        let idl: Value = serde_json::from_str(json)?;
+        let metadata = idl.get("metadata");
+        let name = idl
+            .get("name")
+            .or_else(|| metadata.and_then(|m| m.get("name")))
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow!("IDL is missing program name"))?
+            .to_string();
+        let program_id = match program_id {
+            Some(program_id) => program_id,
+            None => idl
+                .get("address")
+                .or_else(|| metadata.and_then(|m| m.get("address")))
+                .and_then(Value::as_str)
+                .ok_or_else(|| anyhow!("IDL {} is missing program address", name))?
+                .parse()?,
+        };
+
+        let mut types = HashMap::new();
+        for ty in json_array(&idl, "types") {
+            let ty_name = json_name(ty)?;
+            let def = ty.get("type").ok_or_else(|| anyhow!("Type {} has no layout", ty_name))?;
+            types.insert(ty_name, parse_type_def(def)?);
+        }
+
+        let mut accounts = Vec::new();
+        for account in json_array(&idl, "accounts") {
+            let account_name = json_name(account)?;
+            // 旧格式账户布局内联在定义中
+            if let Some(def) = account.get("type") {
+                types.entry(account_name.clone()).or_insert(parse_type_def(def)?);
+            }
+            let discriminator = json_discriminator(account)
+                .unwrap_or_else(|| anchor_discriminator("account", &account_name).to_vec());
+            accounts.push(IdlTypedDef { name: account_name, discriminator });
+        }
+
+        let mut events = Vec::new();
+        for event in json_array(&idl, "events") {
+            let event_name = json_name(event)?;
+            // 旧格式事件字段内联在定义中
+            if let Some(fields) = event.get("fields") {
+                types
+                    .entry(event_name.clone())
+                    .or_insert(IdlTypeDef::Struct(parse_fields(Some(fields))?));
+            }
+            let discriminator = json_discriminator(event)
+                .unwrap_or_else(|| anchor_discriminator("event", &event_name).to_vec());
+            events.push(IdlTypedDef { name: event_name, discriminator });
+        }
+
+        let mut instructions = Vec::new();
+        for instruction in json_array(&idl, "instructions") {
+            let ix_name = json_name(instruction)?;
+            let discriminator = json_discriminator(instruction).unwrap_or_else(|| {
+                anchor_discriminator("global", &to_snake_case(&ix_name)).to_vec()
+            });
+            let mut ix_accounts = Vec::new();
+            flatten_accounts(json_array(instruction, "accounts"), "", &mut ix_accounts)?;
+            let args = json_array(instruction, "args")
+                .map(|arg| Ok((json_name(arg)?, parse_type(json_field(arg, "type")?))))
+                .collect::<Result<Vec<_>>>()?;
+            instructions.push(IdlInstructionDef {
+                name: ix_name,
+                discriminator,
+                accounts: ix_accounts,
+                args,
+            });
+        }
+
+        Ok(Self { name, program_id, instructions, events, accounts, types })
+    }
+
+    pub fn instruction(&self, name: &str) -> Option<&IdlInstructionDef> {
+        self.instructions.iter().find(|ix| ix.name == name)
+    }
+
+    pub fn event(&self, name: &str) -> Option<&IdlTypedDef> {
+        self.events.iter().find(|event| event.name == name)
+    }
+
+    /// 按鉴别器查找事件
+    pub fn event_by_discriminator(&self, data: &[u8]) -> Option<&IdlTypedDef> {
+        self.events.iter().find(|event| data.starts_with(&event.discriminator))
+    }
+
+    /// 按鉴别器查找账户
+    pub fn account_by_discriminator(&self, data: &[u8]) -> Option<&IdlTypedDef> {
+        self.accounts.iter().find(|account| data.starts_with(&account.discriminator))
+    }
+
+    /// 解码指令参数为结构体
+    pub fn decode_args(&self, instruction: &IdlInstructionDef, data: &[u8]) -> Option<IdlValue> {
+        let mut data = data;
+        self.decode_named(&instruction.args, &mut data, 0)
+    }
+
+    /// 按自定义类型名解码，忽略尾部多余字节
+    pub fn decode_defined(&self, name: &str, data: &[u8]) -> Option<IdlValue> {
+        let mut data = data;
+        self.decode_type(&IdlType::Defined(name.to_string()), &mut data, 0)
+    }
+
+    /// 解码 `Program data:` 日志中的事件
+    pub fn decode_log_event(&self, data: &[u8]) -> Option<IdlLogEvent> {
+        let event = self.event_by_discriminator(data)?;
+        let value = self.decode_defined(&event.name, &data[event.discriminator.len()..])?;
+        Some(IdlLogEvent { name: event.name.clone(), data: value })
+    }
+
+    fn decode_named(
+        &self,
+        fields: &[(String, IdlType)],
+        data: &mut &[u8],
+        depth: usize,
+    ) -> Option<IdlValue> {
+        let mut values = Vec::with_capacity(fields.len());
+        for (name, ty) in fields {
+            values.push((name.clone(), self.decode_type(ty, data, depth)?));
+        }
+        Some(IdlValue::Struct(values))
+    }
+
+    fn decode_fields(
+        &self,
+        fields: &IdlFields,
+        data: &mut &[u8],
+        depth: usize,
+    ) -> Option<IdlValue> {
+        match fields {
+            IdlFields::Named(fields) => self.decode_named(fields, data, depth),
+            IdlFields::Tuple(types) => types
+                .iter()
+                .map(|ty| self.decode_type(ty, data, depth))
+                .collect::<Option<Vec<_>>>()
+                .map(IdlValue::Array),
+        }
+    }
+
+    fn decode_type(&self, ty: &IdlType, data: &mut &[u8], depth: usize) -> Option<IdlValue> {
+        if depth > MAX_DECODE_DEPTH {
+            return None;
+        }
+        let depth = depth + 1;
+        let value = match ty {
+            IdlType::Bool => IdlValue::Bool(take::<1>(data)?[0] != 0),
+            IdlType::U8 => IdlValue::Unsigned(u8::from_le_bytes(take(data)?) as u128),
+            IdlType::I8 => IdlValue::Signed(i8::from_le_bytes(take(data)?) as i128),
+            IdlType::U16 => IdlValue::Unsigned(u16::from_le_bytes(take(data)?) as u128),
+            IdlType::I16 => IdlValue::Signed(i16::from_le_bytes(take(data)?) as i128),
+            IdlType::U32 => IdlValue::Unsigned(u32::from_le_bytes(take(data)?) as u128),
+            IdlType::I32 => IdlValue::Signed(i32::from_le_bytes(take(data)?) as i128),
+            IdlType::F32 => IdlValue::Float(f32::from_le_bytes(take(data)?) as f64),
+            IdlType::U64 => IdlValue::Unsigned(u64::from_le_bytes(take(data)?) as u128),
+            IdlType::I64 => IdlValue::Signed(i64::from_le_bytes(take(data)?) as i128),
+            IdlType::F64 => IdlValue::Float(f64::from_le_bytes(take(data)?)),
+            IdlType::U128 => IdlValue::Unsigned(u128::from_le_bytes(take(data)?)),
+            IdlType::I128 => IdlValue::Signed(i128::from_le_bytes(take(data)?)),
+            IdlType::U256 | IdlType::I256 => IdlValue::Bytes(take::<32>(data)?.to_vec()),
+            IdlType::String => {
+                let len = u32::from_le_bytes(take(data)?) as usize;
+                IdlValue::String(String::from_utf8(take_slice(data, len)?.to_vec()).ok()?)
+            }
+            IdlType::Bytes => {
+                let len = u32::from_le_bytes(take(data)?) as usize;
+                IdlValue::Bytes(take_slice(data, len)?.to_vec())
+            }
+            IdlType::Pubkey => IdlValue::Pubkey(Pubkey::new_from_array(take(data)?)),
+            IdlType::Vec(inner) => {
+                let len = u32::from_le_bytes(take(data)?) as usize;
+                // 每个元素至少占 1 字节，长度异常时提前失败
+                if len > data.len() {
+                    return None;
+                }
+                let mut values = Vec::with_capacity(len);
+                for _ in 0..len {
+                    values.push(self.decode_type(inner, data, depth)?);
+                }
+                IdlValue::Array(values)
+            }
+            IdlType::Option(inner) => match take::<1>(data)?[0] {
+                0 => IdlValue::Option(None),
+                _ => IdlValue::Option(Some(Box::new(self.decode_type(inner, data, depth)?))),
+            },
+            IdlType::COption(inner) => match u32::from_le_bytes(take(data)?) {
+                0 => {
+                    // COption 无值时仍占用内部类型的空间
+                    self.decode_type(inner, data, depth)?;
+                    IdlValue::Option(None)
+                }
+                _ => IdlValue::Option(Some(Box::new(self.decode_type(inner, data, depth)?))),
+            },
+            IdlType::Array(inner, len) => {
+                if **inner == IdlType::U8 {
+                    IdlValue::Bytes(take_slice(data, *len)?.to_vec())
+                } else {
+                    let mut values = Vec::with_capacity(*len);
+                    for _ in 0..*len {
+                        values.push(self.decode_type(inner, data, depth)?);
+                    }
+                    IdlValue::Array(values)
+                }
+            }
+            IdlType::Defined(name) => match self.types.get(name)? {
+                IdlTypeDef::Struct(fields) => self.decode_fields(fields, data, depth)?,
+                IdlTypeDef::Enum(variants) => {
+                    let (variant, fields) = variants.get(take::<1>(data)?[0] as usize)?;
+                    let value = self.decode_fields(fields, data, depth)?;
+                    IdlValue::Enum(variant.clone(), Box::new(value))
+                }
+                IdlTypeDef::Alias(ty) => self.decode_type(ty, data, depth)?,
+            },
+            IdlType::Unsupported(_) => return None,
+        };
+        Some(value)
+    }
+}
+
+/// 已加载的 IDL，按程序 ID 索引
+static IDL_PROGRAMS: LazyLock<RwLock<HashMap<Pubkey, Arc<IdlProgram>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// 注册 IDL，同一程序重复注册时覆盖
+pub fn register_idl_program(program: Arc<IdlProgram>) {
+    IDL_PROGRAMS.write().insert(program.program_id, program);
+}
+
+/// 注销 IDL，返回该程序此前是否已注册
+pub fn unregister_idl_program(program_id: &Pubkey) -> bool {
+    IDL_PROGRAMS.write().remove(program_id).is_some()
+}
+
+/// 获取已注册的 IDL
+pub fn get_idl_program(program_id: &Pubkey) -> Option<Arc<IdlProgram>> {
+    IDL_PROGRAMS.read().get(program_id).cloned()
+}
+
+/// 按指令位置归集已注册程序以 `Program data:` 输出的事件
+pub fn collect_idl_log_events(
+    log_messages: &[String],
+) -> HashMap<(i64, Option<i64>), Vec<IdlLogEvent>> {
+    let mut events: HashMap<(i64, Option<i64>), Vec<IdlLogEvent>> = HashMap::new();
+    if !log_messages.iter().any(|log| log.starts_with(PROGRAM_DATA_PREFIX)) {
+        return events;
+    }
+    let programs: Vec<Arc<IdlProgram>> = IDL_PROGRAMS.read().values().cloned().collect();
+    for program in programs {
+        let program_id = program.program_id.to_string();
+        for (outer_index, inner_index, log) in
+            collect_program_logs(log_messages, &program_id, PROGRAM_DATA_PREFIX)
+        {
+            let Ok(data) = STANDARD.decode(log) else {
+                continue;
+            };
+            if let Some(event) = program.decode_log_event(&data) {
+                events.entry((outer_index, inner_index)).or_default().push(event);
+            }
+        }
+    }
+    events
+}
+
+fn take<const N: usize>(data: &mut &[u8]) -> Option<[u8; N]> {
+    let bytes = take_slice(data, N)?;
+    bytes.try_into().ok()
+}
+
+fn take_slice<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if data.len() < len {
+        return None;
+    }
+    let (head, tail) = data.split_at(len);
+    *data = tail;
+    Some(head)
+}
+
+fn json_array<'a>(value: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+    value.get(key).and_then(Value::as_array).into_iter().flatten()
+}
+
+fn json_field<'a>(value: &'a Value, key: &str) -> Result<&'a Value> {
+    value.get(key).ok_or_else(|| anyhow!("IDL entry is missing `{}`: {}", key, value))
+}
+
+fn json_name(value: &Value) -> Result<String> {
+    json_field(value, "name")?
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("IDL entry name is not a string: {}", value))
+}
+
+fn json_discriminator(value: &Value) -> Option<Vec<u8>> {
+    value
+        .get("discriminator")?
+        .as_array()?
+        .iter()
+        .map(|b| b.as_u64().and_then(|b| u8::try_from(b).ok()))
+        .collect()
+}
+
+fn flatten_accounts<'a>(
+    accounts: impl Iterator<Item = &'a Value>,
+    prefix: &str,
+    out: &mut Vec<String>,
+) -> Result<()> {
+    for account in accounts {
+        let name = format!("{}{}", prefix, json_name(account)?);
+        if account.get("accounts").is_some() {
+            flatten_accounts(json_array(account, "accounts"), &format!("{name}."), out)?;
+        } else {
+            out.push(name);
+        }
+    }
+    Ok(())
+}
+
+fn parse_type(value: &Value) -> IdlType {
+    if let Some(name) = value.as_str() {
+        return match name {
+            "bool" => IdlType::Bool,
+            "u8" => IdlType::U8,
+            "i8" => IdlType::I8,
+            "u16" => IdlType::U16,
+            "i16" => IdlType::I16,
+            "u32" => IdlType::U32,
+            "i32" => IdlType::I32,
+            "f32" => IdlType::F32,
+            "u64" => IdlType::U64,
+            "i64" => IdlType::I64,
+            "f64" => IdlType::F64,
+            "u128" => IdlType::U128,
+            "i128" => IdlType::I128,
+            "u256" => IdlType::U256,
+            "i256" => IdlType::I256,
+            "string" => IdlType::String,
+            "bytes" => IdlType::Bytes,
+            "pubkey" | "publicKey" => IdlType::Pubkey,
+            other => IdlType::Unsupported(other.to_string()),
+        };
+    }
+    if let Some(inner) = value.get("vec") {
+        return IdlType::Vec(Box::new(parse_type(inner)));
+    }
+    if let Some(inner) = value.get("option") {
+        return IdlType::Option(Box::new(parse_type(inner)));
+    }
+    if let Some(inner) = value.get("coption") {
+        return IdlType::COption(Box::new(parse_type(inner)));
+    }
+    if let Some([inner, len]) = value.get("array").and_then(Value::as_array).map(Vec::as_slice) {
+        return match len.as_u64() {
+            Some(len) => IdlType::Array(Box::new(parse_type(inner)), len as usize),
+            None => IdlType::Unsupported(value.to_string()),
+        };
+    }
+    if let Some(defined) = value.get("defined") {
+        // 旧格式为类型名字符串，新格式为 { "name": .. }
+        let name = defined.as_str().or_else(|| defined.get("name").and_then(Value::as_str));
+        if let Some(name) = name {
+            return IdlType::Defined(name.to_string());
+        }
+    }
+    IdlType::Unsupported(value.to_string())
+}
+
+fn parse_fields(fields: Option<&Value>) -> Result<IdlFields> {
+    let Some(fields) = fields.and_then(Value::as_array) else {
+        return Ok(IdlFields::Named(Vec::new()));
+    };
+    let named =
+        fields.iter().all(|field| field.get("name").is_some() && field.get("type").is_some());
+    if named {
+        fields
+            .iter()
+            .map(|field| Ok((json_name(field)?, parse_type(json_field(field, "type")?))))
+            .collect::<Result<Vec<_>>>()
+            .map(IdlFields::Named)
+    } else {
+        Ok(IdlFields::Tuple(fields.iter().map(parse_type).collect()))
+    }
+}
+
+fn parse_type_def(def: &Value) -> Result<IdlTypeDef> {
+    match json_field(def, "kind")?.as_str() {
+        Some("struct") => Ok(IdlTypeDef::Struct(parse_fields(def.get("fields"))?)),
+        Some("enum") => json_array(def, "variants")
+            .map(|variant| Ok((json_name(variant)?, parse_fields(variant.get("fields"))?)))
+            .collect::<Result<Vec<_>>>()
+            .map(IdlTypeDef::Enum),
+        Some("type") => Ok(IdlTypeDef::Alias(parse_type(json_field(def, "alias")?))),
+        _ => Err(anyhow!("Unsupported IDL type kind: {}", def)),
+    }
+}
@@ -0,0 +1,120 @@
+use crate::impl_unified_event;
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::jupiter_v6::types::SwapLeg;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 聚合路由（route / shared_accounts_route / exact_out_route / shared_accounts_exact_out_route）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JupiterV6RouteEvent {
+    pub metadata: EventMetadata,
+    /// shared_accounts 指令的程序权限 id
+    pub id: u8,
+    pub route_plan_len: u32,
+    pub exact_out: bool,
+    // exact_in 路由参数
+    pub in_amount: u64,
+    pub quoted_out_amount: u64,
+    // exact_out 路由参数
+    pub out_amount: u64,
+    pub quoted_in_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+    pub token_program: Pubkey,
+    pub program_authority: Pubkey,
+    pub user_transfer_authority: Pubkey,
+    pub user_source_token_account: Pubkey,
+    pub user_destination_token_account: Pubkey,
+    pub program_source_token_account: Pubkey,
+    pub program_destination_token_account: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub source_mint: Pubkey,
+    pub destination_mint: Pubkey,
+    pub platform_fee_account: Pubkey,
+    pub token_2022_program: Pubkey,
+    // 由 SwapEvent 日志汇总的实际成交
+    pub input_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_mint: Pubkey,
+    pub output_amount: u64,
+    pub swap_legs: Vec<SwapLeg>,
+    /// 同一交易中由该路由调用的 AMM 事件ID
+    pub child_event_ids: Vec<String>,
+}
+impl_unified_event!(JupiterV6RouteEvent,);
+
+/// 路由中单个 AMM 的成交（SwapEvent 日志）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JupiterV6SwapEvent {
+    pub metadata: EventMetadata,
+    pub amm: Pubkey,
+    pub input_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_mint: Pubkey,
+    pub output_amount: u64,
+}
+impl_unified_event!(JupiterV6SwapEvent,);
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 事件鉴别器
+    pub const SWAP_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 64, 198, 205, 232, 38, 8, 113, 226];
+
+    // 指令鉴别器
+    pub const ROUTE: &[u8] = &[229, 23, 203, 151, 122, 227, 173, 42];
+    pub const SHARED_ACCOUNTS_ROUTE: &[u8] = &[193, 32, 155, 51, 65, 214, 156, 129];
+    pub const EXACT_OUT_ROUTE: &[u8] = &[208, 51, 239, 151, 123, 43, 237, 92];
+    pub const SHARED_ACCOUNTS_EXACT_OUT_ROUTE: &[u8] = &[176, 209, 105, 168, 154, 125, 69, 62];
+}
+
+use crate::streaming::event_parser::protocols::raydium_cpmm::types::{
+    CopyTradeableEvent, TradeDirection, TradeInfo,
+};
+
+// WSOL mint address for trade direction detection
+const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+
+impl JupiterV6RouteEvent {
+    /// Extract trade information with direction detection
+    pub fn get_trade_info(&self) -> Option<TradeInfo> {
+        // 优先使用 SwapEvent 日志中的实际成交，缺失时回退到指令参数
+        let (input_mint, output_mint, amount_in, amount_out) = if self.swap_legs.is_empty() {
+            let (amount_in, amount_out) = if self.exact_out {
+                (self.quoted_in_amount, self.out_amount)
+            } else {
+                (self.in_amount, self.quoted_out_amount)
+            };
+            (self.source_mint, self.destination_mint, amount_in, amount_out)
+        } else {
+            (self.input_mint, self.output_mint, self.input_amount, self.output_amount)
+        };
+
+        // Only process if one of the tokens is WSOL
+        let (direction, token_mint, sol_amount) = if input_mint == WSOL_MINT {
+            (TradeDirection::Buy, output_mint, amount_in)
+        } else if output_mint == WSOL_MINT {
+            (TradeDirection::Sell, input_mint, amount_out)
+        } else {
+            return None;
+        };
+
+        Some(TradeInfo {
+            direction,
+            user_address: self.user_transfer_authority.to_string(),
+            token_mint: token_mint.to_string(),
+            sol_amount: sol_amount as f64 / 1_000_000_000.0,
+            platform: "JupiterV6".to_string(),
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            amount_in,
+            amount_out,
+        })
+    }
+}
+
+impl CopyTradeableEvent for JupiterV6RouteEvent {
+    fn get_trade_info(&self) -> Option<TradeInfo> {
+        self.get_trade_info()
+    }
+}
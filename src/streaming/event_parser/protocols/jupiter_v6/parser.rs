@@ -0,0 +1,535 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_event_parser_delegate,
+    streaming::event_parser::{
+        common::{
+            read_u16_le, read_u32_le, read_u64_le, read_u8, EventMetadata, EventType, ProtocolType,
+        },
+        core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
+        protocols::{
+            bonk::parser::BONK_PROGRAM_ID,
+            jupiter_v6::{
+                discriminators,
+                types::{swap_leg_decode, SwapLeg},
+                JupiterV6RouteEvent, JupiterV6SwapEvent,
+            },
+            meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID,
+            meteora_dbc::parser::METEORA_DBC_PROGRAM_ID,
+            meteora_dlmm::parser::METEORA_DLMM_PROGRAM_ID,
+            orca_whirlpool::parser::ORCA_WHIRLPOOL_PROGRAM_ID,
+            pumpfun::parser::PUMPFUN_PROGRAM_ID,
+            pumpswap::parser::PUMPSWAP_PROGRAM_ID,
+            raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID,
+            raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID,
+            raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID,
+        },
+    },
+};
+
+/// Jupiter v6 聚合器程序ID
+pub const JUPITER_V6_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+/// 路由可关联的 AMM 程序
+const AMM_PROGRAM_IDS: &[Pubkey] = &[
+    PUMPFUN_PROGRAM_ID,
+    PUMPSWAP_PROGRAM_ID,
+    BONK_PROGRAM_ID,
+    RAYDIUM_CPMM_PROGRAM_ID,
+    RAYDIUM_CLMM_PROGRAM_ID,
+    RAYDIUM_AMM_V4_PROGRAM_ID,
+    METEORA_DLMM_PROGRAM_ID,
+    METEORA_DAMM_V2_PROGRAM_ID,
+    METEORA_DBC_PROGRAM_ID,
+    ORCA_WHIRLPOOL_PROGRAM_ID,
+];
+
+/// Anchor 事件 CPI 指令前缀
+const EVENT_IX_TAG: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29];
+
+/// 路由参数末尾固定部分：两个 u64 数量 + slippage_bps(u16) + platform_fee_bps(u8)
+const ROUTE_ARGS_TAIL_SIZE: usize = 19;
+
+/// Jupiter v6 事件解析器
+pub struct JupiterV6EventParser {
+    inner: GenericEventParser,
+}
+
+impl Default for JupiterV6EventParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JupiterV6EventParser {
+    pub fn new() -> Self {
+        // 配置所有事件类型
+        let configs = vec![
+            GenericEventParseConfig {
+                program_id: JUPITER_V6_PROGRAM_ID,
+                protocol_type: ProtocolType::JupiterV6,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::ROUTE,
+                event_type: EventType::JupiterV6Route,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_route_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: JUPITER_V6_PROGRAM_ID,
+                protocol_type: ProtocolType::JupiterV6,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::SHARED_ACCOUNTS_ROUTE,
+                event_type: EventType::JupiterV6SharedAccountsRoute,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_shared_accounts_route_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: JUPITER_V6_PROGRAM_ID,
+                protocol_type: ProtocolType::JupiterV6,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::EXACT_OUT_ROUTE,
+                event_type: EventType::JupiterV6ExactOutRoute,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_exact_out_route_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: JUPITER_V6_PROGRAM_ID,
+                protocol_type: ProtocolType::JupiterV6,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::SHARED_ACCOUNTS_EXACT_OUT_ROUTE,
+                event_type: EventType::JupiterV6SharedAccountsExactOutRoute,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_shared_accounts_exact_out_route_instruction),
+            },
+            // SwapEvent 日志通过自调用指令发出，作为独立事件解析
+            GenericEventParseConfig {
+                program_id: JUPITER_V6_PROGRAM_ID,
+                protocol_type: ProtocolType::JupiterV6,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::SWAP_EVENT,
+                event_type: EventType::JupiterV6Swap,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_swap_event_instruction),
+            },
+        ];
+
+        let inner = GenericEventParser::new(vec![JUPITER_V6_PROGRAM_ID], configs);
+
+        Self { inner }
+    }
+
+    /// 可选账户未传入时为程序ID
+    fn optional_account(account: Pubkey) -> Pubkey {
+        if account == JUPITER_V6_PROGRAM_ID {
+            Pubkey::default()
+        } else {
+            account
+        }
+    }
+
+    /// 解析路由参数：route_plan 长度及末尾的数量、滑点和平台费率
+    fn parse_route_args(data: &[u8], route_plan_offset: usize) -> Option<(u32, u64, u64, u16, u8)> {
+        if data.len() < route_plan_offset + 4 + ROUTE_ARGS_TAIL_SIZE {
+            return None;
+        }
+        let route_plan_len = read_u32_le(data, route_plan_offset)?;
+        let tail = data.len() - ROUTE_ARGS_TAIL_SIZE;
+        Some((
+            route_plan_len,
+            read_u64_le(data, tail)?,
+            read_u64_le(data, tail + 8)?,
+            read_u16_le(data, tail + 16)?,
+            read_u8(data, tail + 18)?,
+        ))
+    }
+
+    /// 解析路由指令事件
+    fn parse_route_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 7 {
+            return None;
+        }
+        let (route_plan_len, in_amount, quoted_out_amount, slippage_bps, platform_fee_bps) =
+            Self::parse_route_args(data, 0)?;
+        Some(Box::new(JupiterV6RouteEvent {
+            metadata,
+            route_plan_len,
+            in_amount,
+            quoted_out_amount,
+            slippage_bps,
+            platform_fee_bps,
+            token_program: accounts[0],
+            user_transfer_authority: accounts[1],
+            user_source_token_account: accounts[2],
+            user_destination_token_account: accounts[3],
+            destination_token_account: Self::optional_account(accounts[4]),
+            destination_mint: accounts[5],
+            platform_fee_account: Self::optional_account(accounts[6]),
+            ..Default::default()
+        }))
+    }
+
+    /// 解析共享账户路由指令事件
+    fn parse_shared_accounts_route_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 11 {
+            return None;
+        }
+        let (route_plan_len, in_amount, quoted_out_amount, slippage_bps, platform_fee_bps) =
+            Self::parse_route_args(data, 1)?;
+        Some(Box::new(JupiterV6RouteEvent {
+            metadata,
+            id: data[0],
+            route_plan_len,
+            in_amount,
+            quoted_out_amount,
+            slippage_bps,
+            platform_fee_bps,
+            ..Self::shared_accounts_route_event(accounts)
+        }))
+    }
+
+    /// 解析精确输出路由指令事件
+    fn parse_exact_out_route_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 9 {
+            return None;
+        }
+        let (route_plan_len, out_amount, quoted_in_amount, slippage_bps, platform_fee_bps) =
+            Self::parse_route_args(data, 0)?;
+        Some(Box::new(JupiterV6RouteEvent {
+            metadata,
+            route_plan_len,
+            exact_out: true,
+            out_amount,
+            quoted_in_amount,
+            slippage_bps,
+            platform_fee_bps,
+            token_program: accounts[0],
+            user_transfer_authority: accounts[1],
+            user_source_token_account: accounts[2],
+            user_destination_token_account: accounts[3],
+            destination_token_account: Self::optional_account(accounts[4]),
+            source_mint: accounts[5],
+            destination_mint: accounts[6],
+            platform_fee_account: Self::optional_account(accounts[7]),
+            token_2022_program: Self::optional_account(accounts[8]),
+            ..Default::default()
+        }))
+    }
+
+    /// 解析共享账户精确输出路由指令事件
+    fn parse_shared_accounts_exact_out_route_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 11 {
+            return None;
+        }
+        let (route_plan_len, out_amount, quoted_in_amount, slippage_bps, platform_fee_bps) =
+            Self::parse_route_args(data, 1)?;
+        Some(Box::new(JupiterV6RouteEvent {
+            metadata,
+            id: data[0],
+            route_plan_len,
+            exact_out: true,
+            out_amount,
+            quoted_in_amount,
+            slippage_bps,
+            platform_fee_bps,
+            ..Self::shared_accounts_route_event(accounts)
+        }))
+    }
+
+    /// 共享账户路由的账户布局
+    fn shared_accounts_route_event(accounts: &[Pubkey]) -> JupiterV6RouteEvent {
+        JupiterV6RouteEvent {
+            token_program: accounts[0],
+            program_authority: accounts[1],
+            user_transfer_authority: accounts[2],
+            user_source_token_account: accounts[3],
+            program_source_token_account: accounts[4],
+            program_destination_token_account: accounts[5],
+            destination_token_account: accounts[6],
+            source_mint: accounts[7],
+            destination_mint: accounts[8],
+            platform_fee_account: Self::optional_account(accounts[9]),
+            token_2022_program: Self::optional_account(accounts[10]),
+            ..Default::default()
+        }
+    }
+
+    /// 解析 SwapEvent 日志事件
+    fn parse_swap_event_instruction(
+        data: &[u8],
+        _accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let leg = swap_leg_decode(data)?;
+        Some(Box::new(JupiterV6SwapEvent {
+            metadata,
+            amm: leg.amm,
+            input_mint: leg.input_mint,
+            input_amount: leg.input_amount,
+            output_mint: leg.output_mint,
+            output_amount: leg.output_amount,
+        }))
+    }
+}
+
+impl_event_parser_delegate!(JupiterV6EventParser);
+
+/// 内联指令的借用视图，屏蔽RPC和gRPC两种结构的差异
+struct ChildInstruction<'a> {
+    program_id_index: u32,
+    data: &'a [u8],
+    stack_height: Option<u32>,
+}
+
+/// 为路由事件关联 CPI 子树中的 AMM 事件ID，并汇总 SwapEvent 日志
+///
+/// `current_index` 为事件所在内联指令的下标，外层指令事件传 None
+pub fn link_route_child_events(
+    event: &mut dyn UnifiedEvent,
+    inner_instruction: &solana_transaction_status::InnerInstructions,
+    current_index: Option<usize>,
+    accounts: &[Pubkey],
+) {
+    let Some(route) = event.as_any_mut().downcast_mut::<JupiterV6RouteEvent>() else {
+        return;
+    };
+    let instructions: Vec<ChildInstruction> = inner_instruction
+        .instructions
+        .iter()
+        .map(|instruction| ChildInstruction {
+            program_id_index: instruction.instruction.program_id_index as u32,
+            data: &instruction.instruction.data,
+            stack_height: instruction.stack_height,
+        })
+        .collect();
+    link_route_children(route, &instructions, current_index, accounts);
+}
+
+/// 为路由事件关联 CPI 子树中的 AMM 事件ID，并汇总 SwapEvent 日志（gRPC）
+pub fn link_route_child_events_grpc(
+    event: &mut dyn UnifiedEvent,
+    inner_instruction: &yellowstone_grpc_proto::prelude::InnerInstructions,
+    current_index: Option<usize>,
+    accounts: &[Pubkey],
+) {
+    let Some(route) = event.as_any_mut().downcast_mut::<JupiterV6RouteEvent>() else {
+        return;
+    };
+    let instructions: Vec<ChildInstruction> = inner_instruction
+        .instructions
+        .iter()
+        .map(|instruction| ChildInstruction {
+            program_id_index: instruction.program_id_index,
+            data: &instruction.data,
+            stack_height: instruction.stack_height,
+        })
+        .collect();
+    link_route_children(route, &instructions, current_index, accounts);
+}
+
+fn link_route_children(
+    route: &mut JupiterV6RouteEvent,
+    instructions: &[ChildInstruction],
+    current_index: Option<usize>,
+    accounts: &[Pubkey],
+) {
+    let start = current_index.map_or(0, |index| index + 1);
+    // 内联指令事件的子树为其后 stack_height 更深的连续指令
+    let parent_height = current_index
+        .and_then(|index| instructions.get(index))
+        .and_then(|instruction| instruction.stack_height);
+
+    let mut swap_legs: Vec<SwapLeg> = Vec::new();
+    let mut child_event_ids = Vec::new();
+    for (index, instruction) in instructions.iter().enumerate().skip(start) {
+        if let (Some(parent_height), Some(height)) = (parent_height, instruction.stack_height) {
+            if height <= parent_height {
+                break;
+            }
+        }
+        let Some(program_id) = accounts.get(instruction.program_id_index as usize) else {
+            break;
+        };
+        if *program_id == JUPITER_V6_PROGRAM_ID {
+            if instruction.data.starts_with(discriminators::SWAP_EVENT) {
+                if let Some(leg) = swap_leg_decode(&instruction.data[16..]) {
+                    swap_legs.push(leg);
+                }
+            }
+        } else if AMM_PROGRAM_IDS.contains(program_id)
+            && !instruction.data.starts_with(EVENT_IX_TAG)
+        {
+            child_event_ids.push(EventMetadata::event_id(
+                &route.metadata.signature,
+                route.metadata.outer_index,
+                Some(index as i64),
+            ));
+        }
+    }
+
+    // 拆单路由的多条首尾腿按 mint 合计
+    if let (Some(first), Some(last)) = (swap_legs.first(), swap_legs.last()) {
+        route.input_mint = first.input_mint;
+        route.output_mint = last.output_mint;
+        route.input_amount = swap_legs
+            .iter()
+            .filter(|leg| leg.input_mint == route.input_mint)
+            .map(|leg| leg.input_amount)
+            .sum();
+        route.output_amount = swap_legs
+            .iter()
+            .filter(|leg| leg.output_mint == route.output_mint)
+            .map(|leg| leg.output_amount)
+            .sum();
+    }
+    route.swap_legs = swap_legs;
+    route.child_event_ids = child_event_ids;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        protocols::{
+            mutil::parser::MutilEventParser,
+            raydium_cpmm::{discriminators as cpmm_discriminators, RaydiumCpmmSwapEvent},
+        },
+        EventParser, Protocol,
+    };
+    use solana_sdk::signature::Signature;
+    use std::sync::{Arc, Mutex};
+    use yellowstone_grpc_proto::prelude::{
+        CompiledInstruction, InnerInstruction, InnerInstructions, Message,
+        SubscribeUpdateTransactionInfo, Transaction, TransactionStatusMeta,
+    };
+
+    const CPMM_INDEX: u32 = 13;
+    const JUPITER_INDEX: u32 = 14;
+
+    fn inner_instruction(
+        program_id_index: u32,
+        data: Vec<u8>,
+        stack_height: u32,
+    ) -> InnerInstruction {
+        InnerInstruction {
+            program_id_index,
+            accounts: (0..13).collect(),
+            data,
+            stack_height: Some(stack_height),
+        }
+    }
+
+    fn cpmm_swap(amount_in: u64) -> InnerInstruction {
+        let mut data = cpmm_discriminators::SWAP_BASE_IN.to_vec();
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        inner_instruction(CPMM_INDEX, data, 2)
+    }
+
+    fn swap_event(leg: [&Pubkey; 3], input_amount: u64, output_amount: u64) -> InnerInstruction {
+        let mut data = discriminators::SWAP_EVENT.to_vec();
+        data.extend_from_slice(leg[0].as_ref());
+        data.extend_from_slice(leg[1].as_ref());
+        data.extend_from_slice(&input_amount.to_le_bytes());
+        data.extend_from_slice(leg[2].as_ref());
+        data.extend_from_slice(&output_amount.to_le_bytes());
+        inner_instruction(JUPITER_INDEX, data, 2)
+    }
+
+    /// 两跳路由：SOL -> USDC -> BONK，每跳一次 CPMM 交易和一条 SwapEvent
+    fn two_hop_route(mints: &[Pubkey; 3]) -> SubscribeUpdateTransactionInfo {
+        let mut account_keys: Vec<Vec<u8>> =
+            (0..CPMM_INDEX).map(|_| Pubkey::new_unique().to_bytes().to_vec()).collect();
+        account_keys.push(RAYDIUM_CPMM_PROGRAM_ID.to_bytes().to_vec());
+        account_keys.push(JUPITER_V6_PROGRAM_ID.to_bytes().to_vec());
+        let mut route_data = discriminators::ROUTE.to_vec();
+        route_data.extend_from_slice(&0u32.to_le_bytes());
+        route_data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        route_data.extend_from_slice(&4_900u64.to_le_bytes());
+        route_data.extend_from_slice(&50u16.to_le_bytes());
+        route_data.push(0);
+        let amm = Pubkey::new_unique();
+        SubscribeUpdateTransactionInfo {
+            transaction: Some(Transaction {
+                signatures: vec![vec![1; 64]],
+                message: Some(Message {
+                    account_keys,
+                    instructions: vec![CompiledInstruction {
+                        program_id_index: JUPITER_INDEX,
+                        accounts: (0..7).collect(),
+                        data: route_data,
+                    }],
+                    ..Default::default()
+                }),
+            }),
+            meta: Some(TransactionStatusMeta {
+                inner_instructions: vec![InnerInstructions {
+                    index: 0,
+                    instructions: vec![
+                        cpmm_swap(1_000_000),
+                        swap_event([&amm, &mints[0], &mints[1]], 1_000_000, 150_000),
+                        cpmm_swap(150_000),
+                        swap_event([&amm, &mints[1], &mints[2]], 150_000, 5_000),
+                    ],
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn route_links_child_event_ids() {
+        let mints = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let parser = MutilEventParser::new(vec![Protocol::JupiterV6, Protocol::RaydiumCpmm], None);
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        parser
+            .parse_grpc_transaction_owned(
+                two_hop_route(&mints),
+                Signature::new_unique(),
+                Some(1),
+                None,
+                0,
+                None,
+                None,
+                Arc::new(move |event: Box<dyn UnifiedEvent>| sink.lock().unwrap().push(event)),
+            )
+            .await
+            .unwrap();
+        let events = events.lock().unwrap();
+
+        let route = events
+            .iter()
+            .find_map(|event| event.as_any().downcast_ref::<JupiterV6RouteEvent>())
+            .unwrap();
+        let child_ids: Vec<&str> = events
+            .iter()
+            .filter_map(|event| event.as_any().downcast_ref::<RaydiumCpmmSwapEvent>())
+            .map(|event| event.metadata.id.as_str())
+            .collect();
+        assert_eq!(child_ids.len(), 2);
+        assert_eq!(route.child_event_ids, child_ids);
+        assert!(route.child_event_ids[1].ends_with("-0-2"));
+
+        assert_eq!(route.swap_legs.len(), 2);
+        assert_eq!((route.input_mint, route.input_amount), (mints[0], 1_000_000));
+        assert_eq!((route.output_mint, route.output_amount), (mints[2], 5_000));
+    }
+}
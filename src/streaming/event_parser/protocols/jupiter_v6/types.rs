@@ -0,0 +1,22 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// SwapEvent 日志：路由中单个 AMM 的输入输出
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapLeg {
+    pub amm: Pubkey,
+    pub input_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_mint: Pubkey,
+    pub output_amount: u64,
+}
+
+pub const SWAP_LEG_SIZE: usize = 112;
+
+pub fn swap_leg_decode(data: &[u8]) -> Option<SwapLeg> {
+    if data.len() < SWAP_LEG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<SwapLeg>(&data[..SWAP_LEG_SIZE]).ok()
+}
@@ -0,0 +1,484 @@
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::meteora_damm_v2::types::Pool;
+use crate::impl_unified_event;
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 交易
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2SwapEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // EvtSwap 日志
+    pub pool: Pubkey,
+    /// 0: A -> B, 1: B -> A
+    pub trade_direction: u8,
+    pub has_referral: bool,
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+    pub output_amount: u64,
+    pub next_sqrt_price: u128,
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+    pub partner_fee: u64,
+    pub referral_fee: u64,
+    pub actual_amount_in: u64,
+    pub current_timestamp: u64,
+    #[borsh(skip)]
+    pub pool_authority: Pubkey,
+    #[borsh(skip)]
+    pub input_token_account: Pubkey,
+    #[borsh(skip)]
+    pub output_token_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_b_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_a_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_b_mint: Pubkey,
+    #[borsh(skip)]
+    pub payer: Pubkey,
+    #[borsh(skip)]
+    pub token_a_program: Pubkey,
+    #[borsh(skip)]
+    pub token_b_program: Pubkey,
+    #[borsh(skip)]
+    pub referral_token_account: Pubkey,
+}
+
+pub const METEORA_DAMM_V2_SWAP_EVENT_LOG_SIZE: usize = 122;
+
+pub fn meteora_damm_v2_swap_event_log_decode(data: &[u8]) -> Option<MeteoraDammV2SwapEvent> {
+    if data.len() < METEORA_DAMM_V2_SWAP_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2SwapEvent>(&data[..METEORA_DAMM_V2_SWAP_EVENT_LOG_SIZE]).ok()
+}
+
+impl_unified_event!(
+    MeteoraDammV2SwapEvent,
+    pool,
+    trade_direction,
+    has_referral,
+    amount_in,
+    minimum_amount_out,
+    output_amount,
+    next_sqrt_price,
+    lp_fee,
+    protocol_fee,
+    partner_fee,
+    referral_fee,
+    actual_amount_in,
+    current_timestamp
+);
+
+/// 添加流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2AddLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // EvtAddLiquidity 日志
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub liquidity_delta: u128,
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub total_amount_a: u64,
+    pub total_amount_b: u64,
+    #[borsh(skip)]
+    pub token_a_account: Pubkey,
+    #[borsh(skip)]
+    pub token_b_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_b_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_a_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_b_mint: Pubkey,
+    #[borsh(skip)]
+    pub position_nft_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_program: Pubkey,
+    #[borsh(skip)]
+    pub token_b_program: Pubkey,
+}
+
+pub const METEORA_DAMM_V2_ADD_LIQUIDITY_EVENT_LOG_SIZE: usize = 160;
+
+pub fn meteora_damm_v2_add_liquidity_event_log_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV2AddLiquidityEvent> {
+    if data.len() < METEORA_DAMM_V2_ADD_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2AddLiquidityEvent>(
+        &data[..METEORA_DAMM_V2_ADD_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    MeteoraDammV2AddLiquidityEvent,
+    pool,
+    position,
+    owner,
+    liquidity_delta,
+    token_a_amount_threshold,
+    token_b_amount_threshold,
+    token_a_amount,
+    token_b_amount,
+    total_amount_a,
+    total_amount_b
+);
+
+/// 移除流动性（remove_liquidity / remove_all_liquidity）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2RemoveLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // EvtRemoveLiquidity 日志
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub liquidity_delta: u128,
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    #[borsh(skip)]
+    pub pool_authority: Pubkey,
+    #[borsh(skip)]
+    pub token_a_account: Pubkey,
+    #[borsh(skip)]
+    pub token_b_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_b_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_a_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_b_mint: Pubkey,
+    #[borsh(skip)]
+    pub position_nft_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_program: Pubkey,
+    #[borsh(skip)]
+    pub token_b_program: Pubkey,
+}
+
+pub const METEORA_DAMM_V2_REMOVE_LIQUIDITY_EVENT_LOG_SIZE: usize = 144;
+
+pub fn meteora_damm_v2_remove_liquidity_event_log_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV2RemoveLiquidityEvent> {
+    if data.len() < METEORA_DAMM_V2_REMOVE_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2RemoveLiquidityEvent>(
+        &data[..METEORA_DAMM_V2_REMOVE_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    MeteoraDammV2RemoveLiquidityEvent,
+    pool,
+    position,
+    owner,
+    liquidity_delta,
+    token_a_amount_threshold,
+    token_b_amount_threshold,
+    token_a_amount,
+    token_b_amount
+);
+
+/// 创建池子（initialize_pool / initialize_customizable_pool / initialize_pool_with_dynamic_config）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2InitializePoolEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // EvtInitializePool 日志前缀（其后的费率参数为变长结构）
+    pub pool: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub creator: Pubkey,
+    pub payer: Pubkey,
+    pub alpha_vault: Pubkey,
+    // 仅 initialize_pool 指令
+    #[borsh(skip)]
+    pub liquidity: u128,
+    #[borsh(skip)]
+    pub sqrt_price: u128,
+    #[borsh(skip)]
+    pub config: Pubkey,
+    #[borsh(skip)]
+    pub position_nft_mint: Pubkey,
+    #[borsh(skip)]
+    pub position_nft_account: Pubkey,
+    #[borsh(skip)]
+    pub position: Pubkey,
+    #[borsh(skip)]
+    pub token_a_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_b_vault: Pubkey,
+    #[borsh(skip)]
+    pub payer_token_a: Pubkey,
+    #[borsh(skip)]
+    pub payer_token_b: Pubkey,
+}
+
+pub const METEORA_DAMM_V2_INITIALIZE_POOL_EVENT_LOG_SIZE: usize = 192;
+
+pub fn meteora_damm_v2_initialize_pool_event_log_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV2InitializePoolEvent> {
+    if data.len() < METEORA_DAMM_V2_INITIALIZE_POOL_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2InitializePoolEvent>(
+        &data[..METEORA_DAMM_V2_INITIALIZE_POOL_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    MeteoraDammV2InitializePoolEvent,
+    pool,
+    token_a_mint,
+    token_b_mint,
+    creator,
+    payer,
+    alpha_vault
+);
+
+/// 创建仓位
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2CreatePositionEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // EvtCreatePosition 日志
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub position: Pubkey,
+    pub position_nft_mint: Pubkey,
+    #[borsh(skip)]
+    pub position_nft_account: Pubkey,
+    #[borsh(skip)]
+    pub pool_authority: Pubkey,
+    #[borsh(skip)]
+    pub payer: Pubkey,
+}
+
+pub const METEORA_DAMM_V2_CREATE_POSITION_EVENT_LOG_SIZE: usize = 128;
+
+pub fn meteora_damm_v2_create_position_event_log_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV2CreatePositionEvent> {
+    if data.len() < METEORA_DAMM_V2_CREATE_POSITION_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2CreatePositionEvent>(
+        &data[..METEORA_DAMM_V2_CREATE_POSITION_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(MeteoraDammV2CreatePositionEvent, pool, owner, position, position_nft_mint);
+
+/// 领取仓位手续费
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2ClaimPositionFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // EvtClaimPositionFee 日志
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub fee_a_claimed: u64,
+    pub fee_b_claimed: u64,
+    #[borsh(skip)]
+    pub pool_authority: Pubkey,
+    #[borsh(skip)]
+    pub token_a_account: Pubkey,
+    #[borsh(skip)]
+    pub token_b_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_b_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_a_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_b_mint: Pubkey,
+    #[borsh(skip)]
+    pub position_nft_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_program: Pubkey,
+    #[borsh(skip)]
+    pub token_b_program: Pubkey,
+}
+
+pub const METEORA_DAMM_V2_CLAIM_POSITION_FEE_EVENT_LOG_SIZE: usize = 112;
+
+pub fn meteora_damm_v2_claim_position_fee_event_log_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV2ClaimPositionFeeEvent> {
+    if data.len() < METEORA_DAMM_V2_CLAIM_POSITION_FEE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2ClaimPositionFeeEvent>(
+        &data[..METEORA_DAMM_V2_CLAIM_POSITION_FEE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    MeteoraDammV2ClaimPositionFeeEvent,
+    pool,
+    position,
+    owner,
+    fee_a_claimed,
+    fee_b_claimed
+);
+
+/// 池子
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDammV2PoolAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub pool: Pool,
+}
+impl_unified_event!(MeteoraDammV2PoolAccountEvent,);
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 事件鉴别器
+    pub const SWAP_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 27, 60, 21, 213, 138, 170, 187, 147];
+    pub const ADD_LIQUIDITY_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 175, 242, 8, 157, 30, 247, 185, 169];
+    pub const REMOVE_LIQUIDITY_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 87, 46, 88, 98, 175, 96, 34, 91];
+    pub const INITIALIZE_POOL_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 228, 50, 246, 85, 203, 66, 134, 37];
+    pub const CREATE_POSITION_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 156, 15, 119, 198, 29, 181, 221, 55];
+    pub const CLAIM_POSITION_FEE_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 198, 182, 183, 52, 97, 12, 49, 56];
+
+    // 指令鉴别器
+    pub const SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const ADD_LIQUIDITY: &[u8] = &[181, 157, 89, 67, 143, 182, 52, 72];
+    pub const REMOVE_LIQUIDITY: &[u8] = &[80, 85, 209, 72, 24, 206, 177, 108];
+    pub const REMOVE_ALL_LIQUIDITY: &[u8] = &[10, 51, 61, 35, 112, 105, 24, 85];
+    pub const INITIALIZE_POOL: &[u8] = &[95, 180, 10, 172, 84, 174, 232, 40];
+    pub const INITIALIZE_CUSTOMIZABLE_POOL: &[u8] = &[20, 161, 241, 24, 189, 221, 180, 2];
+    pub const INITIALIZE_POOL_WITH_DYNAMIC_CONFIG: &[u8] = &[149, 82, 72, 197, 253, 252, 68, 15];
+    pub const CREATE_POSITION: &[u8] = &[48, 215, 197, 153, 96, 203, 180, 133];
+    pub const CLAIM_POSITION_FEE: &[u8] = &[180, 38, 154, 17, 133, 33, 162, 211];
+
+    // 账号鉴别器
+    pub const POOL: &[u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
+}
+
+use crate::streaming::event_parser::protocols::raydium_cpmm::types::{
+    CopyTradeableEvent, TradeDirection, TradeInfo,
+};
+
+// WSOL mint address for trade direction detection
+const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+
+impl MeteoraDammV2SwapEvent {
+    /// Extract trade information with direction detection
+    pub fn get_trade_info(&self) -> Option<TradeInfo> {
+        let (input_mint, output_mint) = if self.trade_direction == 0 {
+            (self.token_a_mint, self.token_b_mint)
+        } else {
+            (self.token_b_mint, self.token_a_mint)
+        };
+        // 日志中的实际输入扣除了转账手续费，缺失时回退到指令参数
+        let amount_in =
+            if self.actual_amount_in > 0 { self.actual_amount_in } else { self.amount_in };
+
+        // Only process if one of the tokens is WSOL
+        let (direction, token_mint, sol_amount) = if input_mint == WSOL_MINT {
+            (TradeDirection::Buy, output_mint, amount_in)
+        } else if output_mint == WSOL_MINT {
+            (TradeDirection::Sell, input_mint, self.output_amount)
+        } else {
+            return None;
+        };
+
+        Some(TradeInfo {
+            direction,
+            user_address: self.payer.to_string(),
+            token_mint: token_mint.to_string(),
+            sol_amount: sol_amount as f64 / 1_000_000_000.0,
+            platform: "MeteoraDammV2".to_string(),
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            amount_in,
+            amount_out: self.output_amount,
+        })
+    }
+}
+
+impl CopyTradeableEvent for MeteoraDammV2SwapEvent {
+    fn get_trade_info(&self) -> Option<TradeInfo> {
+        self.get_trade_info()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use std::str::FromStr;
+
+    // 按 EvtSwap 布局构造：鉴别器 + 事件数据的 base64
+    const SWAP_EVENT_LOG: &str = concat!(
+        "5EWlLlHLmh0bPBXViqq7k1J1Ja780EFTUYvH/2vIKitgM5qJrZ7vRnLn5IweS7MSAQEAlDV3AAAAAOD9HAAAAAAA",
+        "q8EdAAAAAAAAAAAAAAAAAAAAAAABAAAAiBMAAAAAAADoAwAAAAAAAPQBAAAAAAAA+gAAAAAAAADAUSZ3AAAAAAB4",
+        "52gAAAAA",
+    );
+
+    #[test]
+    fn swap_event_log_layout() {
+        let data = STANDARD.decode(SWAP_EVENT_LOG).unwrap();
+        assert_eq!(&data[..discriminators::SWAP_EVENT.len()], discriminators::SWAP_EVENT);
+        let data = &data[discriminators::SWAP_EVENT.len()..];
+
+        let event = meteora_damm_v2_swap_event_log_decode(data).unwrap();
+        assert_eq!(
+            event.pool,
+            Pubkey::from_str("6Yt38nBGCXttyFdvCtW5V4ViwfZXRR7nAGk2Kr3N962M").unwrap()
+        );
+        assert_eq!(event.trade_direction, 1);
+        assert!(event.has_referral);
+        assert_eq!(event.amount_in, 2_000_000_000);
+        assert_eq!(event.minimum_amount_out, 1_900_000);
+        assert_eq!(event.output_amount, 1_950_123);
+        assert_eq!(event.next_sqrt_price, 1u128 << 96);
+        assert_eq!(
+            (event.lp_fee, event.protocol_fee, event.partner_fee, event.referral_fee),
+            (5_000, 1_000, 500, 250)
+        );
+        assert_eq!(event.actual_amount_in, 1_999_000_000);
+        assert_eq!(event.current_timestamp, 1_760_000_000);
+
+        assert!(meteora_damm_v2_swap_event_log_decode(&data[..data.len() - 1]).is_none());
+    }
+}
@@ -0,0 +1,442 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_event_parser_delegate,
+    streaming::event_parser::{
+        common::{read_u128_le, read_u64_le, EventMetadata, EventType, ProtocolType},
+        core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
+        protocols::meteora_damm_v2::{
+            discriminators, meteora_damm_v2_add_liquidity_event_log_decode,
+            meteora_damm_v2_claim_position_fee_event_log_decode,
+            meteora_damm_v2_create_position_event_log_decode,
+            meteora_damm_v2_initialize_pool_event_log_decode,
+            meteora_damm_v2_remove_liquidity_event_log_decode,
+            meteora_damm_v2_swap_event_log_decode, MeteoraDammV2AddLiquidityEvent,
+            MeteoraDammV2ClaimPositionFeeEvent, MeteoraDammV2CreatePositionEvent,
+            MeteoraDammV2InitializePoolEvent, MeteoraDammV2RemoveLiquidityEvent,
+            MeteoraDammV2SwapEvent,
+        },
+    },
+};
+
+/// Meteora DAMM v2 (cp-amm) 程序ID
+pub const METEORA_DAMM_V2_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG");
+
+/// Meteora DAMM v2事件解析器
+pub struct MeteoraDammV2EventParser {
+    inner: GenericEventParser,
+}
+
+impl Default for MeteoraDammV2EventParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MeteoraDammV2EventParser {
+    pub fn new() -> Self {
+        // 配置所有事件类型
+        let configs = vec![
+            GenericEventParseConfig {
+                program_id: METEORA_DAMM_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDammV2,
+                inner_instruction_discriminator: discriminators::SWAP_EVENT,
+                instruction_discriminator: discriminators::SWAP,
+                event_type: EventType::MeteoraDammV2Swap,
+                inner_instruction_parser: Some(Self::parse_swap_inner_instruction),
+                instruction_parser: Some(Self::parse_swap_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DAMM_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDammV2,
+                inner_instruction_discriminator: discriminators::ADD_LIQUIDITY_EVENT,
+                instruction_discriminator: discriminators::ADD_LIQUIDITY,
+                event_type: EventType::MeteoraDammV2AddLiquidity,
+                inner_instruction_parser: Some(Self::parse_add_liquidity_inner_instruction),
+                instruction_parser: Some(Self::parse_add_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DAMM_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDammV2,
+                inner_instruction_discriminator: discriminators::REMOVE_LIQUIDITY_EVENT,
+                instruction_discriminator: discriminators::REMOVE_LIQUIDITY,
+                event_type: EventType::MeteoraDammV2RemoveLiquidity,
+                inner_instruction_parser: Some(Self::parse_remove_liquidity_inner_instruction),
+                instruction_parser: Some(Self::parse_remove_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DAMM_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDammV2,
+                inner_instruction_discriminator: discriminators::REMOVE_LIQUIDITY_EVENT,
+                instruction_discriminator: discriminators::REMOVE_ALL_LIQUIDITY,
+                event_type: EventType::MeteoraDammV2RemoveLiquidity,
+                inner_instruction_parser: Some(Self::parse_remove_liquidity_inner_instruction),
+                instruction_parser: Some(Self::parse_remove_all_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DAMM_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDammV2,
+                inner_instruction_discriminator: discriminators::INITIALIZE_POOL_EVENT,
+                instruction_discriminator: discriminators::INITIALIZE_POOL,
+                event_type: EventType::MeteoraDammV2InitializePool,
+                inner_instruction_parser: Some(Self::parse_initialize_pool_inner_instruction),
+                instruction_parser: Some(Self::parse_initialize_pool_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DAMM_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDammV2,
+                inner_instruction_discriminator: discriminators::INITIALIZE_POOL_EVENT,
+                instruction_discriminator: discriminators::INITIALIZE_CUSTOMIZABLE_POOL,
+                event_type: EventType::MeteoraDammV2InitializePool,
+                inner_instruction_parser: Some(Self::parse_initialize_pool_inner_instruction),
+                instruction_parser: Some(Self::parse_initialize_customizable_pool_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DAMM_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDammV2,
+                inner_instruction_discriminator: discriminators::INITIALIZE_POOL_EVENT,
+                instruction_discriminator: discriminators::INITIALIZE_POOL_WITH_DYNAMIC_CONFIG,
+                event_type: EventType::MeteoraDammV2InitializePool,
+                inner_instruction_parser: Some(Self::parse_initialize_pool_inner_instruction),
+                instruction_parser: Some(
+                    Self::parse_initialize_pool_with_dynamic_config_instruction,
+                ),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DAMM_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDammV2,
+                inner_instruction_discriminator: discriminators::CREATE_POSITION_EVENT,
+                instruction_discriminator: discriminators::CREATE_POSITION,
+                event_type: EventType::MeteoraDammV2CreatePosition,
+                inner_instruction_parser: Some(Self::parse_create_position_inner_instruction),
+                instruction_parser: Some(Self::parse_create_position_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DAMM_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDammV2,
+                inner_instruction_discriminator: discriminators::CLAIM_POSITION_FEE_EVENT,
+                instruction_discriminator: discriminators::CLAIM_POSITION_FEE,
+                event_type: EventType::MeteoraDammV2ClaimPositionFee,
+                inner_instruction_parser: Some(Self::parse_claim_position_fee_inner_instruction),
+                instruction_parser: Some(Self::parse_claim_position_fee_instruction),
+            },
+        ];
+
+        let inner = GenericEventParser::new(vec![METEORA_DAMM_V2_PROGRAM_ID], configs);
+
+        Self { inner }
+    }
+
+    /// 解析交易日志事件
+    fn parse_swap_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_damm_v2_swap_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析添加流动性日志事件
+    fn parse_add_liquidity_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_damm_v2_add_liquidity_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析移除流动性日志事件
+    fn parse_remove_liquidity_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_damm_v2_remove_liquidity_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析创建池子日志事件
+    fn parse_initialize_pool_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_damm_v2_initialize_pool_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析创建仓位日志事件
+    fn parse_create_position_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_damm_v2_create_position_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析领取手续费日志事件
+    fn parse_claim_position_fee_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_damm_v2_claim_position_fee_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析交易指令事件
+    fn parse_swap_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 16 || accounts.len() < 11 {
+            return None;
+        }
+        Some(Box::new(MeteoraDammV2SwapEvent {
+            metadata,
+            pool: accounts[1],
+            amount_in: read_u64_le(data, 0)?,
+            minimum_amount_out: read_u64_le(data, 8)?,
+            pool_authority: accounts[0],
+            input_token_account: accounts[2],
+            output_token_account: accounts[3],
+            token_a_vault: accounts[4],
+            token_b_vault: accounts[5],
+            token_a_mint: accounts[6],
+            token_b_mint: accounts[7],
+            payer: accounts[8],
+            token_a_program: accounts[9],
+            token_b_program: accounts[10],
+            referral_token_account: accounts.get(11).copied().unwrap_or_default(),
+            ..Default::default()
+        }))
+    }
+
+    /// 解析添加流动性指令事件
+    fn parse_add_liquidity_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 32 || accounts.len() < 12 {
+            return None;
+        }
+        Some(Box::new(MeteoraDammV2AddLiquidityEvent {
+            metadata,
+            pool: accounts[0],
+            position: accounts[1],
+            owner: accounts[9],
+            liquidity_delta: read_u128_le(data, 0)?,
+            token_a_amount_threshold: read_u64_le(data, 16)?,
+            token_b_amount_threshold: read_u64_le(data, 24)?,
+            token_a_account: accounts[2],
+            token_b_account: accounts[3],
+            token_a_vault: accounts[4],
+            token_b_vault: accounts[5],
+            token_a_mint: accounts[6],
+            token_b_mint: accounts[7],
+            position_nft_account: accounts[8],
+            token_a_program: accounts[10],
+            token_b_program: accounts[11],
+            ..Default::default()
+        }))
+    }
+
+    /// 构建移除流动性事件的账户部分
+    fn remove_liquidity_event_from_accounts(
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> MeteoraDammV2RemoveLiquidityEvent {
+        MeteoraDammV2RemoveLiquidityEvent {
+            metadata,
+            pool: accounts[1],
+            position: accounts[2],
+            owner: accounts[10],
+            pool_authority: accounts[0],
+            token_a_account: accounts[3],
+            token_b_account: accounts[4],
+            token_a_vault: accounts[5],
+            token_b_vault: accounts[6],
+            token_a_mint: accounts[7],
+            token_b_mint: accounts[8],
+            position_nft_account: accounts[9],
+            token_a_program: accounts[11],
+            token_b_program: accounts[12],
+            ..Default::default()
+        }
+    }
+
+    /// 解析移除流动性指令事件
+    fn parse_remove_liquidity_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 32 || accounts.len() < 13 {
+            return None;
+        }
+        Some(Box::new(MeteoraDammV2RemoveLiquidityEvent {
+            liquidity_delta: read_u128_le(data, 0)?,
+            token_a_amount_threshold: read_u64_le(data, 16)?,
+            token_b_amount_threshold: read_u64_le(data, 24)?,
+            ..Self::remove_liquidity_event_from_accounts(accounts, metadata)
+        }))
+    }
+
+    /// 解析移除全部流动性指令事件，流动性数量由日志补齐
+    fn parse_remove_all_liquidity_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 16 || accounts.len() < 13 {
+            return None;
+        }
+        Some(Box::new(MeteoraDammV2RemoveLiquidityEvent {
+            token_a_amount_threshold: read_u64_le(data, 0)?,
+            token_b_amount_threshold: read_u64_le(data, 8)?,
+            ..Self::remove_liquidity_event_from_accounts(accounts, metadata)
+        }))
+    }
+
+    /// 解析创建池子指令事件
+    fn parse_initialize_pool_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 32 || accounts.len() < 14 {
+            return None;
+        }
+        Some(Box::new(MeteoraDammV2InitializePoolEvent {
+            metadata,
+            pool: accounts[6],
+            token_a_mint: accounts[8],
+            token_b_mint: accounts[9],
+            creator: accounts[0],
+            payer: accounts[3],
+            liquidity: read_u128_le(data, 0)?,
+            sqrt_price: read_u128_le(data, 16)?,
+            config: accounts[4],
+            position_nft_mint: accounts[1],
+            position_nft_account: accounts[2],
+            position: accounts[7],
+            token_a_vault: accounts[10],
+            token_b_vault: accounts[11],
+            payer_token_a: accounts[12],
+            payer_token_b: accounts[13],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析自定义参数创建池子指令事件，变长的费率参数不做解析
+    fn parse_initialize_customizable_pool_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 13 {
+            return None;
+        }
+        Some(Box::new(MeteoraDammV2InitializePoolEvent {
+            metadata,
+            pool: accounts[5],
+            token_a_mint: accounts[7],
+            token_b_mint: accounts[8],
+            creator: accounts[0],
+            payer: accounts[3],
+            position_nft_mint: accounts[1],
+            position_nft_account: accounts[2],
+            position: accounts[6],
+            token_a_vault: accounts[9],
+            token_b_vault: accounts[10],
+            payer_token_a: accounts[11],
+            payer_token_b: accounts[12],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析动态配置创建池子指令事件，变长的费率参数不做解析
+    fn parse_initialize_pool_with_dynamic_config_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 15 {
+            return None;
+        }
+        Some(Box::new(MeteoraDammV2InitializePoolEvent {
+            metadata,
+            pool: accounts[7],
+            token_a_mint: accounts[9],
+            token_b_mint: accounts[10],
+            creator: accounts[0],
+            payer: accounts[3],
+            config: accounts[5],
+            position_nft_mint: accounts[1],
+            position_nft_account: accounts[2],
+            position: accounts[8],
+            token_a_vault: accounts[11],
+            token_b_vault: accounts[12],
+            payer_token_a: accounts[13],
+            payer_token_b: accounts[14],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析创建仓位指令事件
+    fn parse_create_position_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 7 {
+            return None;
+        }
+        Some(Box::new(MeteoraDammV2CreatePositionEvent {
+            metadata,
+            pool: accounts[3],
+            owner: accounts[0],
+            position: accounts[4],
+            position_nft_mint: accounts[1],
+            position_nft_account: accounts[2],
+            pool_authority: accounts[5],
+            payer: accounts[6],
+        }))
+    }
+
+    /// 解析领取仓位手续费指令事件
+    fn parse_claim_position_fee_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 13 {
+            return None;
+        }
+        Some(Box::new(MeteoraDammV2ClaimPositionFeeEvent {
+            metadata,
+            pool: accounts[1],
+            position: accounts[2],
+            owner: accounts[10],
+            pool_authority: accounts[0],
+            token_a_account: accounts[3],
+            token_b_account: accounts[4],
+            token_a_vault: accounts[5],
+            token_b_vault: accounts[6],
+            token_a_mint: accounts[7],
+            token_b_mint: accounts[8],
+            position_nft_account: accounts[9],
+            token_a_program: accounts[11],
+            token_b_program: accounts[12],
+            ..Default::default()
+        }))
+    }
+}
+
+impl_event_parser_delegate!(MeteoraDammV2EventParser);
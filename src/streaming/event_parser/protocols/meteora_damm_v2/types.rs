@@ -0,0 +1,193 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::EventMetadata, protocols::meteora_damm_v2::MeteoraDammV2PoolAccountEvent,
+        UnifiedEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BaseFeeStruct {
+    pub cliff_fee_numerator: u64,
+    pub fee_scheduler_mode: u8,
+    pub padding_0: [u8; 5],
+    pub number_of_period: u16,
+    pub period_frequency: u64,
+    pub reduction_factor: u64,
+    pub padding_1: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicFeeStruct {
+    pub initialized: u8,
+    pub padding: [u8; 7],
+    pub max_volatility_accumulator: u32,
+    pub variable_fee_control: u32,
+    pub bin_step: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub last_update_timestamp: u64,
+    pub bin_step_u128: u128,
+    pub sqrt_price_reference: u128,
+    pub volatility_accumulator: u128,
+    pub volatility_reference: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolFeesStruct {
+    pub base_fee: BaseFeeStruct,
+    pub protocol_fee_percent: u8,
+    pub partner_fee_percent: u8,
+    pub referral_fee_percent: u8,
+    pub padding_0: [u8; 5],
+    pub dynamic_fee: DynamicFeeStruct,
+    pub padding_1: [u64; 2],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolMetrics {
+    pub total_lp_a_fee: u128,
+    pub total_lp_b_fee: u128,
+    pub total_protocol_a_fee: u64,
+    pub total_protocol_b_fee: u64,
+    pub total_partner_a_fee: u64,
+    pub total_partner_b_fee: u64,
+    pub total_position: u64,
+    pub padding: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RewardInfo {
+    pub initialized: u8,
+    pub reward_token_flag: u8,
+    pub padding_0: [u8; 6],
+    pub padding_1: [u8; 8],
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub funder: Pubkey,
+    pub reward_duration: u64,
+    pub reward_duration_end: u64,
+    pub reward_rate: u128,
+    pub reward_per_token_stored: [u8; 32],
+    pub last_update_time: u64,
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Pool {
+    pub pool_fees: PoolFeesStruct,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+    pub whitelisted_vault: Pubkey,
+    pub partner: Pubkey,
+    pub liquidity: u128,
+    pub padding: u128,
+    pub protocol_a_fee: u64,
+    pub protocol_b_fee: u64,
+    pub partner_a_fee: u64,
+    pub partner_b_fee: u64,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub sqrt_price: u128,
+    pub activation_point: u64,
+    pub activation_type: u8,
+    pub pool_status: u8,
+    pub token_a_flag: u8,
+    pub token_b_flag: u8,
+    pub collect_fee_mode: u8,
+    pub pool_type: u8,
+    pub padding_0: [u8; 2],
+    pub fee_a_per_liquidity: [u8; 32],
+    pub fee_b_per_liquidity: [u8; 32],
+    pub permanent_lock_liquidity: u128,
+    pub metrics: PoolMetrics,
+    pub creator: Pubkey,
+    pub padding_1: [u64; 6],
+    pub reward_infos: [RewardInfo; 2],
+}
+
+pub const POOL_SIZE: usize = 1104;
+
+pub fn pool_decode(data: &[u8]) -> Option<Pool> {
+    if data.len() < POOL_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Pool>(&data[..POOL_SIZE]).ok()
+}
+
+pub fn pool_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < POOL_SIZE + 8 {
+        return None;
+    }
+    let pool = pool_decode(&account.data[8..POOL_SIZE + 8])?;
+    Some(Box::new(MeteoraDammV2PoolAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        pool,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按账户布局偏移写入字段，偏移包含 8 字节鉴别器
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[8 + offset..8 + offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[test]
+    fn pool_layout() {
+        let (mint_a, mint_b, vault_a, vault_b, creator, reward_mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut data = vec![0u8; POOL_SIZE + 8];
+        put(&mut data, 0, &2_500_000u64.to_le_bytes());
+        put(&mut data, 40, &[20]);
+        put(&mut data, 160, mint_a.as_ref());
+        put(&mut data, 192, mint_b.as_ref());
+        put(&mut data, 224, vault_a.as_ref());
+        put(&mut data, 256, vault_b.as_ref());
+        put(&mut data, 352, &123_456_789u128.to_le_bytes());
+        put(&mut data, 448, &(1u128 << 64).to_le_bytes());
+        put(&mut data, 464, &1_760_000_000u64.to_le_bytes());
+        put(&mut data, 473, &[1]);
+        put(&mut data, 476, &[2]);
+        put(&mut data, 640, creator.as_ref());
+        // 第二个奖励信息从偏移 720 + 192 开始，mint 位于其中偏移 16
+        put(&mut data, 720 + 192 + 16, reward_mint.as_ref());
+        let account = AccountPretty { data, ..Default::default() };
+
+        let event = pool_parser(&account, EventMetadata::default()).unwrap();
+        let pool = &event.as_any().downcast_ref::<MeteoraDammV2PoolAccountEvent>().unwrap().pool;
+        assert_eq!(pool.pool_fees.base_fee.cliff_fee_numerator, 2_500_000);
+        assert_eq!(pool.pool_fees.protocol_fee_percent, 20);
+        assert_eq!((pool.token_a_mint, pool.token_b_mint), (mint_a, mint_b));
+        assert_eq!((pool.token_a_vault, pool.token_b_vault), (vault_a, vault_b));
+        assert_eq!(pool.liquidity, 123_456_789);
+        assert_eq!(pool.sqrt_price, 1u128 << 64);
+        assert_eq!(pool.activation_point, 1_760_000_000);
+        assert_eq!((pool.pool_status, pool.collect_fee_mode), (1, 2));
+        assert_eq!(pool.creator, creator);
+        assert_eq!(pool.reward_infos[1].mint, reward_mint);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::meteora_dbc::types::{PoolConfig, VirtualPool};
+use crate::impl_unified_event;
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 创建池子（SPL Token / Token-2022）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDbcInitializePoolEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // EvtInitializePool 日志
+    pub pool: Pubkey,
+    pub config: Pubkey,
+    pub creator: Pubkey,
+    pub base_mint: Pubkey,
+    pub pool_type: u8,
+    pub activation_point: u64,
+    #[borsh(skip)]
+    pub name: String,
+    #[borsh(skip)]
+    pub symbol: String,
+    #[borsh(skip)]
+    pub uri: String,
+    #[borsh(skip)]
+    pub pool_authority: Pubkey,
+    #[borsh(skip)]
+    pub base_vault: Pubkey,
+    #[borsh(skip)]
+    pub quote_vault: Pubkey,
+    #[borsh(skip)]
+    pub quote_mint: Pubkey,
+    #[borsh(skip)]
+    pub mint_metadata: Pubkey,
+    #[borsh(skip)]
+    pub payer: Pubkey,
+    #[borsh(skip)]
+    pub token_quote_program: Pubkey,
+    #[borsh(skip)]
+    pub token_program: Pubkey,
+    #[borsh(skip)]
+    pub is_token2022: bool,
+}
+
+pub const METEORA_DBC_INITIALIZE_POOL_EVENT_LOG_SIZE: usize = 137;
+
+pub fn meteora_dbc_initialize_pool_event_log_decode(
+    data: &[u8],
+) -> Option<MeteoraDbcInitializePoolEvent> {
+    if data.len() < METEORA_DBC_INITIALIZE_POOL_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDbcInitializePoolEvent>(
+        &data[..METEORA_DBC_INITIALIZE_POOL_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    MeteoraDbcInitializePoolEvent,
+    pool,
+    config,
+    creator,
+    base_mint,
+    pool_type,
+    activation_point
+);
+
+/// 交易
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDbcSwapEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // EvtSwap 日志
+    pub pool: Pubkey,
+    pub config: Pubkey,
+    /// 0: base -> quote（卖出）, 1: quote -> base（买入）
+    pub trade_direction: u8,
+    pub has_referral: bool,
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+    pub actual_input_amount: u64,
+    pub output_amount: u64,
+    pub next_sqrt_price: u128,
+    pub trading_fee: u64,
+    pub protocol_fee: u64,
+    pub referral_fee: u64,
+    pub total_amount_in: u64,
+    pub current_timestamp: u64,
+    #[borsh(skip)]
+    pub pool_authority: Pubkey,
+    #[borsh(skip)]
+    pub input_token_account: Pubkey,
+    #[borsh(skip)]
+    pub output_token_account: Pubkey,
+    #[borsh(skip)]
+    pub base_vault: Pubkey,
+    #[borsh(skip)]
+    pub quote_vault: Pubkey,
+    #[borsh(skip)]
+    pub base_mint: Pubkey,
+    #[borsh(skip)]
+    pub quote_mint: Pubkey,
+    #[borsh(skip)]
+    pub payer: Pubkey,
+    #[borsh(skip)]
+    pub token_base_program: Pubkey,
+    #[borsh(skip)]
+    pub token_quote_program: Pubkey,
+    #[borsh(skip)]
+    pub referral_token_account: Pubkey,
+    #[borsh(skip)]
+    pub is_dev_create_token_trade: bool,
+    #[borsh(skip)]
+    pub is_bot: bool,
+}
+
+pub const METEORA_DBC_SWAP_EVENT_LOG_SIZE: usize = 154;
+
+pub fn meteora_dbc_swap_event_log_decode(data: &[u8]) -> Option<MeteoraDbcSwapEvent> {
+    if data.len() < METEORA_DBC_SWAP_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDbcSwapEvent>(&data[..METEORA_DBC_SWAP_EVENT_LOG_SIZE]).ok()
+}
+
+impl_unified_event!(
+    MeteoraDbcSwapEvent,
+    pool,
+    config,
+    trade_direction,
+    has_referral,
+    amount_in,
+    minimum_amount_out,
+    actual_input_amount,
+    output_amount,
+    next_sqrt_price,
+    trading_fee,
+    protocol_fee,
+    referral_fee,
+    total_amount_in,
+    current_timestamp
+);
+
+/// 迁移至 DAMM v1
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDbcMigrateDammV1Event {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub virtual_pool: Pubkey,
+    pub migration_metadata: Pubkey,
+    pub config: Pubkey,
+    pub pool_authority: Pubkey,
+    pub damm_pool: Pubkey,
+    pub damm_config: Pubkey,
+    pub lp_mint: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub payer: Pubkey,
+}
+
+impl_unified_event!(MeteoraDbcMigrateDammV1Event,);
+
+/// 迁移至 DAMM v2
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDbcMigrateDammV2Event {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub virtual_pool: Pubkey,
+    pub migration_metadata: Pubkey,
+    pub config: Pubkey,
+    pub pool_authority: Pubkey,
+    pub damm_pool: Pubkey,
+    pub first_position: Pubkey,
+    pub second_position: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub payer: Pubkey,
+}
+
+impl_unified_event!(MeteoraDbcMigrateDammV2Event,);
+
+/// 虚拟池
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDbcVirtualPoolAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub virtual_pool: VirtualPool,
+}
+impl_unified_event!(MeteoraDbcVirtualPoolAccountEvent,);
+
+/// 池子配置
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDbcPoolConfigAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub pool_config: PoolConfig,
+}
+impl_unified_event!(MeteoraDbcPoolConfigAccountEvent,);
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 事件鉴别器
+    pub const INITIALIZE_POOL_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 228, 50, 246, 85, 203, 66, 134, 37];
+    pub const SWAP_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 27, 60, 21, 213, 138, 170, 187, 147];
+
+    // 指令鉴别器
+    pub const INITIALIZE_VIRTUAL_POOL_WITH_SPL_TOKEN: &[u8] =
+        &[140, 85, 215, 176, 102, 54, 104, 79];
+    pub const INITIALIZE_VIRTUAL_POOL_WITH_TOKEN2022: &[u8] =
+        &[169, 118, 51, 78, 145, 110, 220, 155];
+    pub const SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const MIGRATE_METEORA_DAMM: &[u8] = &[27, 1, 48, 22, 180, 63, 118, 217];
+    pub const MIGRATION_DAMM_V2: &[u8] = &[156, 169, 230, 103, 53, 228, 80, 64];
+
+    // 账号鉴别器
+    pub const VIRTUAL_POOL: &[u8] = &[213, 224, 5, 209, 98, 69, 119, 92];
+    pub const POOL_CONFIG: &[u8] = &[26, 108, 14, 123, 116, 230, 129, 43];
+}
+
+use crate::streaming::event_parser::protocols::raydium_cpmm::types::{
+    CopyTradeableEvent, TradeDirection, TradeInfo,
+};
+
+// WSOL mint address for trade direction detection
+const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+
+impl MeteoraDbcSwapEvent {
+    /// Extract trade information with direction detection
+    pub fn get_trade_info(&self) -> Option<TradeInfo> {
+        let (input_mint, output_mint) = if self.trade_direction == 1 {
+            (self.quote_mint, self.base_mint)
+        } else {
+            (self.base_mint, self.quote_mint)
+        };
+        let amount_in =
+            if self.actual_input_amount > 0 { self.actual_input_amount } else { self.amount_in };
+
+        // Only process if one of the tokens is WSOL
+        let (direction, token_mint, sol_amount) = if input_mint == WSOL_MINT {
+            (TradeDirection::Buy, output_mint, amount_in)
+        } else if output_mint == WSOL_MINT {
+            (TradeDirection::Sell, input_mint, self.output_amount)
+        } else {
+            return None;
+        };
+
+        Some(TradeInfo {
+            direction,
+            user_address: self.payer.to_string(),
+            token_mint: token_mint.to_string(),
+            sol_amount: sol_amount as f64 / 1_000_000_000.0,
+            platform: "MeteoraDbc".to_string(),
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            amount_in,
+            amount_out: self.output_amount,
+        })
+    }
+}
+
+impl CopyTradeableEvent for MeteoraDbcSwapEvent {
+    fn get_trade_info(&self) -> Option<TradeInfo> {
+        self.get_trade_info()
+    }
+}
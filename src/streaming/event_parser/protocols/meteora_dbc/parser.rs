@@ -0,0 +1,264 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_event_parser_delegate,
+    streaming::event_parser::{
+        common::{read_borsh_string, read_u64_le, EventMetadata, EventType, ProtocolType},
+        core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
+        protocols::meteora_dbc::{
+            discriminators, meteora_dbc_initialize_pool_event_log_decode,
+            meteora_dbc_swap_event_log_decode, MeteoraDbcInitializePoolEvent,
+            MeteoraDbcMigrateDammV1Event, MeteoraDbcMigrateDammV2Event, MeteoraDbcSwapEvent,
+        },
+    },
+};
+
+/// Meteora Dynamic Bonding Curve程序ID
+pub const METEORA_DBC_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("dbcij3LWUppWqq96dh6gJWwBifmcGfLSB5D4DuSMaqN");
+
+/// Meteora DBC事件解析器
+pub struct MeteoraDbcEventParser {
+    inner: GenericEventParser,
+}
+
+impl Default for MeteoraDbcEventParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MeteoraDbcEventParser {
+    pub fn new() -> Self {
+        // 配置所有事件类型
+        let configs = vec![
+            GenericEventParseConfig {
+                program_id: METEORA_DBC_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDbc,
+                inner_instruction_discriminator: discriminators::INITIALIZE_POOL_EVENT,
+                instruction_discriminator: discriminators::INITIALIZE_VIRTUAL_POOL_WITH_SPL_TOKEN,
+                event_type: EventType::MeteoraDbcInitializePool,
+                inner_instruction_parser: Some(Self::parse_initialize_pool_inner_instruction),
+                instruction_parser: Some(Self::parse_initialize_pool_with_spl_token_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DBC_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDbc,
+                inner_instruction_discriminator: discriminators::INITIALIZE_POOL_EVENT,
+                instruction_discriminator: discriminators::INITIALIZE_VIRTUAL_POOL_WITH_TOKEN2022,
+                event_type: EventType::MeteoraDbcInitializePool,
+                inner_instruction_parser: Some(Self::parse_initialize_pool_inner_instruction),
+                instruction_parser: Some(Self::parse_initialize_pool_with_token2022_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DBC_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDbc,
+                inner_instruction_discriminator: discriminators::SWAP_EVENT,
+                instruction_discriminator: discriminators::SWAP,
+                event_type: EventType::MeteoraDbcSwap,
+                inner_instruction_parser: Some(Self::parse_swap_inner_instruction),
+                instruction_parser: Some(Self::parse_swap_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DBC_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDbc,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::MIGRATE_METEORA_DAMM,
+                event_type: EventType::MeteoraDbcMigrateDammV1,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_migrate_damm_v1_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DBC_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDbc,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::MIGRATION_DAMM_V2,
+                event_type: EventType::MeteoraDbcMigrateDammV2,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_migrate_damm_v2_instruction),
+            },
+        ];
+
+        let inner = GenericEventParser::new(vec![METEORA_DBC_PROGRAM_ID], configs);
+
+        Self { inner }
+    }
+
+    /// 解析创建池子日志事件
+    fn parse_initialize_pool_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_dbc_initialize_pool_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析交易日志事件
+    fn parse_swap_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_dbc_swap_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析创建池子的代币元数据参数
+    fn parse_token_metadata(data: &[u8]) -> Option<(String, String, String)> {
+        let mut offset = 0;
+        let name = read_borsh_string(data, &mut offset)?;
+        let symbol = read_borsh_string(data, &mut offset)?;
+        let uri = read_borsh_string(data, &mut offset)?;
+        Some((name, symbol, uri))
+    }
+
+    /// 解析 SPL Token 创建池子指令事件
+    fn parse_initialize_pool_with_spl_token_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 13 {
+            return None;
+        }
+        let (name, symbol, uri) = Self::parse_token_metadata(data)?;
+        Some(Box::new(MeteoraDbcInitializePoolEvent {
+            metadata,
+            pool: accounts[4],
+            config: accounts[0],
+            creator: accounts[2],
+            base_mint: accounts[3],
+            name,
+            symbol,
+            uri,
+            pool_authority: accounts[1],
+            base_vault: accounts[5],
+            quote_vault: accounts[6],
+            quote_mint: accounts[7],
+            mint_metadata: accounts[8],
+            payer: accounts[10],
+            token_quote_program: accounts[11],
+            token_program: accounts[12],
+            is_token2022: false,
+            ..Default::default()
+        }))
+    }
+
+    /// 解析 Token-2022 创建池子指令事件
+    fn parse_initialize_pool_with_token2022_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 11 {
+            return None;
+        }
+        let (name, symbol, uri) = Self::parse_token_metadata(data)?;
+        Some(Box::new(MeteoraDbcInitializePoolEvent {
+            metadata,
+            pool: accounts[4],
+            config: accounts[0],
+            creator: accounts[2],
+            base_mint: accounts[3],
+            name,
+            symbol,
+            uri,
+            pool_authority: accounts[1],
+            base_vault: accounts[5],
+            quote_vault: accounts[6],
+            quote_mint: accounts[7],
+            payer: accounts[8],
+            token_quote_program: accounts[9],
+            token_program: accounts[10],
+            is_token2022: true,
+            ..Default::default()
+        }))
+    }
+
+    /// 解析交易指令事件
+    fn parse_swap_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 16 || accounts.len() < 12 {
+            return None;
+        }
+        Some(Box::new(MeteoraDbcSwapEvent {
+            metadata,
+            pool: accounts[2],
+            config: accounts[1],
+            amount_in: read_u64_le(data, 0)?,
+            minimum_amount_out: read_u64_le(data, 8)?,
+            pool_authority: accounts[0],
+            input_token_account: accounts[3],
+            output_token_account: accounts[4],
+            base_vault: accounts[5],
+            quote_vault: accounts[6],
+            base_mint: accounts[7],
+            quote_mint: accounts[8],
+            payer: accounts[9],
+            token_base_program: accounts[10],
+            token_quote_program: accounts[11],
+            referral_token_account: accounts.get(12).copied().unwrap_or_default(),
+            ..Default::default()
+        }))
+    }
+
+    /// 解析迁移至 DAMM v1 指令事件
+    fn parse_migrate_damm_v1_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 23 {
+            return None;
+        }
+        Some(Box::new(MeteoraDbcMigrateDammV1Event {
+            metadata,
+            virtual_pool: accounts[0],
+            migration_metadata: accounts[1],
+            config: accounts[2],
+            pool_authority: accounts[3],
+            damm_pool: accounts[4],
+            damm_config: accounts[5],
+            lp_mint: accounts[6],
+            base_mint: accounts[7],
+            quote_mint: accounts[8],
+            base_vault: accounts[17],
+            quote_vault: accounts[18],
+            payer: accounts[22],
+        }))
+    }
+
+    /// 解析迁移至 DAMM v2 指令事件
+    fn parse_migrate_damm_v2_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 20 {
+            return None;
+        }
+        Some(Box::new(MeteoraDbcMigrateDammV2Event {
+            metadata,
+            virtual_pool: accounts[0],
+            migration_metadata: accounts[1],
+            config: accounts[2],
+            pool_authority: accounts[3],
+            damm_pool: accounts[4],
+            first_position: accounts[7],
+            second_position: accounts[10],
+            base_mint: accounts[13],
+            quote_mint: accounts[14],
+            token_a_vault: accounts[15],
+            token_b_vault: accounts[16],
+            base_vault: accounts[17],
+            quote_vault: accounts[18],
+            payer: accounts[19],
+        }))
+    }
+}
+
+impl_event_parser_delegate!(MeteoraDbcEventParser);
@@ -0,0 +1,261 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::EventMetadata,
+        protocols::meteora_dbc::{
+            MeteoraDbcPoolConfigAccountEvent, MeteoraDbcVirtualPoolAccountEvent,
+        },
+        UnifiedEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VolatilityTracker {
+    pub last_update_timestamp: u64,
+    pub padding: [u8; 8],
+    pub sqrt_price_reference: u128,
+    pub volatility_accumulator: u128,
+    pub volatility_reference: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolMetrics {
+    pub total_protocol_base_fee: u64,
+    pub total_protocol_quote_fee: u64,
+    pub total_trading_base_fee: u64,
+    pub total_trading_quote_fee: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VirtualPool {
+    pub volatility_tracker: VolatilityTracker,
+    pub config: Pubkey,
+    pub creator: Pubkey,
+    pub base_mint: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub base_reserve: u64,
+    pub quote_reserve: u64,
+    pub protocol_base_fee: u64,
+    pub protocol_quote_fee: u64,
+    pub partner_base_fee: u64,
+    pub partner_quote_fee: u64,
+    pub sqrt_price: u128,
+    pub activation_point: u64,
+    pub pool_type: u8,
+    pub is_migrated: u8,
+    pub is_partner_withdraw_surplus: u8,
+    pub is_protocol_withdraw_surplus: u8,
+    pub migration_progress: u8,
+    pub is_withdraw_leftover: u8,
+    pub is_creator_withdraw_surplus: u8,
+    pub migration_fee_withdraw_status: u8,
+    pub metrics: PoolMetrics,
+    pub finish_curve_timestamp: u64,
+    pub creator_base_fee: u64,
+    pub creator_quote_fee: u64,
+    pub padding_1: [u64; 7],
+}
+
+pub const VIRTUAL_POOL_SIZE: usize = 416;
+
+pub fn virtual_pool_decode(data: &[u8]) -> Option<VirtualPool> {
+    if data.len() < VIRTUAL_POOL_SIZE {
+        return None;
+    }
+    borsh::from_slice::<VirtualPool>(&data[..VIRTUAL_POOL_SIZE]).ok()
+}
+
+pub fn virtual_pool_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < VIRTUAL_POOL_SIZE + 8 {
+        return None;
+    }
+    let virtual_pool = virtual_pool_decode(&account.data[8..VIRTUAL_POOL_SIZE + 8])?;
+    Some(Box::new(MeteoraDbcVirtualPoolAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        virtual_pool,
+    }))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BaseFeeConfig {
+    pub cliff_fee_numerator: u64,
+    pub second_factor: u64,
+    pub third_factor: u64,
+    pub first_factor: u16,
+    pub base_fee_mode: u8,
+    pub padding_0: [u8; 5],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicFeeConfig {
+    pub initialized: u8,
+    pub padding: [u8; 7],
+    pub max_volatility_accumulator: u32,
+    pub variable_fee_control: u32,
+    pub bin_step: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub padding2: [u8; 8],
+    pub bin_step_u128: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolFeesConfig {
+    pub base_fee: BaseFeeConfig,
+    pub dynamic_fee: DynamicFeeConfig,
+    pub padding_0: [u64; 5],
+    pub padding_1: [u8; 6],
+    pub protocol_fee_percent: u8,
+    pub referral_fee_percent: u8,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LockedVestingConfig {
+    pub amount_per_period: u64,
+    pub cliff_duration_from_migration_time: u64,
+    pub frequency: u64,
+    pub number_of_period: u64,
+    pub cliff_unlock_amount: u64,
+    pub padding: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidityDistributionConfig {
+    pub sqrt_price: u128,
+    pub liquidity: u128,
+}
+
+/// 联合曲线最多分段数
+pub const MAX_CURVE_POINT: usize = 20;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolConfig {
+    pub quote_mint: Pubkey,
+    pub fee_claimer: Pubkey,
+    pub leftover_receiver: Pubkey,
+    pub pool_fees: PoolFeesConfig,
+    pub collect_fee_mode: u8,
+    pub migration_option: u8,
+    pub activation_type: u8,
+    pub token_decimal: u8,
+    pub version: u8,
+    pub token_type: u8,
+    pub quote_token_flag: u8,
+    pub partner_locked_lp_percentage: u8,
+    pub partner_lp_percentage: u8,
+    pub creator_locked_lp_percentage: u8,
+    pub creator_lp_percentage: u8,
+    pub migration_fee_option: u8,
+    pub fixed_token_supply_flag: u8,
+    pub creator_trading_fee_percentage: u8,
+    pub token_update_authority: u8,
+    pub migration_fee_percentage: u8,
+    pub creator_migration_fee_percentage: u8,
+    pub padding_1: [u8; 7],
+    pub swap_base_amount: u64,
+    pub migration_quote_threshold: u64,
+    pub migration_base_threshold: u64,
+    pub migration_sqrt_price: u128,
+    pub locked_vesting_config: LockedVestingConfig,
+    pub pre_migration_token_supply: u64,
+    pub post_migration_token_supply: u64,
+    pub padding_2: [u128; 2],
+    pub sqrt_start_price: u128,
+    pub curve: [LiquidityDistributionConfig; MAX_CURVE_POINT],
+}
+
+pub const POOL_CONFIG_SIZE: usize = 1040;
+
+pub fn pool_config_decode(data: &[u8]) -> Option<PoolConfig> {
+    if data.len() < POOL_CONFIG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PoolConfig>(&data[..POOL_CONFIG_SIZE]).ok()
+}
+
+pub fn pool_config_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < POOL_CONFIG_SIZE + 8 {
+        return None;
+    }
+    let pool_config = pool_config_decode(&account.data[8..POOL_CONFIG_SIZE + 8])?;
+    Some(Box::new(MeteoraDbcPoolConfigAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        pool_config,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按账户布局偏移写入字段，偏移包含 8 字节鉴别器
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[8 + offset..8 + offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[test]
+    fn virtual_pool_layout() {
+        let (config, creator, base_mint, base_vault, quote_vault) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut data = vec![0u8; VIRTUAL_POOL_SIZE + 8];
+        put(&mut data, 0, &1_750_000_000u64.to_le_bytes());
+        put(&mut data, 64, config.as_ref());
+        put(&mut data, 96, creator.as_ref());
+        put(&mut data, 128, base_mint.as_ref());
+        put(&mut data, 160, base_vault.as_ref());
+        put(&mut data, 192, quote_vault.as_ref());
+        put(&mut data, 224, &800_000_000_000_000u64.to_le_bytes());
+        put(&mut data, 232, &42_000_000_000u64.to_le_bytes());
+        put(&mut data, 272, &(3u128 << 60).to_le_bytes());
+        put(&mut data, 297, &[1]);
+        put(&mut data, 300, &[2]);
+        put(&mut data, 304, &9_000u64.to_le_bytes());
+        put(&mut data, 352, &1_234u64.to_le_bytes());
+        let account = AccountPretty { data: data.clone(), ..Default::default() };
+
+        let event = virtual_pool_parser(&account, EventMetadata::default()).unwrap();
+        let pool = &event
+            .as_any()
+            .downcast_ref::<MeteoraDbcVirtualPoolAccountEvent>()
+            .unwrap()
+            .virtual_pool;
+        assert_eq!(pool.volatility_tracker.last_update_timestamp, 1_750_000_000);
+        assert_eq!((pool.config, pool.creator, pool.base_mint), (config, creator, base_mint));
+        assert_eq!((pool.base_vault, pool.quote_vault), (base_vault, quote_vault));
+        assert_eq!(pool.base_reserve, 800_000_000_000_000);
+        assert_eq!(pool.quote_reserve, 42_000_000_000);
+        assert_eq!(pool.sqrt_price, 3u128 << 60);
+        assert_eq!((pool.is_migrated, pool.migration_progress), (1, 2));
+        assert_eq!(pool.metrics.total_protocol_base_fee, 9_000);
+        assert_eq!(pool.creator_quote_fee, 1_234);
+
+        assert!(virtual_pool_decode(&data[8..VIRTUAL_POOL_SIZE + 7]).is_none());
+    }
+}
@@ -0,0 +1,367 @@
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::meteora_dlmm::types::{BinArray, LbPair};
+use crate::impl_unified_event;
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 交易（swap / swap2 / swap_exact_out / swap_exact_out2）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmSwapEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // Swap 日志
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub start_bin_id: i32,
+    pub end_bin_id: i32,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub swap_for_y: bool,
+    pub fee: u64,
+    pub protocol_fee: u64,
+    pub fee_bps: u128,
+    pub host_fee: u64,
+    // exact in
+    #[borsh(skip)]
+    pub min_amount_out: u64,
+    // exact out
+    #[borsh(skip)]
+    pub max_in_amount: u64,
+    #[borsh(skip)]
+    pub bin_array_bitmap_extension: Pubkey,
+    #[borsh(skip)]
+    pub reserve_x: Pubkey,
+    #[borsh(skip)]
+    pub reserve_y: Pubkey,
+    #[borsh(skip)]
+    pub user_token_in: Pubkey,
+    #[borsh(skip)]
+    pub user_token_out: Pubkey,
+    #[borsh(skip)]
+    pub token_x_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_y_mint: Pubkey,
+    #[borsh(skip)]
+    pub oracle: Pubkey,
+    #[borsh(skip)]
+    pub host_fee_in: Pubkey,
+    #[borsh(skip)]
+    pub user: Pubkey,
+    #[borsh(skip)]
+    pub token_x_program: Pubkey,
+    #[borsh(skip)]
+    pub token_y_program: Pubkey,
+    #[borsh(skip)]
+    pub remaining_accounts: Vec<Pubkey>,
+}
+
+pub const METEORA_DLMM_SWAP_EVENT_LOG_SIZE: usize = 129;
+
+pub fn meteora_dlmm_swap_event_log_decode(data: &[u8]) -> Option<MeteoraDlmmSwapEvent> {
+    if data.len() < METEORA_DLMM_SWAP_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmSwapEvent>(&data[..METEORA_DLMM_SWAP_EVENT_LOG_SIZE]).ok()
+}
+
+impl_unified_event!(
+    MeteoraDlmmSwapEvent,
+    lb_pair,
+    from,
+    start_bin_id,
+    end_bin_id,
+    amount_in,
+    amount_out,
+    swap_for_y,
+    fee,
+    protocol_fee,
+    fee_bps,
+    host_fee
+);
+
+/// 添加流动性（add_liquidity / add_liquidity_by_strategy）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmAddLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // AddLiquidity 日志
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub position: Pubkey,
+    pub amounts: [u64; 2],
+    pub active_bin_id: i32,
+    #[borsh(skip)]
+    pub amount_x: u64,
+    #[borsh(skip)]
+    pub amount_y: u64,
+    #[borsh(skip)]
+    pub bin_array_bitmap_extension: Pubkey,
+    #[borsh(skip)]
+    pub user_token_x: Pubkey,
+    #[borsh(skip)]
+    pub user_token_y: Pubkey,
+    #[borsh(skip)]
+    pub reserve_x: Pubkey,
+    #[borsh(skip)]
+    pub reserve_y: Pubkey,
+    #[borsh(skip)]
+    pub token_x_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_y_mint: Pubkey,
+    #[borsh(skip)]
+    pub bin_array_lower: Pubkey,
+    #[borsh(skip)]
+    pub bin_array_upper: Pubkey,
+    #[borsh(skip)]
+    pub sender: Pubkey,
+    #[borsh(skip)]
+    pub token_x_program: Pubkey,
+    #[borsh(skip)]
+    pub token_y_program: Pubkey,
+}
+
+pub const METEORA_DLMM_ADD_LIQUIDITY_EVENT_LOG_SIZE: usize = 116;
+
+pub fn meteora_dlmm_add_liquidity_event_log_decode(
+    data: &[u8],
+) -> Option<MeteoraDlmmAddLiquidityEvent> {
+    if data.len() < METEORA_DLMM_ADD_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmAddLiquidityEvent>(
+        &data[..METEORA_DLMM_ADD_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(MeteoraDlmmAddLiquidityEvent, lb_pair, from, position, amounts, active_bin_id);
+
+/// 移除流动性（remove_liquidity / remove_liquidity_by_range）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmRemoveLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // RemoveLiquidity 日志
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub position: Pubkey,
+    pub amounts: [u64; 2],
+    pub active_bin_id: i32,
+    #[borsh(skip)]
+    pub bin_array_bitmap_extension: Pubkey,
+    #[borsh(skip)]
+    pub user_token_x: Pubkey,
+    #[borsh(skip)]
+    pub user_token_y: Pubkey,
+    #[borsh(skip)]
+    pub reserve_x: Pubkey,
+    #[borsh(skip)]
+    pub reserve_y: Pubkey,
+    #[borsh(skip)]
+    pub token_x_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_y_mint: Pubkey,
+    #[borsh(skip)]
+    pub bin_array_lower: Pubkey,
+    #[borsh(skip)]
+    pub bin_array_upper: Pubkey,
+    #[borsh(skip)]
+    pub sender: Pubkey,
+    #[borsh(skip)]
+    pub token_x_program: Pubkey,
+    #[borsh(skip)]
+    pub token_y_program: Pubkey,
+}
+
+pub const METEORA_DLMM_REMOVE_LIQUIDITY_EVENT_LOG_SIZE: usize = 116;
+
+pub fn meteora_dlmm_remove_liquidity_event_log_decode(
+    data: &[u8],
+) -> Option<MeteoraDlmmRemoveLiquidityEvent> {
+    if data.len() < METEORA_DLMM_REMOVE_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmRemoveLiquidityEvent>(
+        &data[..METEORA_DLMM_REMOVE_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    MeteoraDlmmRemoveLiquidityEvent,
+    lb_pair,
+    from,
+    position,
+    amounts,
+    active_bin_id
+);
+
+/// 创建仓位
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmInitializePositionEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // PositionCreate 日志
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    #[borsh(skip)]
+    pub lower_bin_id: i32,
+    #[borsh(skip)]
+    pub width: i32,
+    #[borsh(skip)]
+    pub payer: Pubkey,
+}
+
+pub const METEORA_DLMM_POSITION_CREATE_EVENT_LOG_SIZE: usize = 96;
+
+pub fn meteora_dlmm_position_create_event_log_decode(
+    data: &[u8],
+) -> Option<MeteoraDlmmInitializePositionEvent> {
+    if data.len() < METEORA_DLMM_POSITION_CREATE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmInitializePositionEvent>(
+        &data[..METEORA_DLMM_POSITION_CREATE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(MeteoraDlmmInitializePositionEvent, lb_pair, position, owner);
+
+/// 关闭仓位
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmClosePositionEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    // PositionClose 日志
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    #[borsh(skip)]
+    pub lb_pair: Pubkey,
+    #[borsh(skip)]
+    pub bin_array_lower: Pubkey,
+    #[borsh(skip)]
+    pub bin_array_upper: Pubkey,
+    #[borsh(skip)]
+    pub rent_receiver: Pubkey,
+}
+
+pub const METEORA_DLMM_POSITION_CLOSE_EVENT_LOG_SIZE: usize = 64;
+
+pub fn meteora_dlmm_position_close_event_log_decode(
+    data: &[u8],
+) -> Option<MeteoraDlmmClosePositionEvent> {
+    if data.len() < METEORA_DLMM_POSITION_CLOSE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmClosePositionEvent>(
+        &data[..METEORA_DLMM_POSITION_CLOSE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(MeteoraDlmmClosePositionEvent, position, owner);
+
+/// 交易对
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDlmmLbPairAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub lb_pair: LbPair,
+}
+impl_unified_event!(MeteoraDlmmLbPairAccountEvent,);
+
+/// Bin数组
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDlmmBinArrayAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub bin_array: BinArray,
+}
+impl_unified_event!(MeteoraDlmmBinArrayAccountEvent,);
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 事件鉴别器
+    pub const SWAP_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 81, 108, 227, 190, 205, 208, 10, 196];
+    pub const ADD_LIQUIDITY_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 31, 94, 125, 90, 227, 52, 61, 186];
+    pub const REMOVE_LIQUIDITY_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 116, 244, 97, 232, 103, 31, 152, 58];
+    pub const POSITION_CREATE_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 144, 142, 252, 84, 157, 53, 37, 121];
+    pub const POSITION_CLOSE_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 255, 196, 16, 107, 28, 202, 53, 128];
+
+    // 指令鉴别器
+    pub const SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const SWAP2: &[u8] = &[65, 75, 63, 76, 235, 91, 91, 136];
+    pub const SWAP_EXACT_OUT: &[u8] = &[250, 73, 101, 33, 38, 207, 75, 184];
+    pub const SWAP_EXACT_OUT2: &[u8] = &[43, 215, 247, 132, 137, 60, 243, 81];
+    pub const ADD_LIQUIDITY: &[u8] = &[181, 157, 89, 67, 143, 182, 52, 72];
+    pub const ADD_LIQUIDITY_BY_STRATEGY: &[u8] = &[7, 3, 150, 127, 148, 40, 61, 200];
+    pub const REMOVE_LIQUIDITY: &[u8] = &[80, 85, 209, 72, 24, 206, 177, 108];
+    pub const REMOVE_LIQUIDITY_BY_RANGE: &[u8] = &[26, 82, 102, 152, 240, 74, 105, 26];
+    pub const INITIALIZE_POSITION: &[u8] = &[219, 192, 234, 71, 190, 191, 102, 80];
+    pub const CLOSE_POSITION: &[u8] = &[123, 134, 81, 0, 49, 68, 98, 98];
+
+    // 账号鉴别器
+    pub const LB_PAIR: &[u8] = &[33, 11, 49, 98, 181, 101, 177, 13];
+    pub const BIN_ARRAY: &[u8] = &[92, 142, 92, 220, 5, 148, 70, 181];
+}
+
+use crate::streaming::event_parser::protocols::raydium_cpmm::types::{
+    CopyTradeableEvent, TradeDirection, TradeInfo,
+};
+
+// WSOL mint address for trade direction detection
+const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+
+impl MeteoraDlmmSwapEvent {
+    /// Extract trade information with direction detection
+    pub fn get_trade_info(&self) -> Option<TradeInfo> {
+        let (input_mint, output_mint) = if self.swap_for_y {
+            (self.token_x_mint, self.token_y_mint)
+        } else {
+            (self.token_y_mint, self.token_x_mint)
+        };
+
+        // Only process if one of the tokens is WSOL
+        let (direction, token_mint, sol_amount) = if input_mint == WSOL_MINT {
+            (TradeDirection::Buy, output_mint, self.amount_in)
+        } else if output_mint == WSOL_MINT {
+            (TradeDirection::Sell, input_mint, self.amount_out)
+        } else {
+            return None;
+        };
+
+        Some(TradeInfo {
+            direction,
+            user_address: self.user.to_string(),
+            token_mint: token_mint.to_string(),
+            sol_amount: sol_amount as f64 / 1_000_000_000.0,
+            platform: "MeteoraDlmm".to_string(),
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            amount_in: self.amount_in,
+            amount_out: self.amount_out,
+        })
+    }
+}
+
+impl CopyTradeableEvent for MeteoraDlmmSwapEvent {
+    fn get_trade_info(&self) -> Option<TradeInfo> {
+        self.get_trade_info()
+    }
+}
@@ -0,0 +1,6 @@
+pub mod events;
+pub mod parser;
+pub mod types;
+
+pub use events::*;
+pub use parser::MeteoraDlmmEventParser;
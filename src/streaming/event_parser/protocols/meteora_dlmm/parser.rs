@@ -0,0 +1,347 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_event_parser_delegate,
+    streaming::event_parser::{
+        common::{read_i32_le, read_u64_le, EventMetadata, EventType, ProtocolType},
+        core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
+        protocols::meteora_dlmm::{
+            discriminators, meteora_dlmm_add_liquidity_event_log_decode,
+            meteora_dlmm_position_close_event_log_decode,
+            meteora_dlmm_position_create_event_log_decode,
+            meteora_dlmm_remove_liquidity_event_log_decode, meteora_dlmm_swap_event_log_decode,
+            MeteoraDlmmAddLiquidityEvent, MeteoraDlmmClosePositionEvent,
+            MeteoraDlmmInitializePositionEvent, MeteoraDlmmRemoveLiquidityEvent,
+            MeteoraDlmmSwapEvent,
+        },
+    },
+};
+
+/// Meteora DLMM程序ID
+pub const METEORA_DLMM_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+
+/// Meteora DLMM事件解析器
+pub struct MeteoraDlmmEventParser {
+    inner: GenericEventParser,
+}
+
+impl Default for MeteoraDlmmEventParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MeteoraDlmmEventParser {
+    pub fn new() -> Self {
+        // 配置所有事件类型
+        let configs = vec![
+            GenericEventParseConfig {
+                program_id: METEORA_DLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDlmm,
+                inner_instruction_discriminator: discriminators::SWAP_EVENT,
+                instruction_discriminator: discriminators::SWAP,
+                event_type: EventType::MeteoraDlmmSwap,
+                inner_instruction_parser: Some(Self::parse_swap_inner_instruction),
+                instruction_parser: Some(Self::parse_swap_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDlmm,
+                inner_instruction_discriminator: discriminators::SWAP_EVENT,
+                instruction_discriminator: discriminators::SWAP2,
+                event_type: EventType::MeteoraDlmmSwap,
+                inner_instruction_parser: Some(Self::parse_swap_inner_instruction),
+                instruction_parser: Some(Self::parse_swap_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDlmm,
+                inner_instruction_discriminator: discriminators::SWAP_EVENT,
+                instruction_discriminator: discriminators::SWAP_EXACT_OUT,
+                event_type: EventType::MeteoraDlmmSwapExactOut,
+                inner_instruction_parser: Some(Self::parse_swap_inner_instruction),
+                instruction_parser: Some(Self::parse_swap_exact_out_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDlmm,
+                inner_instruction_discriminator: discriminators::SWAP_EVENT,
+                instruction_discriminator: discriminators::SWAP_EXACT_OUT2,
+                event_type: EventType::MeteoraDlmmSwapExactOut,
+                inner_instruction_parser: Some(Self::parse_swap_inner_instruction),
+                instruction_parser: Some(Self::parse_swap_exact_out_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDlmm,
+                inner_instruction_discriminator: discriminators::ADD_LIQUIDITY_EVENT,
+                instruction_discriminator: discriminators::ADD_LIQUIDITY,
+                event_type: EventType::MeteoraDlmmAddLiquidity,
+                inner_instruction_parser: Some(Self::parse_add_liquidity_inner_instruction),
+                instruction_parser: Some(Self::parse_add_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDlmm,
+                inner_instruction_discriminator: discriminators::ADD_LIQUIDITY_EVENT,
+                instruction_discriminator: discriminators::ADD_LIQUIDITY_BY_STRATEGY,
+                event_type: EventType::MeteoraDlmmAddLiquidity,
+                inner_instruction_parser: Some(Self::parse_add_liquidity_inner_instruction),
+                instruction_parser: Some(Self::parse_add_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDlmm,
+                inner_instruction_discriminator: discriminators::REMOVE_LIQUIDITY_EVENT,
+                instruction_discriminator: discriminators::REMOVE_LIQUIDITY,
+                event_type: EventType::MeteoraDlmmRemoveLiquidity,
+                inner_instruction_parser: Some(Self::parse_remove_liquidity_inner_instruction),
+                instruction_parser: Some(Self::parse_remove_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDlmm,
+                inner_instruction_discriminator: discriminators::REMOVE_LIQUIDITY_EVENT,
+                instruction_discriminator: discriminators::REMOVE_LIQUIDITY_BY_RANGE,
+                event_type: EventType::MeteoraDlmmRemoveLiquidity,
+                inner_instruction_parser: Some(Self::parse_remove_liquidity_inner_instruction),
+                instruction_parser: Some(Self::parse_remove_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDlmm,
+                inner_instruction_discriminator: discriminators::POSITION_CREATE_EVENT,
+                instruction_discriminator: discriminators::INITIALIZE_POSITION,
+                event_type: EventType::MeteoraDlmmInitializePosition,
+                inner_instruction_parser: Some(Self::parse_position_create_inner_instruction),
+                instruction_parser: Some(Self::parse_initialize_position_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: METEORA_DLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::MeteoraDlmm,
+                inner_instruction_discriminator: discriminators::POSITION_CLOSE_EVENT,
+                instruction_discriminator: discriminators::CLOSE_POSITION,
+                event_type: EventType::MeteoraDlmmClosePosition,
+                inner_instruction_parser: Some(Self::parse_position_close_inner_instruction),
+                instruction_parser: Some(Self::parse_close_position_instruction),
+            },
+        ];
+
+        let inner = GenericEventParser::new(vec![METEORA_DLMM_PROGRAM_ID], configs);
+
+        Self { inner }
+    }
+
+    /// 解析交易日志事件
+    fn parse_swap_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_dlmm_swap_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析添加流动性日志事件
+    fn parse_add_liquidity_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_dlmm_add_liquidity_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析移除流动性日志事件
+    fn parse_remove_liquidity_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_dlmm_remove_liquidity_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析创建仓位日志事件
+    fn parse_position_create_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_dlmm_position_create_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 解析关闭仓位日志事件
+    fn parse_position_close_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut event = meteora_dlmm_position_close_event_log_decode(data)?;
+        event.metadata = metadata;
+        Some(Box::new(event))
+    }
+
+    /// 构建交易事件的账户部分，swap2 额外的 memo_program 位于第13个账户之后
+    fn swap_event_from_accounts(
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> MeteoraDlmmSwapEvent {
+        MeteoraDlmmSwapEvent {
+            metadata,
+            lb_pair: accounts[0],
+            from: accounts[10],
+            bin_array_bitmap_extension: accounts[1],
+            reserve_x: accounts[2],
+            reserve_y: accounts[3],
+            user_token_in: accounts[4],
+            user_token_out: accounts[5],
+            token_x_mint: accounts[6],
+            token_y_mint: accounts[7],
+            oracle: accounts[8],
+            host_fee_in: accounts[9],
+            user: accounts[10],
+            token_x_program: accounts[11],
+            token_y_program: accounts[12],
+            remaining_accounts: accounts[13..].to_vec(),
+            ..Default::default()
+        }
+    }
+
+    /// 解析交易指令事件
+    fn parse_swap_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 16 || accounts.len() < 13 {
+            return None;
+        }
+        Some(Box::new(MeteoraDlmmSwapEvent {
+            amount_in: read_u64_le(data, 0)?,
+            min_amount_out: read_u64_le(data, 8)?,
+            ..Self::swap_event_from_accounts(accounts, metadata)
+        }))
+    }
+
+    /// 解析精确输出交易指令事件
+    fn parse_swap_exact_out_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 16 || accounts.len() < 13 {
+            return None;
+        }
+        Some(Box::new(MeteoraDlmmSwapEvent {
+            max_in_amount: read_u64_le(data, 0)?,
+            amount_out: read_u64_le(data, 8)?,
+            ..Self::swap_event_from_accounts(accounts, metadata)
+        }))
+    }
+
+    /// 解析添加流动性指令事件
+    fn parse_add_liquidity_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 16 || accounts.len() < 14 {
+            return None;
+        }
+        Some(Box::new(MeteoraDlmmAddLiquidityEvent {
+            metadata,
+            lb_pair: accounts[1],
+            from: accounts[11],
+            position: accounts[0],
+            amount_x: read_u64_le(data, 0)?,
+            amount_y: read_u64_le(data, 8)?,
+            bin_array_bitmap_extension: accounts[2],
+            user_token_x: accounts[3],
+            user_token_y: accounts[4],
+            reserve_x: accounts[5],
+            reserve_y: accounts[6],
+            token_x_mint: accounts[7],
+            token_y_mint: accounts[8],
+            bin_array_lower: accounts[9],
+            bin_array_upper: accounts[10],
+            sender: accounts[11],
+            token_x_program: accounts[12],
+            token_y_program: accounts[13],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析移除流动性指令事件
+    fn parse_remove_liquidity_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 14 {
+            return None;
+        }
+        Some(Box::new(MeteoraDlmmRemoveLiquidityEvent {
+            metadata,
+            lb_pair: accounts[1],
+            from: accounts[11],
+            position: accounts[0],
+            bin_array_bitmap_extension: accounts[2],
+            user_token_x: accounts[3],
+            user_token_y: accounts[4],
+            reserve_x: accounts[5],
+            reserve_y: accounts[6],
+            token_x_mint: accounts[7],
+            token_y_mint: accounts[8],
+            bin_array_lower: accounts[9],
+            bin_array_upper: accounts[10],
+            sender: accounts[11],
+            token_x_program: accounts[12],
+            token_y_program: accounts[13],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析创建仓位指令事件
+    fn parse_initialize_position_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 8 || accounts.len() < 4 {
+            return None;
+        }
+        Some(Box::new(MeteoraDlmmInitializePositionEvent {
+            metadata,
+            lb_pair: accounts[2],
+            position: accounts[1],
+            owner: accounts[3],
+            lower_bin_id: read_i32_le(data, 0)?,
+            width: read_i32_le(data, 4)?,
+            payer: accounts[0],
+        }))
+    }
+
+    /// 解析关闭仓位指令事件
+    fn parse_close_position_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 6 {
+            return None;
+        }
+        Some(Box::new(MeteoraDlmmClosePositionEvent {
+            metadata,
+            position: accounts[0],
+            owner: accounts[4],
+            lb_pair: accounts[1],
+            bin_array_lower: accounts[2],
+            bin_array_upper: accounts[3],
+            rent_receiver: accounts[5],
+        }))
+    }
+}
+
+impl_event_parser_delegate!(MeteoraDlmmEventParser);
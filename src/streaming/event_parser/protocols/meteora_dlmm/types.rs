@@ -0,0 +1,270 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::EventMetadata,
+        protocols::meteora_dlmm::{MeteoraDlmmBinArrayAccountEvent, MeteoraDlmmLbPairAccountEvent},
+        UnifiedEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct StaticParameters {
+    pub base_factor: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub variable_fee_control: u32,
+    pub max_volatility_accumulator: u32,
+    pub min_bin_id: i32,
+    pub max_bin_id: i32,
+    pub protocol_share: u16,
+    pub base_fee_power_factor: u8,
+    pub padding: [u8; 5],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VariableParameters {
+    pub volatility_accumulator: u32,
+    pub volatility_reference: u32,
+    pub index_reference: i32,
+    pub padding: [u8; 4],
+    pub last_update_timestamp: i64,
+    pub padding1: [u8; 8],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ProtocolFee {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub funder: Pubkey,
+    pub reward_duration: u64,
+    pub reward_duration_end: u64,
+    pub reward_rate: u128,
+    pub last_update_time: u64,
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LbPair {
+    pub parameters: StaticParameters,
+    pub v_parameters: VariableParameters,
+    pub bump_seed: [u8; 1],
+    pub bin_step_seed: [u8; 2],
+    pub pair_type: u8,
+    pub active_id: i32,
+    pub bin_step: u16,
+    pub status: u8,
+    pub require_base_factor_seed: u8,
+    pub base_factor_seed: [u8; 2],
+    pub activation_type: u8,
+    pub creator_pool_on_off_control: u8,
+    pub token_x_mint: Pubkey,
+    pub token_y_mint: Pubkey,
+    pub reserve_x: Pubkey,
+    pub reserve_y: Pubkey,
+    pub protocol_fee: ProtocolFee,
+    pub padding1: [u8; 32],
+    pub reward_infos: [RewardInfo; 2],
+    pub oracle: Pubkey,
+    pub bin_array_bitmap: [u64; 16],
+    pub last_updated_at: i64,
+    pub padding2: [u8; 32],
+    pub pre_activation_swap_address: Pubkey,
+    pub base_key: Pubkey,
+    pub activation_point: u64,
+    pub pre_activation_duration: u64,
+    pub padding3: [u8; 8],
+    pub padding4: u64,
+    pub creator: Pubkey,
+    pub token_mint_x_program_flag: u8,
+    pub token_mint_y_program_flag: u8,
+    pub reserved: [u8; 22],
+}
+
+pub const LB_PAIR_SIZE: usize = 896;
+
+pub fn lb_pair_decode(data: &[u8]) -> Option<LbPair> {
+    if data.len() < LB_PAIR_SIZE {
+        return None;
+    }
+    borsh::from_slice::<LbPair>(&data[..LB_PAIR_SIZE]).ok()
+}
+
+pub fn lb_pair_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < LB_PAIR_SIZE + 8 {
+        return None;
+    }
+    let lb_pair = lb_pair_decode(&account.data[8..LB_PAIR_SIZE + 8])?;
+    Some(Box::new(MeteoraDlmmLbPairAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        lb_pair,
+    }))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Bin {
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub price: u128,
+    pub liquidity_supply: u128,
+    pub reward_per_token_stored: [u128; 2],
+    pub fee_amount_x_per_token_stored: u128,
+    pub fee_amount_y_per_token_stored: u128,
+    pub amount_x_in: u128,
+    pub amount_y_in: u128,
+}
+
+/// 每个BinArray包含的bin数量
+pub const MAX_BIN_PER_ARRAY: usize = 70;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BinArray {
+    pub index: i64,
+    pub version: u8,
+    pub padding: [u8; 7],
+    pub lb_pair: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub bins: [Bin; MAX_BIN_PER_ARRAY],
+}
+
+impl Default for BinArray {
+    fn default() -> Self {
+        Self {
+            index: 0,
+            version: 0,
+            padding: [0u8; 7],
+            lb_pair: Pubkey::default(),
+            bins: core::array::from_fn(|_| Bin::default()),
+        }
+    }
+}
+
+pub const BIN_ARRAY_SIZE: usize = 10128;
+
+pub fn bin_array_decode(data: &[u8]) -> Option<BinArray> {
+    if data.len() < BIN_ARRAY_SIZE {
+        return None;
+    }
+    borsh::from_slice::<BinArray>(&data[..BIN_ARRAY_SIZE]).ok()
+}
+
+pub fn bin_array_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < BIN_ARRAY_SIZE + 8 {
+        return None;
+    }
+    let bin_array = bin_array_decode(&account.data[8..BIN_ARRAY_SIZE + 8])?;
+    Some(Box::new(MeteoraDlmmBinArrayAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        bin_array,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按账户布局偏移写入字段，偏移包含 8 字节鉴别器
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[8 + offset..8 + offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    fn account(data: Vec<u8>) -> AccountPretty {
+        AccountPretty { pubkey: Pubkey::new_unique(), data, ..Default::default() }
+    }
+
+    #[test]
+    fn lb_pair_layout() {
+        let (mint_x, mint_y, reserve_x, reserve_y, creator) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut data = vec![0u8; LB_PAIR_SIZE + 8];
+        put(&mut data, 0, &10_000u16.to_le_bytes());
+        put(&mut data, 68, &(-1_234i32).to_le_bytes());
+        put(&mut data, 72, &25u16.to_le_bytes());
+        put(&mut data, 80, mint_x.as_ref());
+        put(&mut data, 112, mint_y.as_ref());
+        put(&mut data, 144, reserve_x.as_ref());
+        put(&mut data, 176, reserve_y.as_ref());
+        put(&mut data, 208, &7_000u64.to_le_bytes());
+        put(&mut data, 808, &1_700_000_000u64.to_le_bytes());
+        put(&mut data, 840, creator.as_ref());
+        put(&mut data, 873, &[1]);
+
+        let event = lb_pair_parser(&account(data.clone()), EventMetadata::default()).unwrap();
+        let event = event.as_any().downcast_ref::<MeteoraDlmmLbPairAccountEvent>().unwrap();
+        let lb_pair = &event.lb_pair;
+        assert_eq!(lb_pair.parameters.base_factor, 10_000);
+        assert_eq!(lb_pair.active_id, -1_234);
+        assert_eq!(lb_pair.bin_step, 25);
+        assert_eq!(lb_pair.token_x_mint, mint_x);
+        assert_eq!(lb_pair.token_y_mint, mint_y);
+        assert_eq!(lb_pair.reserve_x, reserve_x);
+        assert_eq!(lb_pair.reserve_y, reserve_y);
+        assert_eq!(lb_pair.protocol_fee.amount_x, 7_000);
+        assert_eq!(lb_pair.activation_point, 1_700_000_000);
+        assert_eq!(lb_pair.creator, creator);
+        assert_eq!((lb_pair.token_mint_x_program_flag, lb_pair.token_mint_y_program_flag), (0, 1));
+
+        assert!(lb_pair_parser(
+            &account(data[..LB_PAIR_SIZE + 7].to_vec()),
+            EventMetadata::default()
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn bin_array_layout() {
+        let lb_pair = Pubkey::new_unique();
+        let mut data = vec![0u8; BIN_ARRAY_SIZE + 8];
+        put(&mut data, 0, &(-3i64).to_le_bytes());
+        put(&mut data, 8, &[1]);
+        put(&mut data, 16, lb_pair.as_ref());
+        // 每个 bin 144 字节，从偏移 48 开始
+        put(&mut data, 48, &11u64.to_le_bytes());
+        put(&mut data, 56, &22u64.to_le_bytes());
+        put(&mut data, 64, &(1u128 << 64).to_le_bytes());
+        put(&mut data, 48 + 69 * 144, &33u64.to_le_bytes());
+        put(&mut data, 48 + 69 * 144 + 128, &44u128.to_le_bytes());
+
+        let event = bin_array_parser(&account(data), EventMetadata::default()).unwrap();
+        let event = event.as_any().downcast_ref::<MeteoraDlmmBinArrayAccountEvent>().unwrap();
+        let bin_array = &event.bin_array;
+        assert_eq!(bin_array.index, -3);
+        assert_eq!(bin_array.version, 1);
+        assert_eq!(bin_array.lb_pair, lb_pair);
+        assert_eq!((bin_array.bins[0].amount_x, bin_array.bins[0].amount_y), (11, 22));
+        assert_eq!(bin_array.bins[0].price, 1u128 << 64);
+        assert_eq!(bin_array.bins[69].amount_x, 33);
+        assert_eq!(bin_array.bins[69].amount_y_in, 44);
+    }
+}
@@ -4,7 +4,18 @@ pub mod bonk;
 pub mod raydium_cpmm;
 pub mod raydium_clmm;
 pub mod raydium_amm_v4;
+pub mod meteora_dlmm;
+pub mod meteora_damm_v2;
+pub mod meteora_dbc;
+pub mod orca_whirlpool;
+pub mod jupiter_v6;
+pub mod openbook_v2;
+pub mod phoenix;
+pub mod moonshot;
+pub mod idl;
 pub mod block;
+pub mod raw;
+pub mod program_upgrade;
 pub mod mutil;
 
 pub use pumpfun::PumpFunEventParser;
@@ -13,5 +24,16 @@ pub use bonk::BonkEventParser;
 pub use raydium_cpmm::RaydiumCpmmEventParser;
 pub use raydium_clmm::RaydiumClmmEventParser;
 pub use raydium_amm_v4::RaydiumAmmV4EventParser;
+pub use meteora_dlmm::MeteoraDlmmEventParser;
+pub use meteora_damm_v2::MeteoraDammV2EventParser;
+pub use meteora_dbc::MeteoraDbcEventParser;
+pub use orca_whirlpool::OrcaWhirlpoolEventParser;
+pub use jupiter_v6::JupiterV6EventParser;
+pub use openbook_v2::OpenBookV2EventParser;
+pub use phoenix::PhoenixEventParser;
+pub use moonshot::MoonshotEventParser;
+pub use idl::IdlEventParser;
 pub use block::block_meta_event::BlockMetaEvent;
+pub use raw::raw_instruction_event::{RawInstructionEvent, RawInstructionReason};
+pub use program_upgrade::program_upgrade_event::{ProgramUpgradeEvent, ProgramUpgradeKind};
 pub use mutil::MutilEventParser;
\ No newline at end of file
@@ -0,0 +1,138 @@
+use crate::impl_unified_event;
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::moonshot::types::CurveAccount;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 创建代币
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoonshotTokenMintEvent {
+    pub metadata: EventMetadata,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub decimals: u8,
+    pub collateral_currency: u8,
+    pub amount: u64,
+    pub curve_type: u8,
+    pub migration_target: u8,
+    pub sender: Pubkey,
+    pub backend_authority: Pubkey,
+    pub curve_account: Pubkey,
+    pub mint: Pubkey,
+    pub mint_metadata: Pubkey,
+    pub curve_token_account: Pubkey,
+    pub config_account: Pubkey,
+    pub token_program: Pubkey,
+    pub associated_token_program: Pubkey,
+    pub mpl_token_metadata: Pubkey,
+    pub system_program: Pubkey,
+}
+impl_unified_event!(MoonshotTokenMintEvent,);
+
+/// 买入/卖出
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoonshotTradeEvent {
+    pub metadata: EventMetadata,
+    pub is_buy: bool,
+    pub token_amount: u64,
+    /// 抵押物（SOL）数量，非固定方向时为上限/下限
+    pub collateral_amount: u64,
+    /// 0: ExactIn, 1: ExactOut
+    pub fixed_side: u8,
+    pub slippage_bps: u64,
+    pub sender: Pubkey,
+    pub sender_token_account: Pubkey,
+    pub curve_account: Pubkey,
+    pub curve_token_account: Pubkey,
+    pub dex_fee: Pubkey,
+    pub helio_fee: Pubkey,
+    pub mint: Pubkey,
+    pub config_account: Pubkey,
+    pub token_program: Pubkey,
+    pub associated_token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub is_bot: bool,
+    pub is_dev_create_token_trade: bool, // 是否是dev创建token的交易
+}
+impl_unified_event!(MoonshotTradeEvent,);
+
+/// 迁移资金
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoonshotMigrateFundsEvent {
+    pub metadata: EventMetadata,
+    pub backend_authority: Pubkey,
+    pub migration_authority: Pubkey,
+    pub curve_account: Pubkey,
+    pub curve_token_account: Pubkey,
+    pub migration_authority_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub dex_fee_account: Pubkey,
+    pub helio_fee_account: Pubkey,
+    pub config_account: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub associated_token_program: Pubkey,
+}
+impl_unified_event!(MoonshotMigrateFundsEvent,);
+
+/// 联合曲线
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoonshotCurveAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub curve_account: CurveAccount,
+}
+impl_unified_event!(MoonshotCurveAccountEvent,);
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器
+    pub const TOKEN_MINT: &[u8] = &[3, 44, 164, 184, 123, 13, 245, 179];
+    pub const BUY: &[u8] = &[102, 6, 61, 18, 1, 218, 235, 234];
+    pub const SELL: &[u8] = &[51, 230, 133, 164, 1, 127, 131, 173];
+    pub const MIGRATE_FUNDS: &[u8] = &[42, 229, 10, 231, 189, 62, 193, 174];
+
+    // 账号鉴别器
+    pub const CURVE_ACCOUNT: &[u8] = &[8, 91, 83, 28, 132, 216, 248, 22];
+}
+
+use crate::streaming::event_parser::protocols::raydium_cpmm::types::{
+    CopyTradeableEvent, TradeDirection, TradeInfo,
+};
+
+// WSOL mint address for trade direction detection
+const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+
+impl MoonshotTradeEvent {
+    /// Extract trade information with direction detection
+    pub fn get_trade_info(&self) -> Option<TradeInfo> {
+        let (direction, input_mint, output_mint, amount_in, amount_out) = if self.is_buy {
+            (TradeDirection::Buy, WSOL_MINT, self.mint, self.collateral_amount, self.token_amount)
+        } else {
+            (TradeDirection::Sell, self.mint, WSOL_MINT, self.token_amount, self.collateral_amount)
+        };
+
+        Some(TradeInfo {
+            direction,
+            user_address: self.sender.to_string(),
+            token_mint: self.mint.to_string(),
+            sol_amount: self.collateral_amount as f64 / 1_000_000_000.0,
+            platform: "Moonshot".to_string(),
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            amount_in,
+            amount_out,
+        })
+    }
+}
+
+impl CopyTradeableEvent for MoonshotTradeEvent {
+    fn get_trade_info(&self) -> Option<TradeInfo> {
+        self.get_trade_info()
+    }
+}
@@ -0,0 +1,190 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_event_parser_delegate,
+    streaming::event_parser::{
+        common::{read_borsh_string, read_u64_le, read_u8, EventMetadata, EventType, ProtocolType},
+        core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
+        protocols::moonshot::{
+            discriminators, MoonshotMigrateFundsEvent, MoonshotTokenMintEvent, MoonshotTradeEvent,
+        },
+    },
+};
+
+/// Moonshot程序ID
+pub const MOONSHOT_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("MoonCVVNZFSYkqNXP6bxHLPL6QQJiMagDL3qcqUQTrG");
+
+/// Moonshot事件解析器
+pub struct MoonshotEventParser {
+    inner: GenericEventParser,
+}
+
+impl Default for MoonshotEventParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MoonshotEventParser {
+    pub fn new() -> Self {
+        // 配置所有事件类型
+        let configs = vec![
+            GenericEventParseConfig {
+                program_id: MOONSHOT_PROGRAM_ID,
+                protocol_type: ProtocolType::Moonshot,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::TOKEN_MINT,
+                event_type: EventType::MoonshotTokenMint,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_token_mint_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: MOONSHOT_PROGRAM_ID,
+                protocol_type: ProtocolType::Moonshot,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::BUY,
+                event_type: EventType::MoonshotBuy,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_buy_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: MOONSHOT_PROGRAM_ID,
+                protocol_type: ProtocolType::Moonshot,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::SELL,
+                event_type: EventType::MoonshotSell,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_sell_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: MOONSHOT_PROGRAM_ID,
+                protocol_type: ProtocolType::Moonshot,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::MIGRATE_FUNDS,
+                event_type: EventType::MoonshotMigrateFunds,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_migrate_funds_instruction),
+            },
+        ];
+
+        let inner = GenericEventParser::new(vec![MOONSHOT_PROGRAM_ID], configs);
+
+        Self { inner }
+    }
+
+    /// 解析创建代币指令事件
+    fn parse_token_mint_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 11 {
+            return None;
+        }
+        let mut offset = 0;
+        let name = read_borsh_string(data, &mut offset)?;
+        let symbol = read_borsh_string(data, &mut offset)?;
+        let uri = read_borsh_string(data, &mut offset)?;
+        Some(Box::new(MoonshotTokenMintEvent {
+            metadata,
+            name,
+            symbol,
+            uri,
+            decimals: read_u8(data, offset)?,
+            collateral_currency: read_u8(data, offset + 1)?,
+            amount: read_u64_le(data, offset + 2)?,
+            curve_type: read_u8(data, offset + 10)?,
+            migration_target: read_u8(data, offset + 11)?,
+            sender: accounts[0],
+            backend_authority: accounts[1],
+            curve_account: accounts[2],
+            mint: accounts[3],
+            mint_metadata: accounts[4],
+            curve_token_account: accounts[5],
+            config_account: accounts[6],
+            token_program: accounts[7],
+            associated_token_program: accounts[8],
+            mpl_token_metadata: accounts[9],
+            system_program: accounts[10],
+        }))
+    }
+
+    /// 解析买入指令事件
+    fn parse_buy_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        Self::parse_trade_instruction(data, accounts, metadata, true)
+    }
+
+    /// 解析卖出指令事件
+    fn parse_sell_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        Self::parse_trade_instruction(data, accounts, metadata, false)
+    }
+
+    /// 买入与卖出共用参数 TradeParams 及账户布局
+    fn parse_trade_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+        is_buy: bool,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 25 || accounts.len() < 11 {
+            return None;
+        }
+        Some(Box::new(MoonshotTradeEvent {
+            metadata,
+            is_buy,
+            token_amount: read_u64_le(data, 0)?,
+            collateral_amount: read_u64_le(data, 8)?,
+            fixed_side: read_u8(data, 16)?,
+            slippage_bps: read_u64_le(data, 17)?,
+            sender: accounts[0],
+            sender_token_account: accounts[1],
+            curve_account: accounts[2],
+            curve_token_account: accounts[3],
+            dex_fee: accounts[4],
+            helio_fee: accounts[5],
+            mint: accounts[6],
+            config_account: accounts[7],
+            token_program: accounts[8],
+            associated_token_program: accounts[9],
+            system_program: accounts[10],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析迁移资金指令事件
+    fn parse_migrate_funds_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 12 {
+            return None;
+        }
+        Some(Box::new(MoonshotMigrateFundsEvent {
+            metadata,
+            backend_authority: accounts[0],
+            migration_authority: accounts[1],
+            curve_account: accounts[2],
+            curve_token_account: accounts[3],
+            migration_authority_token_account: accounts[4],
+            mint: accounts[5],
+            dex_fee_account: accounts[6],
+            helio_fee_account: accounts[7],
+            config_account: accounts[8],
+            system_program: accounts[9],
+            token_program: accounts[10],
+            associated_token_program: accounts[11],
+        }))
+    }
+}
+
+impl_event_parser_delegate!(MoonshotEventParser);
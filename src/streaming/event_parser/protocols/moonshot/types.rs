@@ -0,0 +1,107 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::EventMetadata, protocols::moonshot::MoonshotCurveAccountEvent, UnifiedEvent,
+    },
+    grpc::AccountPretty,
+};
+
+/// 交易固定方向：0: ExactIn, 1: ExactOut
+pub const FIXED_SIDE_EXACT_IN: u8 = 0;
+pub const FIXED_SIDE_EXACT_OUT: u8 = 1;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CurveAccount {
+    pub total_supply: u64,
+    pub curve_amount: u64,
+    pub mint: Pubkey,
+    pub decimals: u8,
+    /// 0: Sol
+    pub collateral_currency: u8,
+    /// 0: LinearV1, 1: ConstantProductV1
+    pub curve_type: u8,
+    pub marketcap_threshold: u64,
+    pub marketcap_currency: u8,
+    pub migration_fee: u64,
+    pub coef_b: u32,
+    pub bump: u8,
+    /// 0: Raydium, 1: Meteora
+    pub migration_target: u8,
+}
+
+pub const CURVE_ACCOUNT_SIZE: usize = 74;
+
+pub fn curve_account_decode(data: &[u8]) -> Option<CurveAccount> {
+    if data.len() < CURVE_ACCOUNT_SIZE {
+        return None;
+    }
+    borsh::from_slice::<CurveAccount>(&data[..CURVE_ACCOUNT_SIZE]).ok()
+}
+
+pub fn curve_account_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < CURVE_ACCOUNT_SIZE + 8 {
+        return None;
+    }
+    let curve_account = curve_account_decode(&account.data[8..CURVE_ACCOUNT_SIZE + 8])?;
+    Some(Box::new(MoonshotCurveAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        curve_account,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::moonshot::discriminators;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use std::str::FromStr;
+
+    // 按 CurveAccount 布局构造：鉴别器 + 账户数据的 base64
+    const CURVE_ACCOUNT_DATA: &str = concat!(
+        "CFtTHITY+BYAAGSns7bgDQAAUOzCKxoLhUxEmyWXCsiYMpP8371D5DGkForORsAV23iynQEynd8JAAEAOppTUAAA",
+        "AAAAXtCyAAAAAEBCDwD+AQ==",
+    );
+
+    #[test]
+    fn curve_account_layout() {
+        let data = STANDARD.decode(CURVE_ACCOUNT_DATA).unwrap();
+        assert_eq!(&data[..8], discriminators::CURVE_ACCOUNT);
+        let account = AccountPretty { data: data.clone(), ..Default::default() };
+
+        let event = curve_account_parser(&account, EventMetadata::default()).unwrap();
+        let curve =
+            &event.as_any().downcast_ref::<MoonshotCurveAccountEvent>().unwrap().curve_account;
+        assert_eq!(
+            *curve,
+            CurveAccount {
+                total_supply: 1_000_000_000_000_000_000,
+                curve_amount: 800_000_000_000_000_000,
+                mint: Pubkey::from_str("9yLgBiva7FG498uyNmds9QEamHGHefFQYtpzdzyPyd2v").unwrap(),
+                decimals: 9,
+                collateral_currency: 0,
+                curve_type: 1,
+                marketcap_threshold: 345_000_000_000,
+                marketcap_currency: 0,
+                migration_fee: 3_000_000_000,
+                coef_b: 1_000_000,
+                bump: 254,
+                migration_target: 1,
+            }
+        );
+
+        let truncated =
+            AccountPretty { data: data[..data.len() - 1].to_vec(), ..Default::default() };
+        assert!(curve_account_parser(&truncated, EventMetadata::default()).is_none());
+    }
+}
@@ -1,25 +1,54 @@
-use crate::{
-    impl_event_parser_delegate,
-    streaming::event_parser::{
-        common::filter::EventTypeFilter,
-        core::traits::{GenericEventParseConfig, GenericEventParser},
-        EventParserFactory, Protocol,
-    },
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use prost_types::Timestamp;
+use solana_sdk::{instruction::CompiledInstruction, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::InnerInstructions;
+
+use crate::streaming::event_parser::{
+    common::{filter::EventTypeFilter, EventType},
+    core::traits::{EventParser, GenericEventParseConfig, GenericEventParser, UnifiedEvent},
+    EventParserFactory, Protocol,
 };
 
+type EventRefCallback = Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>;
+
 pub struct MutilEventParser {
     inner: GenericEventParser,
+    /// 自定义协议的解析器，按程序ID直接分派，保留其对 EventParser 方法的重写
+    custom_parsers: Vec<Arc<dyn EventParser>>,
+    event_type_filter: Option<EventTypeFilter>,
 }
 
 impl MutilEventParser {
+    /// 合并多个协议的解析器，未注册的自定义协议会被跳过
     pub fn new(protocols: Vec<Protocol>, event_type_filter: Option<EventTypeFilter>) -> Self {
         let mut inner = GenericEventParser::new(vec![], vec![]);
+        inner.program_upgrade_enabled = event_type_filter
+            .as_ref()
+            .map(|filter| filter.include.contains(&EventType::ProgramUpgrade))
+            .unwrap_or(true);
+        let mut custom_parsers = Vec::new();
         // Configure all event types
         for protocol in protocols {
-            let parse = EventParserFactory::create_parser(protocol);
+            let parse = match EventParserFactory::try_create_parser(protocol.clone()) {
+                Ok(parse) => parse,
+                Err(e) => {
+                    log::error!("Skipping protocol {}: {}", protocol, e);
+                    continue;
+                }
+            };
+            if matches!(protocol, Protocol::Custom(_)) {
+                custom_parsers.push(parse);
+                continue;
+            }
 
             // Merge instruction_configs, append configurations to existing Vec
             for (key, configs) in parse.instruction_configs() {
+                // 被过滤掉的指令仍视为已知，避免被当作未知指令捕获
+                inner
+                    .known_discriminators
+                    .extend(configs.iter().flat_map(GenericEventParser::config_discriminators));
                 let filtered_configs: Vec<GenericEventParseConfig> = configs
                     .into_iter()
                     .filter(|config| {
@@ -39,8 +68,347 @@ impl MutilEventParser {
             // Append program_ids (this is already appending)
             inner.program_ids.extend(parse.supported_program_ids().clone());
         }
-        Self { inner }
+        Self { inner, custom_parsers, event_type_filter }
+    }
+
+    /// 处理该程序的自定义协议解析器
+    fn custom_parser(&self, program_id: &Pubkey) -> Option<&Arc<dyn EventParser>> {
+        self.custom_parsers.iter().find(|parser| parser.should_handle(program_id))
+    }
+
+    /// 事件类型是否通过过滤，原始指令事件由解码监控单独控制
+    fn includes(&self, event_type: &EventType) -> bool {
+        *event_type == EventType::RawInstruction
+            || self
+                .event_type_filter
+                .as_ref()
+                .map(|filter| filter.include.contains(event_type))
+                .unwrap_or(true)
+    }
+
+    /// 为自定义协议解析器的回调加上事件类型过滤
+    fn filtered_callback(&self, callback: EventRefCallback) -> EventRefCallback {
+        let Some(filter) = self.event_type_filter.clone() else {
+            return callback;
+        };
+        let filtered: EventRefCallback = Arc::new(move |event| {
+            let event_type = event.event_type();
+            if event_type == EventType::RawInstruction || filter.include.contains(&event_type) {
+                callback(event);
+            }
+        });
+        filtered
     }
 }
 
-impl_event_parser_delegate!(MutilEventParser);
+#[async_trait::async_trait]
+impl EventParser for MutilEventParser {
+    fn instruction_configs(&self) -> HashMap<Vec<u8>, Vec<GenericEventParseConfig>> {
+        let mut configs = self.inner.instruction_configs();
+        for parser in &self.custom_parsers {
+            for (key, custom_configs) in parser.instruction_configs() {
+                configs.entry(key).or_default().extend(
+                    custom_configs.into_iter().filter(|config| self.includes(&config.event_type)),
+                );
+            }
+        }
+        configs
+    }
+
+    fn parse_events_from_inner_instruction(
+        &self,
+        inner_instruction: &CompiledInstruction,
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        outer_index: i64,
+        inner_index: Option<i64>,
+        transaction_index: Option<u64>,
+        config: &GenericEventParseConfig,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        let Some(parser) = self.custom_parser(&config.program_id) else {
+            return self.inner.parse_events_from_inner_instruction(
+                inner_instruction,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                transaction_index,
+                config,
+            );
+        };
+        let mut events = parser.parse_events_from_inner_instruction(
+            inner_instruction,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            outer_index,
+            inner_index,
+            transaction_index,
+            config,
+        );
+        events.retain(|event| self.includes(&event.event_type()));
+        events
+    }
+
+    fn parse_events_from_grpc_inner_instruction(
+        &self,
+        inner_instruction: &yellowstone_grpc_proto::prelude::InnerInstruction,
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        outer_index: i64,
+        inner_index: Option<i64>,
+        transaction_index: Option<u64>,
+        config: &GenericEventParseConfig,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        let Some(parser) = self.custom_parser(&config.program_id) else {
+            return self.inner.parse_events_from_grpc_inner_instruction(
+                inner_instruction,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                transaction_index,
+                config,
+            );
+        };
+        let mut events = parser.parse_events_from_grpc_inner_instruction(
+            inner_instruction,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            outer_index,
+            inner_index,
+            transaction_index,
+            config,
+        );
+        events.retain(|event| self.includes(&event.event_type()));
+        events
+    }
+
+    fn parse_events_from_instruction(
+        &self,
+        instruction: &CompiledInstruction,
+        accounts: &[Pubkey],
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        outer_index: i64,
+        inner_index: Option<i64>,
+        bot_wallet: Option<Pubkey>,
+        transaction_index: Option<u64>,
+        inner_instructions: Option<&InnerInstructions>,
+        callback: EventRefCallback,
+    ) -> anyhow::Result<()> {
+        let custom_parser = accounts
+            .get(instruction.program_id_index as usize)
+            .and_then(|program_id| self.custom_parser(program_id));
+        let Some(parser) = custom_parser else {
+            return self.inner.parse_events_from_instruction(
+                instruction,
+                accounts,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                bot_wallet,
+                transaction_index,
+                inner_instructions,
+                callback,
+            );
+        };
+        parser.parse_events_from_instruction(
+            instruction,
+            accounts,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            outer_index,
+            inner_index,
+            bot_wallet,
+            transaction_index,
+            inner_instructions,
+            self.filtered_callback(callback),
+        )
+    }
+
+    fn parse_events_from_grpc_instruction(
+        &self,
+        instruction: &yellowstone_grpc_proto::prelude::CompiledInstruction,
+        accounts: &[Pubkey],
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        outer_index: i64,
+        inner_index: Option<i64>,
+        bot_wallet: Option<Pubkey>,
+        transaction_index: Option<u64>,
+        inner_instructions: Option<&yellowstone_grpc_proto::prelude::InnerInstructions>,
+        callback: EventRefCallback,
+    ) -> anyhow::Result<()> {
+        let custom_parser = accounts
+            .get(instruction.program_id_index as usize)
+            .and_then(|program_id| self.custom_parser(program_id));
+        let Some(parser) = custom_parser else {
+            return self.inner.parse_events_from_grpc_instruction(
+                instruction,
+                accounts,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                bot_wallet,
+                transaction_index,
+                inner_instructions,
+                callback,
+            );
+        };
+        parser.parse_events_from_grpc_instruction(
+            instruction,
+            accounts,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            outer_index,
+            inner_index,
+            bot_wallet,
+            transaction_index,
+            inner_instructions,
+            self.filtered_callback(callback),
+        )
+    }
+
+    fn parse_events_from_logs(
+        &self,
+        log_messages: &[String],
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        bot_wallet: Option<Pubkey>,
+        callback: EventRefCallback,
+    ) -> anyhow::Result<()> {
+        self.inner.parse_events_from_logs(
+            log_messages,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            bot_wallet,
+            callback.clone(),
+        )?;
+        for parser in &self.custom_parsers {
+            parser.parse_events_from_logs(
+                log_messages,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                bot_wallet,
+                self.filtered_callback(callback.clone()),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn should_handle(&self, program_id: &Pubkey) -> bool {
+        self.inner.should_handle(program_id) || self.custom_parser(program_id).is_some()
+    }
+
+    fn supported_program_ids(&self) -> Vec<Pubkey> {
+        let mut program_ids = self.inner.supported_program_ids();
+        for parser in &self.custom_parsers {
+            program_ids.extend(parser.supported_program_ids());
+        }
+        program_ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::core::program_upgrade::{
+        program_data_address, BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+    };
+    use crate::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+
+    #[test]
+    fn unregistered_custom_protocol_is_skipped() {
+        let protocol = Protocol::Custom("unregistered".to_string());
+        assert!(EventParserFactory::try_create_parser(protocol.clone()).is_err());
+
+        let parser = MutilEventParser::new(vec![Protocol::PumpFun, protocol], None);
+        assert!(parser.custom_parsers.is_empty());
+        assert_eq!(
+            parser.supported_program_ids(),
+            EventParserFactory::create_parser(Protocol::PumpFun).supported_program_ids()
+        );
+    }
+
+    /// 解析一条升级 PumpFun 程序的 Upgrade 指令，返回产生的事件类型
+    fn upgrade_events(parser: &MutilEventParser) -> Vec<EventType> {
+        // Upgrade 指令账户：programdata, program, buffer, spill, rent, clock, authority
+        let mut accounts = vec![program_data_address(&PUMPFUN_PROGRAM_ID), PUMPFUN_PROGRAM_ID];
+        accounts.extend((0..5).map(|_| Pubkey::new_unique()));
+        accounts.push(BPF_LOADER_UPGRADEABLE_PROGRAM_ID);
+        let instruction = CompiledInstruction {
+            program_id_index: 7,
+            accounts: (0..7).collect(),
+            data: vec![3, 0, 0, 0],
+        };
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let callback: EventRefCallback =
+            Arc::new(move |event| sink.lock().unwrap().push(event.event_type()));
+        parser
+            .parse_events_from_instruction(
+                &instruction,
+                &accounts,
+                Signature::default(),
+                0,
+                None,
+                0,
+                0,
+                None,
+                None,
+                None,
+                None,
+                callback,
+            )
+            .unwrap();
+        let events = events.lock().unwrap().clone();
+        events
+    }
+
+    #[test]
+    fn program_upgrade_follows_event_type_filter() {
+        let protocols = vec![Protocol::PumpFun];
+        let parser = MutilEventParser::new(protocols.clone(), None);
+        assert_eq!(upgrade_events(&parser), vec![EventType::ProgramUpgrade]);
+
+        let filter = EventTypeFilter { include: vec![EventType::PumpFunBuy] };
+        let parser = MutilEventParser::new(protocols.clone(), Some(filter));
+        assert!(upgrade_events(&parser).is_empty());
+
+        let filter = EventTypeFilter { include: vec![EventType::ProgramUpgrade] };
+        let parser = MutilEventParser::new(protocols, Some(filter));
+        assert_eq!(upgrade_events(&parser), vec![EventType::ProgramUpgrade]);
+    }
+}
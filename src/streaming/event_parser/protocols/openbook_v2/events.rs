@@ -0,0 +1,131 @@
+use crate::impl_unified_event;
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::openbook_v2::types::{BookSide, EventHeap, Market};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 挂单（place_order）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenBookV2PlaceOrderEvent {
+    pub metadata: EventMetadata,
+    /// 0: bid, 1: ask
+    pub side: u8,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots_including_fees: i64,
+    pub client_order_id: u64,
+    pub order_type: u8,
+    pub expiry_timestamp: u64,
+    pub self_trade_behavior: u8,
+    pub limit: u8,
+    pub signer: Pubkey,
+    pub open_orders_account: Pubkey,
+    pub open_orders_admin: Pubkey,
+    pub user_token_account: Pubkey,
+    pub market: Pubkey,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub event_heap: Pubkey,
+    pub market_vault: Pubkey,
+    pub oracle_a: Pubkey,
+    pub oracle_b: Pubkey,
+    pub token_program: Pubkey,
+}
+impl_unified_event!(OpenBookV2PlaceOrderEvent,);
+
+/// 吃单（place_take_order）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenBookV2PlaceTakeOrderEvent {
+    pub metadata: EventMetadata,
+    /// 0: bid, 1: ask
+    pub side: u8,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots_including_fees: i64,
+    pub order_type: u8,
+    pub limit: u8,
+    pub signer: Pubkey,
+    pub penalty_payer: Pubkey,
+    pub market: Pubkey,
+    pub market_authority: Pubkey,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub market_base_vault: Pubkey,
+    pub market_quote_vault: Pubkey,
+    pub event_heap: Pubkey,
+    pub user_base_account: Pubkey,
+    pub user_quote_account: Pubkey,
+    pub oracle_a: Pubkey,
+    pub oracle_b: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub open_orders_admin: Pubkey,
+}
+impl_unified_event!(OpenBookV2PlaceTakeOrderEvent,);
+
+/// 撤单（cancel_order / cancel_order_by_client_order_id）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenBookV2CancelOrderEvent {
+    pub metadata: EventMetadata,
+    pub order_id: u128,
+    pub client_order_id: u64,
+    pub signer: Pubkey,
+    pub open_orders_account: Pubkey,
+    pub market: Pubkey,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+}
+impl_unified_event!(OpenBookV2CancelOrderEvent,);
+
+/// 市场
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OpenBookV2MarketAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub market: Market,
+}
+impl_unified_event!(OpenBookV2MarketAccountEvent,);
+
+/// 订单簿一侧（最优价）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenBookV2BookSideAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub book_side: BookSide,
+}
+impl_unified_event!(OpenBookV2BookSideAccountEvent,);
+
+/// 事件队列（成交 / 移出）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenBookV2EventHeapAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub event_heap: EventHeap,
+}
+impl_unified_event!(OpenBookV2EventHeapAccountEvent,);
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器
+    pub const PLACE_ORDER: &[u8] = &[51, 194, 155, 175, 109, 130, 96, 106];
+    pub const PLACE_TAKE_ORDER: &[u8] = &[3, 44, 71, 3, 26, 199, 203, 85];
+    pub const CANCEL_ORDER: &[u8] = &[95, 129, 237, 240, 8, 49, 223, 132];
+    pub const CANCEL_ORDER_BY_CLIENT_ORDER_ID: &[u8] = &[115, 178, 201, 8, 175, 183, 123, 119];
+
+    // 账号鉴别器
+    pub const MARKET: &[u8] = &[219, 190, 213, 55, 0, 227, 198, 154];
+    pub const BOOK_SIDE: &[u8] = &[72, 44, 225, 141, 178, 130, 97, 57];
+    pub const EVENT_HEAP: &[u8] = &[119, 59, 61, 19, 165, 84, 57, 175];
+}
@@ -0,0 +1,202 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_event_parser_delegate,
+    streaming::event_parser::{
+        common::{
+            read_i64_le, read_u128_le, read_u64_le, read_u8, EventMetadata, EventType, ProtocolType,
+        },
+        core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
+        protocols::openbook_v2::{
+            discriminators, OpenBookV2CancelOrderEvent, OpenBookV2PlaceOrderEvent,
+            OpenBookV2PlaceTakeOrderEvent,
+        },
+    },
+};
+
+/// OpenBook v2程序ID
+pub const OPENBOOK_V2_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHpU1h4VZWqie");
+
+/// OpenBook v2事件解析器
+pub struct OpenBookV2EventParser {
+    inner: GenericEventParser,
+}
+
+impl Default for OpenBookV2EventParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpenBookV2EventParser {
+    pub fn new() -> Self {
+        // 配置所有事件类型
+        let configs = vec![
+            GenericEventParseConfig {
+                program_id: OPENBOOK_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::OpenBookV2,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::PLACE_ORDER,
+                event_type: EventType::OpenBookV2PlaceOrder,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_place_order_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: OPENBOOK_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::OpenBookV2,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::PLACE_TAKE_ORDER,
+                event_type: EventType::OpenBookV2PlaceTakeOrder,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_place_take_order_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: OPENBOOK_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::OpenBookV2,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CANCEL_ORDER,
+                event_type: EventType::OpenBookV2CancelOrder,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_cancel_order_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: OPENBOOK_V2_PROGRAM_ID,
+                protocol_type: ProtocolType::OpenBookV2,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CANCEL_ORDER_BY_CLIENT_ORDER_ID,
+                event_type: EventType::OpenBookV2CancelOrderByClientOrderId,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_cancel_order_by_client_order_id_instruction),
+            },
+        ];
+
+        let inner = GenericEventParser::new(vec![OPENBOOK_V2_PROGRAM_ID], configs);
+
+        Self { inner }
+    }
+
+    /// 可选账户未传入时为程序ID
+    fn optional_account(account: Pubkey) -> Pubkey {
+        if account == OPENBOOK_V2_PROGRAM_ID {
+            Pubkey::default()
+        } else {
+            account
+        }
+    }
+
+    /// 解析挂单指令事件
+    fn parse_place_order_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 44 || accounts.len() < 12 {
+            return None;
+        }
+        Some(Box::new(OpenBookV2PlaceOrderEvent {
+            metadata,
+            side: read_u8(data, 0)?,
+            price_lots: read_i64_le(data, 1)?,
+            max_base_lots: read_i64_le(data, 9)?,
+            max_quote_lots_including_fees: read_i64_le(data, 17)?,
+            client_order_id: read_u64_le(data, 25)?,
+            order_type: read_u8(data, 33)?,
+            expiry_timestamp: read_u64_le(data, 34)?,
+            self_trade_behavior: read_u8(data, 42)?,
+            limit: read_u8(data, 43)?,
+            signer: accounts[0],
+            open_orders_account: accounts[1],
+            open_orders_admin: Self::optional_account(accounts[2]),
+            user_token_account: accounts[3],
+            market: accounts[4],
+            bids: accounts[5],
+            asks: accounts[6],
+            event_heap: accounts[7],
+            market_vault: accounts[8],
+            oracle_a: Self::optional_account(accounts[9]),
+            oracle_b: Self::optional_account(accounts[10]),
+            token_program: accounts[11],
+        }))
+    }
+
+    /// 解析吃单指令事件
+    fn parse_place_take_order_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 27 || accounts.len() < 16 {
+            return None;
+        }
+        Some(Box::new(OpenBookV2PlaceTakeOrderEvent {
+            metadata,
+            side: read_u8(data, 0)?,
+            price_lots: read_i64_le(data, 1)?,
+            max_base_lots: read_i64_le(data, 9)?,
+            max_quote_lots_including_fees: read_i64_le(data, 17)?,
+            order_type: read_u8(data, 25)?,
+            limit: read_u8(data, 26)?,
+            signer: accounts[0],
+            penalty_payer: accounts[1],
+            market: accounts[2],
+            market_authority: accounts[3],
+            bids: accounts[4],
+            asks: accounts[5],
+            market_base_vault: accounts[6],
+            market_quote_vault: accounts[7],
+            event_heap: accounts[8],
+            user_base_account: accounts[9],
+            user_quote_account: accounts[10],
+            oracle_a: Self::optional_account(accounts[11]),
+            oracle_b: Self::optional_account(accounts[12]),
+            token_program: accounts[13],
+            system_program: accounts[14],
+            open_orders_admin: Self::optional_account(accounts[15]),
+        }))
+    }
+
+    /// 解析撤单指令事件
+    fn parse_cancel_order_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 5 {
+            return None;
+        }
+        Some(Box::new(OpenBookV2CancelOrderEvent {
+            metadata,
+            order_id: read_u128_le(data, 0)?,
+            client_order_id: 0,
+            signer: accounts[0],
+            open_orders_account: accounts[1],
+            market: accounts[2],
+            bids: accounts[3],
+            asks: accounts[4],
+        }))
+    }
+
+    /// 解析按客户端订单ID撤单指令事件
+    fn parse_cancel_order_by_client_order_id_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 5 {
+            return None;
+        }
+        Some(Box::new(OpenBookV2CancelOrderEvent {
+            metadata,
+            order_id: 0,
+            client_order_id: read_u64_le(data, 0)?,
+            signer: accounts[0],
+            open_orders_account: accounts[1],
+            market: accounts[2],
+            bids: accounts[3],
+            asks: accounts[4],
+        }))
+    }
+}
+
+impl_event_parser_delegate!(OpenBookV2EventParser);
@@ -0,0 +1,392 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::{
+            read_i64_le, read_pubkey, read_u128_le, read_u16_le, read_u32_le, read_u64_le, read_u8,
+            EventMetadata,
+        },
+        protocols::openbook_v2::{
+            OpenBookV2BookSideAccountEvent, OpenBookV2EventHeapAccountEvent,
+            OpenBookV2MarketAccountEvent,
+        },
+        UnifiedEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OracleConfig {
+    pub conf_filter: f64,
+    pub max_staleness_slots: i64,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub reserved: [u8; 72],
+}
+
+impl Default for OracleConfig {
+    fn default() -> Self {
+        Self { conf_filter: 0.0, max_staleness_slots: 0, reserved: [0; 72] }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Market {
+    pub bump: u8,
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+    pub padding1: [u8; 5],
+    pub market_authority: Pubkey,
+    pub time_expiry: i64,
+    pub collect_fee_admin: Pubkey,
+    pub open_orders_admin: Pubkey,
+    pub consume_events_admin: Pubkey,
+    pub close_market_admin: Pubkey,
+    pub name: [u8; 16],
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub event_heap: Pubkey,
+    pub oracle_a: Pubkey,
+    pub oracle_b: Pubkey,
+    pub oracle_config: OracleConfig,
+    pub quote_lot_size: i64,
+    pub base_lot_size: i64,
+    pub seq_num: u64,
+    pub registration_time: i64,
+    pub maker_fee: i64,
+    pub taker_fee: i64,
+    pub fees_accrued: u128,
+    pub fees_to_referrers: u128,
+    pub referrer_rebates_accrued: u64,
+    pub fees_available: u64,
+    pub maker_volume: u128,
+    pub taker_volume_wo_oo: u128,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub market_base_vault: Pubkey,
+    pub base_deposit_total: u64,
+    pub market_quote_vault: Pubkey,
+    pub quote_deposit_total: u64,
+}
+
+/// 不含末尾 reserved 的 Market 长度
+pub const MARKET_SIZE: usize = 712;
+
+pub fn market_decode(data: &[u8]) -> Option<Market> {
+    if data.len() < MARKET_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Market>(&data[..MARKET_SIZE]).ok()
+}
+
+pub fn market_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < MARKET_SIZE + 8 {
+        return None;
+    }
+    let market = market_decode(&account.data[8..MARKET_SIZE + 8])?;
+    Some(Box::new(OpenBookV2MarketAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        market,
+    }))
+}
+
+/// 订单簿一侧的汇总：固定价格订单树的最优价及其挂单量
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookSide {
+    /// 0: bids, 1: asks
+    pub order_tree_type: u8,
+    pub fixed_order_count: u32,
+    pub oracle_pegged_order_count: u32,
+    pub best_price_lots: i64,
+    pub best_quantity_lots: i64,
+    pub best_order_count: u32,
+}
+
+/// 订单树节点数量与大小
+pub const MAX_ORDERTREE_NODES: usize = 1024;
+pub const ORDERTREE_NODE_SIZE: usize = 88;
+/// roots(16) + reserved_roots(32) + reserved(256) + nodes 头部(528)
+const BOOK_SIDE_NODES_OFFSET: usize = 832;
+const BOOK_SIDE_ORDER_TREE_TYPE_OFFSET: usize = 304;
+pub const BOOK_SIDE_SIZE: usize =
+    BOOK_SIDE_NODES_OFFSET + MAX_ORDERTREE_NODES * ORDERTREE_NODE_SIZE;
+
+const NODE_TAG_INNER: u8 = 1;
+const NODE_TAG_LEAF: u8 = 2;
+
+pub fn book_side_decode(data: &[u8]) -> Option<BookSide> {
+    if data.len() < BOOK_SIDE_SIZE {
+        return None;
+    }
+    let order_tree_type = read_u8(data, BOOK_SIDE_ORDER_TREE_TYPE_OFFSET)?;
+    let fixed_root = read_u32_le(data, 0)?;
+    let fixed_order_count = read_u32_le(data, 4)?;
+    let oracle_pegged_order_count = read_u32_le(data, 12)?;
+    let mut book_side = BookSide {
+        order_tree_type,
+        fixed_order_count,
+        oracle_pegged_order_count,
+        ..Default::default()
+    };
+    if fixed_order_count == 0 {
+        return Some(book_side);
+    }
+
+    // 遍历固定价格订单树的全部叶子，key 高 64 位为价格
+    let is_bids = order_tree_type == 0;
+    let mut stack = vec![fixed_root];
+    let mut visited = 0;
+    while let Some(handle) = stack.pop() {
+        visited += 1;
+        if handle as usize >= MAX_ORDERTREE_NODES || visited > MAX_ORDERTREE_NODES {
+            break;
+        }
+        let offset = BOOK_SIDE_NODES_OFFSET + handle as usize * ORDERTREE_NODE_SIZE;
+        match read_u8(data, offset)? {
+            NODE_TAG_INNER => {
+                stack.push(read_u32_le(data, offset + 24)?);
+                stack.push(read_u32_le(data, offset + 28)?);
+            }
+            NODE_TAG_LEAF => {
+                let price_lots = (read_u128_le(data, offset + 8)? >> 64) as i64;
+                let quantity = read_i64_le(data, offset + 56)?;
+                let better = book_side.best_order_count == 0
+                    || (is_bids && price_lots > book_side.best_price_lots)
+                    || (!is_bids && price_lots < book_side.best_price_lots);
+                if better {
+                    book_side.best_price_lots = price_lots;
+                    book_side.best_quantity_lots = quantity;
+                    book_side.best_order_count = 1;
+                } else if price_lots == book_side.best_price_lots {
+                    book_side.best_quantity_lots += quantity;
+                    book_side.best_order_count += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(book_side)
+}
+
+pub fn book_side_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < BOOK_SIDE_SIZE + 8 {
+        return None;
+    }
+    let book_side = book_side_decode(&account.data[8..BOOK_SIDE_SIZE + 8])?;
+    Some(Box::new(OpenBookV2BookSideAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        book_side,
+    }))
+}
+
+/// 成交事件（EventHeap 中的 FillEvent）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FillEvent {
+    pub taker_side: u8,
+    pub maker_out: bool,
+    pub maker_slot: u8,
+    pub timestamp: u64,
+    pub market_seq_num: u64,
+    pub maker: Pubkey,
+    pub maker_timestamp: u64,
+    pub taker: Pubkey,
+    pub taker_client_order_id: u64,
+    pub price: i64,
+    pub peg_limit: i64,
+    pub quantity: i64,
+    pub maker_client_order_id: u64,
+}
+
+/// 挂单移出事件（EventHeap 中的 OutEvent，撤单或过期）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutEvent {
+    pub side: u8,
+    pub owner_slot: u8,
+    pub timestamp: u64,
+    pub seq_num: u64,
+    pub owner: Pubkey,
+    pub quantity: i64,
+}
+
+/// 待处理事件队列
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventHeap {
+    pub count: u16,
+    pub seq_num: u64,
+    pub fills: Vec<FillEvent>,
+    pub outs: Vec<OutEvent>,
+}
+
+pub const MAX_NUM_EVENTS: usize = 600;
+pub const EVENT_NODE_SIZE: usize = 152;
+const EVENT_HEAP_HEADER_SIZE: usize = 16;
+pub const EVENT_HEAP_SIZE: usize = EVENT_HEAP_HEADER_SIZE + MAX_NUM_EVENTS * EVENT_NODE_SIZE;
+
+const EVENT_TYPE_FILL: u8 = 0;
+const EVENT_TYPE_OUT: u8 = 1;
+
+fn fill_event_decode(data: &[u8], offset: usize) -> Option<FillEvent> {
+    Some(FillEvent {
+        taker_side: read_u8(data, offset + 1)?,
+        maker_out: read_u8(data, offset + 2)? != 0,
+        maker_slot: read_u8(data, offset + 3)?,
+        timestamp: read_u64_le(data, offset + 8)?,
+        market_seq_num: read_u64_le(data, offset + 16)?,
+        maker: read_pubkey(data, offset + 24)?,
+        maker_timestamp: read_u64_le(data, offset + 56)?,
+        taker: read_pubkey(data, offset + 64)?,
+        taker_client_order_id: read_u64_le(data, offset + 96)?,
+        price: read_i64_le(data, offset + 104)?,
+        peg_limit: read_i64_le(data, offset + 112)?,
+        quantity: read_i64_le(data, offset + 120)?,
+        maker_client_order_id: read_u64_le(data, offset + 128)?,
+    })
+}
+
+fn out_event_decode(data: &[u8], offset: usize) -> Option<OutEvent> {
+    Some(OutEvent {
+        side: read_u8(data, offset + 1)?,
+        owner_slot: read_u8(data, offset + 2)?,
+        timestamp: read_u64_le(data, offset + 8)?,
+        seq_num: read_u64_le(data, offset + 16)?,
+        owner: read_pubkey(data, offset + 24)?,
+        quantity: read_i64_le(data, offset + 56)?,
+    })
+}
+
+pub fn event_heap_decode(data: &[u8]) -> Option<EventHeap> {
+    if data.len() < EVENT_HEAP_SIZE {
+        return None;
+    }
+    let used_head = read_u16_le(data, 2)?;
+    let count = read_u16_le(data, 4)?;
+    let mut event_heap = EventHeap { count, seq_num: read_u64_le(data, 8)?, ..Default::default() };
+
+    // 按 used 链表顺序读取事件
+    let mut index = used_head as usize;
+    for _ in 0..(count as usize).min(MAX_NUM_EVENTS) {
+        if index >= MAX_NUM_EVENTS {
+            break;
+        }
+        let node = EVENT_HEAP_HEADER_SIZE + index * EVENT_NODE_SIZE;
+        let event = node + 8;
+        match read_u8(data, event)? {
+            EVENT_TYPE_FILL => event_heap.fills.push(fill_event_decode(data, event)?),
+            EVENT_TYPE_OUT => event_heap.outs.push(out_event_decode(data, event)?),
+            _ => {}
+        }
+        index = read_u16_le(data, node)? as usize;
+    }
+    Some(event_heap)
+}
+
+pub fn event_heap_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < EVENT_HEAP_SIZE + 8 {
+        return None;
+    }
+    let event_heap = event_heap_decode(&account.data[8..EVENT_HEAP_SIZE + 8])?;
+    Some(Box::new(OpenBookV2EventHeapAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        event_heap,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按账户布局偏移写入字段，偏移包含 8 字节鉴别器
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[8 + offset..8 + offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[test]
+    fn event_heap_layout() {
+        let (maker, taker, owner) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = vec![0u8; EVENT_HEAP_SIZE + 8];
+        // 头部：free_head, used_head, count, padding, seq_num
+        put(&mut data, 2, &5u16.to_le_bytes());
+        put(&mut data, 4, &2u16.to_le_bytes());
+        put(&mut data, 8, &77u64.to_le_bytes());
+
+        // used 链表：节点 5 -> 节点 9，事件位于节点偏移 8
+        let fill = EVENT_HEAP_HEADER_SIZE + 5 * EVENT_NODE_SIZE;
+        put(&mut data, fill, &9u16.to_le_bytes());
+        put(&mut data, fill + 8, &[EVENT_TYPE_FILL, 1, 1, 3]);
+        put(&mut data, fill + 16, &1_760_000_000u64.to_le_bytes());
+        put(&mut data, fill + 24, &76u64.to_le_bytes());
+        put(&mut data, fill + 32, maker.as_ref());
+        put(&mut data, fill + 72, taker.as_ref());
+        put(&mut data, fill + 104, &42u64.to_le_bytes());
+        put(&mut data, fill + 112, &1_234i64.to_le_bytes());
+        put(&mut data, fill + 128, &500i64.to_le_bytes());
+        put(&mut data, fill + 136, &7u64.to_le_bytes());
+
+        let out = EVENT_HEAP_HEADER_SIZE + 9 * EVENT_NODE_SIZE;
+        put(&mut data, out + 8, &[EVENT_TYPE_OUT, 0, 2]);
+        put(&mut data, out + 24, &75u64.to_le_bytes());
+        put(&mut data, out + 32, owner.as_ref());
+        put(&mut data, out + 64, &300i64.to_le_bytes());
+        let account = AccountPretty { data, ..Default::default() };
+
+        let event = event_heap_parser(&account, EventMetadata::default()).unwrap();
+        let event_heap =
+            &event.as_any().downcast_ref::<OpenBookV2EventHeapAccountEvent>().unwrap().event_heap;
+        assert_eq!((event_heap.count, event_heap.seq_num), (2, 77));
+        assert_eq!(
+            event_heap.fills,
+            vec![FillEvent {
+                taker_side: 1,
+                maker_out: true,
+                maker_slot: 3,
+                timestamp: 1_760_000_000,
+                market_seq_num: 76,
+                maker,
+                maker_timestamp: 0,
+                taker,
+                taker_client_order_id: 42,
+                price: 1_234,
+                peg_limit: 0,
+                quantity: 500,
+                maker_client_order_id: 7,
+            }]
+        );
+        assert_eq!(
+            event_heap.outs,
+            vec![OutEvent {
+                side: 0,
+                owner_slot: 2,
+                timestamp: 0,
+                seq_num: 75,
+                owner,
+                quantity: 300
+            }]
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use crate::impl_unified_event;
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::orca_whirlpool::types::{TickArray, Whirlpool};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 交易（swap / swap_v2，v1 指令不携带 mint 账户）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolSwapEvent {
+    pub metadata: EventMetadata,
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit: u128,
+    pub amount_specified_is_input: bool,
+    pub a_to_b: bool,
+    pub token_authority: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array0: Pubkey,
+    pub tick_array1: Pubkey,
+    pub tick_array2: Pubkey,
+    pub oracle: Pubkey,
+    pub token_program_a: Pubkey,
+    pub token_program_b: Pubkey,
+    pub memo_program: Pubkey,
+    pub remaining_accounts: Vec<Pubkey>,
+}
+impl_unified_event!(OrcaWhirlpoolSwapEvent,);
+
+/// 两跳交易（two_hop_swap / two_hop_swap_v2）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolTwoHopSwapEvent {
+    pub metadata: EventMetadata,
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub amount_specified_is_input: bool,
+    pub a_to_b_one: bool,
+    pub a_to_b_two: bool,
+    pub sqrt_price_limit_one: u128,
+    pub sqrt_price_limit_two: u128,
+    pub token_authority: Pubkey,
+    pub whirlpool_one: Pubkey,
+    pub whirlpool_two: Pubkey,
+    pub token_owner_account_input: Pubkey,
+    pub token_owner_account_output: Pubkey,
+    pub token_vault_one_input: Pubkey,
+    pub token_vault_one_intermediate: Pubkey,
+    pub token_vault_two_intermediate: Pubkey,
+    pub token_vault_two_output: Pubkey,
+    // 仅 v2 指令
+    pub token_mint_input: Pubkey,
+    pub token_mint_intermediate: Pubkey,
+    pub token_mint_output: Pubkey,
+    pub oracle_one: Pubkey,
+    pub oracle_two: Pubkey,
+    pub remaining_accounts: Vec<Pubkey>,
+}
+impl_unified_event!(OrcaWhirlpoolTwoHopSwapEvent,);
+
+/// 增加流动性（increase_liquidity / increase_liquidity_v2）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolIncreaseLiquidityEvent {
+    pub metadata: EventMetadata,
+    pub liquidity_amount: u128,
+    pub token_max_a: u64,
+    pub token_max_b: u64,
+    pub whirlpool: Pubkey,
+    pub position_authority: Pubkey,
+    pub position: Pubkey,
+    pub position_token_account: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+}
+impl_unified_event!(OrcaWhirlpoolIncreaseLiquidityEvent,);
+
+/// 减少流动性（decrease_liquidity / decrease_liquidity_v2）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolDecreaseLiquidityEvent {
+    pub metadata: EventMetadata,
+    pub liquidity_amount: u128,
+    pub token_min_a: u64,
+    pub token_min_b: u64,
+    pub whirlpool: Pubkey,
+    pub position_authority: Pubkey,
+    pub position: Pubkey,
+    pub position_token_account: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+}
+impl_unified_event!(OrcaWhirlpoolDecreaseLiquidityEvent,);
+
+/// 开仓（open_position / open_position_with_metadata / open_position_with_token_extensions）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolOpenPositionEvent {
+    pub metadata: EventMetadata,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub funder: Pubkey,
+    pub owner: Pubkey,
+    pub position: Pubkey,
+    pub position_mint: Pubkey,
+    pub position_token_account: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_program: Pubkey,
+}
+impl_unified_event!(OrcaWhirlpoolOpenPositionEvent,);
+
+/// 关闭仓位（close_position / close_position_with_token_extensions）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolClosePositionEvent {
+    pub metadata: EventMetadata,
+    pub position_authority: Pubkey,
+    pub receiver: Pubkey,
+    pub position: Pubkey,
+    pub position_mint: Pubkey,
+    pub position_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+impl_unified_event!(OrcaWhirlpoolClosePositionEvent,);
+
+/// 池子
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub whirlpool: Whirlpool,
+}
+impl_unified_event!(OrcaWhirlpoolAccountEvent,);
+
+/// Tick数组
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolTickArrayAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub tick_array: TickArray,
+}
+impl_unified_event!(OrcaWhirlpoolTickArrayAccountEvent,);
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器
+    pub const SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const SWAP_V2: &[u8] = &[43, 4, 237, 11, 26, 201, 30, 98];
+    pub const TWO_HOP_SWAP: &[u8] = &[195, 96, 237, 108, 68, 162, 219, 230];
+    pub const TWO_HOP_SWAP_V2: &[u8] = &[186, 143, 209, 29, 254, 2, 194, 117];
+    pub const INCREASE_LIQUIDITY: &[u8] = &[46, 156, 243, 118, 13, 205, 251, 178];
+    pub const INCREASE_LIQUIDITY_V2: &[u8] = &[133, 29, 89, 223, 69, 238, 176, 10];
+    pub const DECREASE_LIQUIDITY: &[u8] = &[160, 38, 208, 111, 104, 91, 44, 1];
+    pub const DECREASE_LIQUIDITY_V2: &[u8] = &[58, 127, 188, 62, 79, 82, 196, 96];
+    pub const OPEN_POSITION: &[u8] = &[135, 128, 47, 77, 15, 152, 240, 49];
+    pub const OPEN_POSITION_WITH_METADATA: &[u8] = &[242, 29, 134, 48, 58, 110, 14, 60];
+    pub const OPEN_POSITION_WITH_TOKEN_EXTENSIONS: &[u8] = &[212, 47, 95, 92, 114, 102, 131, 250];
+    pub const CLOSE_POSITION: &[u8] = &[123, 134, 81, 0, 49, 68, 98, 98];
+    pub const CLOSE_POSITION_WITH_TOKEN_EXTENSIONS: &[u8] = &[1, 182, 135, 59, 155, 25, 99, 223];
+
+    // 账号鉴别器
+    pub const WHIRLPOOL: &[u8] = &[63, 149, 209, 12, 225, 128, 99, 9];
+    pub const TICK_ARRAY: &[u8] = &[69, 97, 189, 190, 110, 7, 66, 187];
+}
+
+use crate::streaming::event_parser::protocols::raydium_cpmm::types::{
+    CopyTradeableEvent, TradeDirection, TradeInfo,
+};
+
+// WSOL mint address for trade direction detection
+const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+
+impl OrcaWhirlpoolSwapEvent {
+    /// Extract trade information with direction detection
+    pub fn get_trade_info(&self) -> Option<TradeInfo> {
+        // v1 指令没有 mint 账户，从已解析的 swap_data 中获取
+        let (input_mint, output_mint) = match &self.metadata.swap_data {
+            Some(swap_data) if self.token_mint_a == Pubkey::default() => {
+                (swap_data.from_mint, swap_data.to_mint)
+            }
+            _ if self.a_to_b => (self.token_mint_a, self.token_mint_b),
+            _ => (self.token_mint_b, self.token_mint_a),
+        };
+        let (amount_in, amount_out) = match &self.metadata.swap_data {
+            Some(swap_data) => (swap_data.from_amount, swap_data.to_amount),
+            None if self.amount_specified_is_input => (self.amount, self.other_amount_threshold),
+            None => (self.other_amount_threshold, self.amount),
+        };
+
+        // Only process if one of the tokens is WSOL
+        let (direction, token_mint, sol_amount) = if input_mint == WSOL_MINT {
+            (TradeDirection::Buy, output_mint, amount_in)
+        } else if output_mint == WSOL_MINT {
+            (TradeDirection::Sell, input_mint, amount_out)
+        } else {
+            return None;
+        };
+
+        Some(TradeInfo {
+            direction,
+            user_address: self.token_authority.to_string(),
+            token_mint: token_mint.to_string(),
+            sol_amount: sol_amount as f64 / 1_000_000_000.0,
+            platform: "OrcaWhirlpool".to_string(),
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            amount_in,
+            amount_out,
+        })
+    }
+}
+
+impl CopyTradeableEvent for OrcaWhirlpoolSwapEvent {
+    fn get_trade_info(&self) -> Option<TradeInfo> {
+        self.get_trade_info()
+    }
+}
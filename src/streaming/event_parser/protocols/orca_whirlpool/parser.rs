@@ -0,0 +1,544 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_event_parser_delegate,
+    streaming::event_parser::{
+        common::{
+            read_i32_le, read_u128_le, read_u64_le, read_u8_le, EventMetadata, EventType,
+            ProtocolType,
+        },
+        core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
+        protocols::orca_whirlpool::{
+            discriminators, OrcaWhirlpoolClosePositionEvent, OrcaWhirlpoolDecreaseLiquidityEvent,
+            OrcaWhirlpoolIncreaseLiquidityEvent, OrcaWhirlpoolOpenPositionEvent,
+            OrcaWhirlpoolSwapEvent, OrcaWhirlpoolTwoHopSwapEvent,
+        },
+    },
+};
+
+/// Orca Whirlpool程序ID
+pub const ORCA_WHIRLPOOL_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+/// Orca Whirlpool事件解析器
+pub struct OrcaWhirlpoolEventParser {
+    inner: GenericEventParser,
+}
+
+impl Default for OrcaWhirlpoolEventParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrcaWhirlpoolEventParser {
+    pub fn new() -> Self {
+        // 配置所有事件类型
+        let configs = vec![
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::SWAP,
+                event_type: EventType::OrcaWhirlpoolSwap,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_swap_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::SWAP_V2,
+                event_type: EventType::OrcaWhirlpoolSwapV2,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_swap_v2_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::TWO_HOP_SWAP,
+                event_type: EventType::OrcaWhirlpoolTwoHopSwap,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_two_hop_swap_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::TWO_HOP_SWAP_V2,
+                event_type: EventType::OrcaWhirlpoolTwoHopSwapV2,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_two_hop_swap_v2_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::INCREASE_LIQUIDITY,
+                event_type: EventType::OrcaWhirlpoolIncreaseLiquidity,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_increase_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::INCREASE_LIQUIDITY_V2,
+                event_type: EventType::OrcaWhirlpoolIncreaseLiquidity,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_increase_liquidity_v2_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::DECREASE_LIQUIDITY,
+                event_type: EventType::OrcaWhirlpoolDecreaseLiquidity,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_decrease_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::DECREASE_LIQUIDITY_V2,
+                event_type: EventType::OrcaWhirlpoolDecreaseLiquidity,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_decrease_liquidity_v2_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::OPEN_POSITION,
+                event_type: EventType::OrcaWhirlpoolOpenPosition,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_open_position_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::OPEN_POSITION_WITH_METADATA,
+                event_type: EventType::OrcaWhirlpoolOpenPosition,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_open_position_with_metadata_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::OPEN_POSITION_WITH_TOKEN_EXTENSIONS,
+                event_type: EventType::OrcaWhirlpoolOpenPosition,
+                inner_instruction_parser: None,
+                instruction_parser: Some(
+                    Self::parse_open_position_with_token_extensions_instruction,
+                ),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CLOSE_POSITION,
+                event_type: EventType::OrcaWhirlpoolClosePosition,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_close_position_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                protocol_type: ProtocolType::OrcaWhirlpool,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CLOSE_POSITION_WITH_TOKEN_EXTENSIONS,
+                event_type: EventType::OrcaWhirlpoolClosePosition,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_close_position_instruction),
+            },
+        ];
+
+        let inner = GenericEventParser::new(vec![ORCA_WHIRLPOOL_PROGRAM_ID], configs);
+
+        Self { inner }
+    }
+
+    /// 解析交易指令参数
+    fn parse_swap_args(data: &[u8]) -> Option<(u64, u64, u128, bool, bool)> {
+        if data.len() < 34 {
+            return None;
+        }
+        Some((
+            read_u64_le(data, 0)?,
+            read_u64_le(data, 8)?,
+            read_u128_le(data, 16)?,
+            read_u8_le(data, 32)? == 1,
+            read_u8_le(data, 33)? == 1,
+        ))
+    }
+
+    /// 解析交易指令事件
+    fn parse_swap_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 11 {
+            return None;
+        }
+        let (amount, other_amount_threshold, sqrt_price_limit, amount_specified_is_input, a_to_b) =
+            Self::parse_swap_args(data)?;
+
+        Some(Box::new(OrcaWhirlpoolSwapEvent {
+            metadata,
+            amount,
+            other_amount_threshold,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+            token_program_a: accounts[0],
+            token_program_b: accounts[0],
+            token_authority: accounts[1],
+            whirlpool: accounts[2],
+            token_owner_account_a: accounts[3],
+            token_vault_a: accounts[4],
+            token_owner_account_b: accounts[5],
+            token_vault_b: accounts[6],
+            tick_array0: accounts[7],
+            tick_array1: accounts[8],
+            tick_array2: accounts[9],
+            oracle: accounts[10],
+            remaining_accounts: accounts[11..].to_vec(),
+            ..Default::default()
+        }))
+    }
+
+    /// 解析交易v2指令事件
+    fn parse_swap_v2_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 15 {
+            return None;
+        }
+        let (amount, other_amount_threshold, sqrt_price_limit, amount_specified_is_input, a_to_b) =
+            Self::parse_swap_args(data)?;
+
+        Some(Box::new(OrcaWhirlpoolSwapEvent {
+            metadata,
+            amount,
+            other_amount_threshold,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+            token_program_a: accounts[0],
+            token_program_b: accounts[1],
+            memo_program: accounts[2],
+            token_authority: accounts[3],
+            whirlpool: accounts[4],
+            token_mint_a: accounts[5],
+            token_mint_b: accounts[6],
+            token_owner_account_a: accounts[7],
+            token_vault_a: accounts[8],
+            token_owner_account_b: accounts[9],
+            token_vault_b: accounts[10],
+            tick_array0: accounts[11],
+            tick_array1: accounts[12],
+            tick_array2: accounts[13],
+            oracle: accounts[14],
+            remaining_accounts: accounts[15..].to_vec(),
+        }))
+    }
+
+    /// 解析两跳交易指令参数
+    fn parse_two_hop_swap_args(data: &[u8]) -> Option<OrcaWhirlpoolTwoHopSwapEvent> {
+        if data.len() < 51 {
+            return None;
+        }
+        Some(OrcaWhirlpoolTwoHopSwapEvent {
+            amount: read_u64_le(data, 0)?,
+            other_amount_threshold: read_u64_le(data, 8)?,
+            amount_specified_is_input: read_u8_le(data, 16)? == 1,
+            a_to_b_one: read_u8_le(data, 17)? == 1,
+            a_to_b_two: read_u8_le(data, 18)? == 1,
+            sqrt_price_limit_one: read_u128_le(data, 19)?,
+            sqrt_price_limit_two: read_u128_le(data, 35)?,
+            ..Default::default()
+        })
+    }
+
+    /// 解析两跳交易指令事件，按方向选出输入/输出账户
+    fn parse_two_hop_swap_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 20 {
+            return None;
+        }
+        let args = Self::parse_two_hop_swap_args(data)?;
+        let (owner_input, vault_one_input, vault_one_intermediate) = if args.a_to_b_one {
+            (accounts[4], accounts[5], accounts[7])
+        } else {
+            (accounts[6], accounts[7], accounts[5])
+        };
+        let (owner_output, vault_two_intermediate, vault_two_output) = if args.a_to_b_two {
+            (accounts[10], accounts[9], accounts[11])
+        } else {
+            (accounts[8], accounts[11], accounts[9])
+        };
+
+        Some(Box::new(OrcaWhirlpoolTwoHopSwapEvent {
+            metadata,
+            token_authority: accounts[1],
+            whirlpool_one: accounts[2],
+            whirlpool_two: accounts[3],
+            token_owner_account_input: owner_input,
+            token_owner_account_output: owner_output,
+            token_vault_one_input: vault_one_input,
+            token_vault_one_intermediate: vault_one_intermediate,
+            token_vault_two_intermediate: vault_two_intermediate,
+            token_vault_two_output: vault_two_output,
+            oracle_one: accounts[18],
+            oracle_two: accounts[19],
+            remaining_accounts: accounts[20..].to_vec(),
+            ..args
+        }))
+    }
+
+    /// 解析两跳交易v2指令事件
+    fn parse_two_hop_swap_v2_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 24 {
+            return None;
+        }
+        let args = Self::parse_two_hop_swap_args(data)?;
+
+        Some(Box::new(OrcaWhirlpoolTwoHopSwapEvent {
+            metadata,
+            token_authority: accounts[14],
+            whirlpool_one: accounts[0],
+            whirlpool_two: accounts[1],
+            token_mint_input: accounts[2],
+            token_mint_intermediate: accounts[3],
+            token_mint_output: accounts[4],
+            token_owner_account_input: accounts[8],
+            token_vault_one_input: accounts[9],
+            token_vault_one_intermediate: accounts[10],
+            token_vault_two_intermediate: accounts[11],
+            token_vault_two_output: accounts[12],
+            token_owner_account_output: accounts[13],
+            oracle_one: accounts[21],
+            oracle_two: accounts[22],
+            remaining_accounts: accounts[24..].to_vec(),
+            ..args
+        }))
+    }
+
+    /// 解析增加流动性指令事件
+    fn parse_increase_liquidity_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 32 || accounts.len() < 11 {
+            return None;
+        }
+        Some(Box::new(OrcaWhirlpoolIncreaseLiquidityEvent {
+            metadata,
+            liquidity_amount: read_u128_le(data, 0)?,
+            token_max_a: read_u64_le(data, 16)?,
+            token_max_b: read_u64_le(data, 24)?,
+            whirlpool: accounts[0],
+            position_authority: accounts[2],
+            position: accounts[3],
+            position_token_account: accounts[4],
+            token_owner_account_a: accounts[5],
+            token_owner_account_b: accounts[6],
+            token_vault_a: accounts[7],
+            token_vault_b: accounts[8],
+            tick_array_lower: accounts[9],
+            tick_array_upper: accounts[10],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析增加流动性v2指令事件
+    fn parse_increase_liquidity_v2_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 32 || accounts.len() < 15 {
+            return None;
+        }
+        Some(Box::new(OrcaWhirlpoolIncreaseLiquidityEvent {
+            metadata,
+            liquidity_amount: read_u128_le(data, 0)?,
+            token_max_a: read_u64_le(data, 16)?,
+            token_max_b: read_u64_le(data, 24)?,
+            whirlpool: accounts[0],
+            position_authority: accounts[4],
+            position: accounts[5],
+            position_token_account: accounts[6],
+            token_mint_a: accounts[7],
+            token_mint_b: accounts[8],
+            token_owner_account_a: accounts[9],
+            token_owner_account_b: accounts[10],
+            token_vault_a: accounts[11],
+            token_vault_b: accounts[12],
+            tick_array_lower: accounts[13],
+            tick_array_upper: accounts[14],
+        }))
+    }
+
+    /// 解析减少流动性指令事件
+    fn parse_decrease_liquidity_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 32 || accounts.len() < 11 {
+            return None;
+        }
+        Some(Box::new(OrcaWhirlpoolDecreaseLiquidityEvent {
+            metadata,
+            liquidity_amount: read_u128_le(data, 0)?,
+            token_min_a: read_u64_le(data, 16)?,
+            token_min_b: read_u64_le(data, 24)?,
+            whirlpool: accounts[0],
+            position_authority: accounts[2],
+            position: accounts[3],
+            position_token_account: accounts[4],
+            token_owner_account_a: accounts[5],
+            token_owner_account_b: accounts[6],
+            token_vault_a: accounts[7],
+            token_vault_b: accounts[8],
+            tick_array_lower: accounts[9],
+            tick_array_upper: accounts[10],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析减少流动性v2指令事件
+    fn parse_decrease_liquidity_v2_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 32 || accounts.len() < 15 {
+            return None;
+        }
+        Some(Box::new(OrcaWhirlpoolDecreaseLiquidityEvent {
+            metadata,
+            liquidity_amount: read_u128_le(data, 0)?,
+            token_min_a: read_u64_le(data, 16)?,
+            token_min_b: read_u64_le(data, 24)?,
+            whirlpool: accounts[0],
+            position_authority: accounts[4],
+            position: accounts[5],
+            position_token_account: accounts[6],
+            token_mint_a: accounts[7],
+            token_mint_b: accounts[8],
+            token_owner_account_a: accounts[9],
+            token_owner_account_b: accounts[10],
+            token_vault_a: accounts[11],
+            token_vault_b: accounts[12],
+            tick_array_lower: accounts[13],
+            tick_array_upper: accounts[14],
+        }))
+    }
+
+    /// 解析开仓指令事件
+    fn parse_open_position_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 9 || accounts.len() < 7 {
+            return None;
+        }
+        Some(Box::new(OrcaWhirlpoolOpenPositionEvent {
+            metadata,
+            tick_lower_index: read_i32_le(data, 1)?,
+            tick_upper_index: read_i32_le(data, 5)?,
+            funder: accounts[0],
+            owner: accounts[1],
+            position: accounts[2],
+            position_mint: accounts[3],
+            position_token_account: accounts[4],
+            whirlpool: accounts[5],
+            token_program: accounts[6],
+        }))
+    }
+
+    /// 解析带元数据开仓指令事件
+    fn parse_open_position_with_metadata_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 10 || accounts.len() < 8 {
+            return None;
+        }
+        Some(Box::new(OrcaWhirlpoolOpenPositionEvent {
+            metadata,
+            tick_lower_index: read_i32_le(data, 2)?,
+            tick_upper_index: read_i32_le(data, 6)?,
+            funder: accounts[0],
+            owner: accounts[1],
+            position: accounts[2],
+            position_mint: accounts[3],
+            position_token_account: accounts[5],
+            whirlpool: accounts[6],
+            token_program: accounts[7],
+        }))
+    }
+
+    /// 解析 Token-2022 开仓指令事件
+    fn parse_open_position_with_token_extensions_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 8 || accounts.len() < 7 {
+            return None;
+        }
+        Some(Box::new(OrcaWhirlpoolOpenPositionEvent {
+            metadata,
+            tick_lower_index: read_i32_le(data, 0)?,
+            tick_upper_index: read_i32_le(data, 4)?,
+            funder: accounts[0],
+            owner: accounts[1],
+            position: accounts[2],
+            position_mint: accounts[3],
+            position_token_account: accounts[4],
+            whirlpool: accounts[5],
+            token_program: accounts[6],
+        }))
+    }
+
+    /// 解析关闭仓位指令事件
+    fn parse_close_position_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 6 {
+            return None;
+        }
+        Some(Box::new(OrcaWhirlpoolClosePositionEvent {
+            metadata,
+            position_authority: accounts[0],
+            receiver: accounts[1],
+            position: accounts[2],
+            position_mint: accounts[3],
+            position_token_account: accounts[4],
+            token_program: accounts[5],
+        }))
+    }
+}
+
+impl_event_parser_delegate!(OrcaWhirlpoolEventParser);
@@ -0,0 +1,213 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::EventMetadata,
+        protocols::orca_whirlpool::{
+            OrcaWhirlpoolAccountEvent, OrcaWhirlpoolTickArrayAccountEvent,
+        },
+        UnifiedEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WhirlpoolRewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub emissions_per_second_x64: u128,
+    pub growth_global_x64: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Whirlpool {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool_bump: [u8; 1],
+    pub tick_spacing: u16,
+    pub fee_tier_index_seed: [u8; 2],
+    pub fee_rate: u16,
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub protocol_fee_owed_a: u64,
+    pub protocol_fee_owed_b: u64,
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub fee_growth_global_a: u128,
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub fee_growth_global_b: u128,
+    pub reward_last_updated_timestamp: u64,
+    pub reward_infos: [WhirlpoolRewardInfo; 3],
+}
+
+pub const WHIRLPOOL_SIZE: usize = 645;
+
+pub fn whirlpool_decode(data: &[u8]) -> Option<Whirlpool> {
+    if data.len() < WHIRLPOOL_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Whirlpool>(&data[..WHIRLPOOL_SIZE]).ok()
+}
+
+pub fn whirlpool_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < WHIRLPOOL_SIZE + 8 {
+        return None;
+    }
+    let whirlpool = whirlpool_decode(&account.data[8..WHIRLPOOL_SIZE + 8])?;
+    Some(Box::new(OrcaWhirlpoolAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        whirlpool,
+    }))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Tick {
+    pub initialized: bool,
+    pub liquidity_net: i128,
+    pub liquidity_gross: u128,
+    pub fee_growth_outside_a: u128,
+    pub fee_growth_outside_b: u128,
+    pub reward_growths_outside: [u128; 3],
+}
+
+/// 每个TickArray包含的tick数量
+pub const TICKS_PER_ARRAY: usize = 88;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TickArray {
+    pub start_tick_index: i32,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub ticks: [Tick; TICKS_PER_ARRAY],
+    pub whirlpool: Pubkey,
+}
+
+impl Default for TickArray {
+    fn default() -> Self {
+        Self {
+            start_tick_index: 0,
+            ticks: core::array::from_fn(|_| Tick::default()),
+            whirlpool: Pubkey::default(),
+        }
+    }
+}
+
+pub const TICK_ARRAY_SIZE: usize = 9980;
+
+pub fn tick_array_decode(data: &[u8]) -> Option<TickArray> {
+    if data.len() < TICK_ARRAY_SIZE {
+        return None;
+    }
+    borsh::from_slice::<TickArray>(&data[..TICK_ARRAY_SIZE]).ok()
+}
+
+pub fn tick_array_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < TICK_ARRAY_SIZE + 8 {
+        return None;
+    }
+    let tick_array = tick_array_decode(&account.data[8..TICK_ARRAY_SIZE + 8])?;
+    Some(Box::new(OrcaWhirlpoolTickArrayAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        tick_array,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按账户布局偏移写入字段，偏移包含 8 字节鉴别器
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[8 + offset..8 + offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[test]
+    fn whirlpool_layout() {
+        let (config, mint_a, vault_a, mint_b, vault_b, reward_mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut data = vec![0u8; WHIRLPOOL_SIZE + 8];
+        put(&mut data, 0, config.as_ref());
+        put(&mut data, 33, &64u16.to_le_bytes());
+        put(&mut data, 37, &3_000u16.to_le_bytes());
+        put(&mut data, 41, &987_654_321u128.to_le_bytes());
+        put(&mut data, 57, &(1u128 << 64).to_le_bytes());
+        put(&mut data, 73, &(-18_421i32).to_le_bytes());
+        put(&mut data, 93, mint_a.as_ref());
+        put(&mut data, 125, vault_a.as_ref());
+        put(&mut data, 173, mint_b.as_ref());
+        put(&mut data, 205, vault_b.as_ref());
+        put(&mut data, 253, &1_760_000_000u64.to_le_bytes());
+        // 第三个奖励信息从偏移 261 + 2 * 128 开始
+        put(&mut data, 261 + 2 * 128, reward_mint.as_ref());
+        let account = AccountPretty { data, ..Default::default() };
+
+        let event = whirlpool_parser(&account, EventMetadata::default()).unwrap();
+        let whirlpool =
+            &event.as_any().downcast_ref::<OrcaWhirlpoolAccountEvent>().unwrap().whirlpool;
+        assert_eq!(whirlpool.whirlpools_config, config);
+        assert_eq!((whirlpool.tick_spacing, whirlpool.fee_rate), (64, 3_000));
+        assert_eq!(whirlpool.liquidity, 987_654_321);
+        assert_eq!(whirlpool.sqrt_price, 1u128 << 64);
+        assert_eq!(whirlpool.tick_current_index, -18_421);
+        assert_eq!((whirlpool.token_mint_a, whirlpool.token_vault_a), (mint_a, vault_a));
+        assert_eq!((whirlpool.token_mint_b, whirlpool.token_vault_b), (mint_b, vault_b));
+        assert_eq!(whirlpool.reward_last_updated_timestamp, 1_760_000_000);
+        assert_eq!(whirlpool.reward_infos[2].mint, reward_mint);
+    }
+
+    #[test]
+    fn tick_array_layout() {
+        let whirlpool = Pubkey::new_unique();
+        let mut data = vec![0u8; TICK_ARRAY_SIZE + 8];
+        put(&mut data, 0, &(-5_632i32).to_le_bytes());
+        // 每个 tick 113 字节，从偏移 4 开始
+        put(&mut data, 4, &[1]);
+        put(&mut data, 5, &(-1_000i128).to_le_bytes());
+        put(&mut data, 21, &1_000u128.to_le_bytes());
+        put(&mut data, 4 + 87 * 113, &[1]);
+        put(&mut data, 4 + 87 * 113 + 65 + 32, &7u128.to_le_bytes());
+        put(&mut data, 9948, whirlpool.as_ref());
+        let account = AccountPretty { data, ..Default::default() };
+
+        let event = tick_array_parser(&account, EventMetadata::default()).unwrap();
+        let tick_array = &event
+            .as_any()
+            .downcast_ref::<OrcaWhirlpoolTickArrayAccountEvent>()
+            .unwrap()
+            .tick_array;
+        assert_eq!(tick_array.start_tick_index, -5_632);
+        assert!(tick_array.ticks[0].initialized);
+        assert_eq!(tick_array.ticks[0].liquidity_net, -1_000);
+        assert_eq!(tick_array.ticks[0].liquidity_gross, 1_000);
+        assert!(!tick_array.ticks[1].initialized);
+        assert!(tick_array.ticks[87].initialized);
+        assert_eq!(tick_array.ticks[87].reward_growths_outside[2], 7);
+        assert_eq!(tick_array.whirlpool, whirlpool);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::impl_unified_event;
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::phoenix::types::{
+    AuditLogHeader, CancelOrderParams, EvictEvent, ExpiredOrderEvent, FeeEvent, FillEvent,
+    FillSummaryEvent, Market, OrderPacket, PlaceEvent, ReduceEvent, TimeInForceEvent,
+};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 吃单（Swap / SwapWithFreeFunds）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoenixSwapEvent {
+    pub metadata: EventMetadata,
+    pub order: OrderPacket,
+    pub with_free_funds: bool,
+    pub log_authority: Pubkey,
+    pub market: Pubkey,
+    pub trader: Pubkey,
+    // 仅 WithFreeFunds 指令
+    pub seat: Pubkey,
+    pub base_account: Pubkey,
+    pub quote_account: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+impl_unified_event!(PhoenixSwapEvent,);
+
+/// 挂单（PlaceLimitOrder / PlaceLimitOrderWithFreeFunds）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoenixPlaceLimitOrderEvent {
+    pub metadata: EventMetadata,
+    pub order: OrderPacket,
+    pub with_free_funds: bool,
+    pub log_authority: Pubkey,
+    pub market: Pubkey,
+    pub trader: Pubkey,
+    pub seat: Pubkey,
+    pub base_account: Pubkey,
+    pub quote_account: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+impl_unified_event!(PhoenixPlaceLimitOrderEvent,);
+
+/// 撤单（CancelAllOrders / CancelMultipleOrdersById 及其 WithFreeFunds 版本）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoenixCancelOrdersEvent {
+    pub metadata: EventMetadata,
+    /// 撤销全部订单时为空
+    pub orders: Vec<CancelOrderParams>,
+    pub with_free_funds: bool,
+    pub log_authority: Pubkey,
+    pub market: Pubkey,
+    pub trader: Pubkey,
+    pub base_account: Pubkey,
+    pub quote_account: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+impl_unified_event!(PhoenixCancelOrdersEvent,);
+
+/// 市场事件日志（Log 指令）：成交、挂单、撤单等
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoenixMarketLogEvent {
+    pub metadata: EventMetadata,
+    pub header: AuditLogHeader,
+    pub fills: Vec<FillEvent>,
+    pub places: Vec<PlaceEvent>,
+    pub reduces: Vec<ReduceEvent>,
+    pub evictions: Vec<EvictEvent>,
+    pub fill_summaries: Vec<FillSummaryEvent>,
+    pub fees: Vec<FeeEvent>,
+    pub time_in_force: Vec<TimeInForceEvent>,
+    pub expired_orders: Vec<ExpiredOrderEvent>,
+}
+impl_unified_event!(PhoenixMarketLogEvent,);
+
+/// 市场（含买卖盘最优价）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhoenixMarketAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub market: Market,
+}
+impl_unified_event!(PhoenixMarketAccountEvent,);
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器（单字节指令标签）
+    pub const SWAP: &[u8] = &[0];
+    pub const SWAP_WITH_FREE_FUNDS: &[u8] = &[1];
+    pub const PLACE_LIMIT_ORDER: &[u8] = &[2];
+    pub const PLACE_LIMIT_ORDER_WITH_FREE_FUNDS: &[u8] = &[3];
+    pub const CANCEL_ALL_ORDERS: &[u8] = &[6];
+    pub const CANCEL_ALL_ORDERS_WITH_FREE_FUNDS: &[u8] = &[7];
+    pub const CANCEL_MULTIPLE_ORDERS_BY_ID: &[u8] = &[10];
+    pub const CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS: &[u8] = &[11];
+    pub const LOG: &[u8] = &[15];
+
+    // 账号鉴别器
+    pub const MARKET: &[u8] = &[85, 153, 127, 98, 215, 115, 0, 175];
+}
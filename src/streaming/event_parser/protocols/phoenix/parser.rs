@@ -0,0 +1,331 @@
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_event_parser_delegate,
+    streaming::event_parser::{
+        common::{EventMetadata, EventType, ProtocolType},
+        core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
+        protocols::phoenix::{
+            discriminators,
+            types::{market_events_decode, order_packet_decode, CancelOrderParams, MarketEvent},
+            PhoenixCancelOrdersEvent, PhoenixMarketLogEvent, PhoenixPlaceLimitOrderEvent,
+            PhoenixSwapEvent,
+        },
+    },
+};
+
+/// Phoenix程序ID
+pub const PHOENIX_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("PhoeNiXZ8ByJGLkxNfZRnkUfjvmuYqLR89jjFHGqdXY");
+
+/// Phoenix事件解析器
+pub struct PhoenixEventParser {
+    inner: GenericEventParser,
+}
+
+impl Default for PhoenixEventParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoenixEventParser {
+    pub fn new() -> Self {
+        // 配置所有事件类型
+        let configs = vec![
+            GenericEventParseConfig {
+                program_id: PHOENIX_PROGRAM_ID,
+                protocol_type: ProtocolType::Phoenix,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::SWAP,
+                event_type: EventType::PhoenixSwap,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_swap_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PHOENIX_PROGRAM_ID,
+                protocol_type: ProtocolType::Phoenix,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::SWAP_WITH_FREE_FUNDS,
+                event_type: EventType::PhoenixSwap,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_swap_with_free_funds_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PHOENIX_PROGRAM_ID,
+                protocol_type: ProtocolType::Phoenix,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::PLACE_LIMIT_ORDER,
+                event_type: EventType::PhoenixPlaceLimitOrder,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_place_limit_order_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PHOENIX_PROGRAM_ID,
+                protocol_type: ProtocolType::Phoenix,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::PLACE_LIMIT_ORDER_WITH_FREE_FUNDS,
+                event_type: EventType::PhoenixPlaceLimitOrder,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_place_limit_order_with_free_funds_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PHOENIX_PROGRAM_ID,
+                protocol_type: ProtocolType::Phoenix,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CANCEL_ALL_ORDERS,
+                event_type: EventType::PhoenixCancelOrders,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_cancel_all_orders_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PHOENIX_PROGRAM_ID,
+                protocol_type: ProtocolType::Phoenix,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CANCEL_ALL_ORDERS_WITH_FREE_FUNDS,
+                event_type: EventType::PhoenixCancelOrders,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_cancel_all_orders_with_free_funds_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PHOENIX_PROGRAM_ID,
+                protocol_type: ProtocolType::Phoenix,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::CANCEL_MULTIPLE_ORDERS_BY_ID,
+                event_type: EventType::PhoenixCancelOrders,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_cancel_multiple_orders_by_id_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PHOENIX_PROGRAM_ID,
+                protocol_type: ProtocolType::Phoenix,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator:
+                    discriminators::CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS,
+                event_type: EventType::PhoenixCancelOrders,
+                inner_instruction_parser: None,
+                instruction_parser: Some(
+                    Self::parse_cancel_multiple_orders_by_id_with_free_funds_instruction,
+                ),
+            },
+            // 市场事件通过自调用 Log 指令发出
+            GenericEventParseConfig {
+                program_id: PHOENIX_PROGRAM_ID,
+                protocol_type: ProtocolType::Phoenix,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::LOG,
+                event_type: EventType::PhoenixMarketLog,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_log_instruction),
+            },
+        ];
+
+        let inner = GenericEventParser::new(vec![PHOENIX_PROGRAM_ID], configs);
+
+        Self { inner }
+    }
+
+    /// 解析吃单指令事件
+    fn parse_swap_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 9 {
+            return None;
+        }
+        Some(Box::new(PhoenixSwapEvent {
+            metadata,
+            order: order_packet_decode(data)?,
+            log_authority: accounts[1],
+            market: accounts[2],
+            trader: accounts[3],
+            base_account: accounts[4],
+            quote_account: accounts[5],
+            base_vault: accounts[6],
+            quote_vault: accounts[7],
+            token_program: accounts[8],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析使用已存入资金的吃单指令事件
+    fn parse_swap_with_free_funds_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 5 {
+            return None;
+        }
+        Some(Box::new(PhoenixSwapEvent {
+            metadata,
+            order: order_packet_decode(data)?,
+            with_free_funds: true,
+            log_authority: accounts[1],
+            market: accounts[2],
+            trader: accounts[3],
+            seat: accounts[4],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析挂单指令事件
+    fn parse_place_limit_order_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 10 {
+            return None;
+        }
+        Some(Box::new(PhoenixPlaceLimitOrderEvent {
+            metadata,
+            order: order_packet_decode(data)?,
+            with_free_funds: false,
+            log_authority: accounts[1],
+            market: accounts[2],
+            trader: accounts[3],
+            seat: accounts[4],
+            base_account: accounts[5],
+            quote_account: accounts[6],
+            base_vault: accounts[7],
+            quote_vault: accounts[8],
+            token_program: accounts[9],
+        }))
+    }
+
+    /// 解析使用已存入资金的挂单指令事件
+    fn parse_place_limit_order_with_free_funds_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 5 {
+            return None;
+        }
+        Some(Box::new(PhoenixPlaceLimitOrderEvent {
+            metadata,
+            order: order_packet_decode(data)?,
+            with_free_funds: true,
+            log_authority: accounts[1],
+            market: accounts[2],
+            trader: accounts[3],
+            seat: accounts[4],
+            ..Default::default()
+        }))
+    }
+
+    /// 撤单事件的账户布局
+    fn cancel_orders_event(
+        orders: Vec<CancelOrderParams>,
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 9 {
+            return None;
+        }
+        Some(Box::new(PhoenixCancelOrdersEvent {
+            metadata,
+            orders,
+            with_free_funds: false,
+            log_authority: accounts[1],
+            market: accounts[2],
+            trader: accounts[3],
+            base_account: accounts[4],
+            quote_account: accounts[5],
+            base_vault: accounts[6],
+            quote_vault: accounts[7],
+            token_program: accounts[8],
+        }))
+    }
+
+    /// 使用已存入资金的撤单事件的账户布局
+    fn cancel_orders_with_free_funds_event(
+        orders: Vec<CancelOrderParams>,
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 4 {
+            return None;
+        }
+        Some(Box::new(PhoenixCancelOrdersEvent {
+            metadata,
+            orders,
+            with_free_funds: true,
+            log_authority: accounts[1],
+            market: accounts[2],
+            trader: accounts[3],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析撤销全部订单指令事件
+    fn parse_cancel_all_orders_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        Self::cancel_orders_event(Vec::new(), accounts, metadata)
+    }
+
+    /// 解析使用已存入资金的撤销全部订单指令事件
+    fn parse_cancel_all_orders_with_free_funds_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        Self::cancel_orders_with_free_funds_event(Vec::new(), accounts, metadata)
+    }
+
+    /// 解析按ID撤单指令事件
+    fn parse_cancel_multiple_orders_by_id_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let orders = Vec::<CancelOrderParams>::deserialize(&mut &data[..]).ok()?;
+        Self::cancel_orders_event(orders, accounts, metadata)
+    }
+
+    /// 解析使用已存入资金的按ID撤单指令事件
+    fn parse_cancel_multiple_orders_by_id_with_free_funds_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let orders = Vec::<CancelOrderParams>::deserialize(&mut &data[..]).ok()?;
+        Self::cancel_orders_with_free_funds_event(orders, accounts, metadata)
+    }
+
+    /// 解析市场事件日志
+    fn parse_log_instruction(
+        data: &[u8],
+        _accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let mut events = market_events_decode(data).into_iter();
+        // 第一条必须是日志头
+        let Some(MarketEvent::Header(header)) = events.next() else {
+            return None;
+        };
+        let mut event = PhoenixMarketLogEvent { metadata, header, ..Default::default() };
+        for market_event in events {
+            match market_event {
+                MarketEvent::Fill(fill) => event.fills.push(fill),
+                MarketEvent::Place(place) => event.places.push(place),
+                MarketEvent::Reduce(reduce) => event.reduces.push(reduce),
+                MarketEvent::Evict(evict) => event.evictions.push(evict),
+                MarketEvent::FillSummary(summary) => event.fill_summaries.push(summary),
+                MarketEvent::Fee(fee) => event.fees.push(fee),
+                MarketEvent::TimeInForce(time_in_force) => event.time_in_force.push(time_in_force),
+                MarketEvent::ExpiredOrder(expired) => event.expired_orders.push(expired),
+                MarketEvent::Uninitialized | MarketEvent::Header(_) => {}
+            }
+        }
+        Some(Box::new(event))
+    }
+}
+
+impl_event_parser_delegate!(PhoenixEventParser);
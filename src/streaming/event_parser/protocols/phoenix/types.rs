@@ -0,0 +1,430 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::{read_u32_le, read_u64_le, EventMetadata},
+        protocols::phoenix::PhoenixMarketAccountEvent,
+        UnifiedEvent,
+    },
+    grpc::AccountPretty,
+};
+
+/// 0: Bid, 1: Ask
+pub const SIDE_BID: u8 = 0;
+pub const SIDE_ASK: u8 = 1;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MarketSizeParams {
+    pub bids_size: u64,
+    pub asks_size: u64,
+    pub num_seats: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TokenParams {
+    pub decimals: u32,
+    pub vault_bump: u32,
+    pub mint_key: Pubkey,
+    pub vault_key: Pubkey,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MarketHeader {
+    pub discriminant: u64,
+    pub status: u64,
+    pub market_size_params: MarketSizeParams,
+    pub base_params: TokenParams,
+    pub base_lot_size: u64,
+    pub quote_params: TokenParams,
+    pub quote_lot_size: u64,
+    pub tick_size_in_quote_atoms_per_base_unit: u64,
+    pub authority: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub market_sequence_number: u64,
+    pub successor: Pubkey,
+    pub raw_base_units_per_base_unit: u32,
+    pub padding1: u32,
+    pub padding2: [u64; 32],
+}
+
+pub const MARKET_HEADER_SIZE: usize = 576;
+
+/// 订单簿一侧的汇总：最优价及其挂单量
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookSide {
+    pub order_count: u64,
+    pub best_price_in_ticks: u64,
+    pub best_base_lots: u64,
+}
+
+/// 市场账户：头部、FIFO 市场参数和买卖盘最优价
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Market {
+    pub header: MarketHeader,
+    pub base_lots_per_base_unit: u64,
+    pub tick_size_in_quote_lots_per_base_unit: u64,
+    pub order_sequence_number: u64,
+    pub taker_fee_bps: u64,
+    pub collected_quote_lot_fees: u64,
+    pub unclaimed_quote_lot_fees: u64,
+    pub bids: BookSide,
+    pub asks: BookSide,
+}
+
+/// FIFOMarket 中 _padding([u64; 32]) 之后的参数及红黑树的偏移
+const FIFO_MARKET_PARAMS_OFFSET: usize = MARKET_HEADER_SIZE + 256;
+const BIDS_TREE_OFFSET: usize = FIFO_MARKET_PARAMS_OFFSET + 48;
+/// 红黑树头部：root(8) + padding(8) + size(8) + bump_index(4) + free_list_head(4)
+const TREE_HEADER_SIZE: usize = 32;
+/// 节点：registers([u32; 4]) + FIFOOrderId(16) + FIFORestingOrder(32)
+const TREE_NODE_SIZE: usize = 64;
+const REGISTER_LEFT: usize = 0;
+const REGISTER_RIGHT: usize = 4;
+
+fn tree_size(max_size: u64) -> usize {
+    TREE_HEADER_SIZE + max_size as usize * TREE_NODE_SIZE
+}
+
+/// 遍历红黑树求最优价（节点下标从 1 开始，0 为空）
+fn book_side_decode(data: &[u8], offset: usize, max_size: u64, is_bids: bool) -> Option<BookSide> {
+    let root = read_u64_le(data, offset)? as u32;
+    let order_count = read_u64_le(data, offset + 16)?;
+    let mut book_side = BookSide { order_count, ..Default::default() };
+    let mut found = false;
+    let mut stack = vec![root];
+    let mut visited = 0;
+    while let Some(index) = stack.pop() {
+        if index == 0 || index as u64 > max_size {
+            continue;
+        }
+        visited += 1;
+        if visited > max_size {
+            break;
+        }
+        let node = offset + TREE_HEADER_SIZE + (index as usize - 1) * TREE_NODE_SIZE;
+        stack.push(read_u32_le(data, node + REGISTER_LEFT)?);
+        stack.push(read_u32_le(data, node + REGISTER_RIGHT)?);
+        let price_in_ticks = read_u64_le(data, node + 16)?;
+        let base_lots = read_u64_le(data, node + 40)?;
+        let better = !found
+            || (is_bids && price_in_ticks > book_side.best_price_in_ticks)
+            || (!is_bids && price_in_ticks < book_side.best_price_in_ticks);
+        if better {
+            book_side.best_price_in_ticks = price_in_ticks;
+            book_side.best_base_lots = base_lots;
+            found = true;
+        } else if price_in_ticks == book_side.best_price_in_ticks {
+            book_side.best_base_lots += base_lots;
+        }
+    }
+    Some(book_side)
+}
+
+pub fn market_decode(data: &[u8]) -> Option<Market> {
+    if data.len() < BIDS_TREE_OFFSET {
+        return None;
+    }
+    let header = borsh::from_slice::<MarketHeader>(&data[..MARKET_HEADER_SIZE]).ok()?;
+    let bids_size = header.market_size_params.bids_size;
+    let asks_size = header.market_size_params.asks_size;
+    let asks_offset = BIDS_TREE_OFFSET + tree_size(bids_size);
+    if data.len() < asks_offset + tree_size(asks_size) {
+        return None;
+    }
+    Some(Market {
+        base_lots_per_base_unit: read_u64_le(data, FIFO_MARKET_PARAMS_OFFSET)?,
+        tick_size_in_quote_lots_per_base_unit: read_u64_le(data, FIFO_MARKET_PARAMS_OFFSET + 8)?,
+        order_sequence_number: read_u64_le(data, FIFO_MARKET_PARAMS_OFFSET + 16)?,
+        taker_fee_bps: read_u64_le(data, FIFO_MARKET_PARAMS_OFFSET + 24)?,
+        collected_quote_lot_fees: read_u64_le(data, FIFO_MARKET_PARAMS_OFFSET + 32)?,
+        unclaimed_quote_lot_fees: read_u64_le(data, FIFO_MARKET_PARAMS_OFFSET + 40)?,
+        bids: book_side_decode(data, BIDS_TREE_OFFSET, bids_size, true)?,
+        asks: book_side_decode(data, asks_offset, asks_size, false)?,
+        header,
+    })
+}
+
+pub fn market_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    // Phoenix 账户的鉴别器即 MarketHeader.discriminant，从头解析
+    let market = market_decode(&account.data)?;
+    Some(Box::new(PhoenixMarketAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        market,
+    }))
+}
+
+/// 订单参数（OrderPacket 的公共部分）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderPacket {
+    /// 0: PostOnly, 1: Limit, 2: ImmediateOrCancel
+    pub order_type: u8,
+    pub side: u8,
+    pub price_in_ticks: u64,
+    pub num_base_lots: u64,
+    pub num_quote_lots: u64,
+    pub client_order_id: u128,
+}
+
+pub const ORDER_TYPE_POST_ONLY: u8 = 0;
+pub const ORDER_TYPE_LIMIT: u8 = 1;
+pub const ORDER_TYPE_IMMEDIATE_OR_CANCEL: u8 = 2;
+
+fn read_option_u64(data: &[u8], offset: &mut usize) -> Option<u64> {
+    let has_value = *data.get(*offset)?;
+    *offset += 1;
+    if has_value == 0 {
+        return Some(0);
+    }
+    let value = read_u64_le(data, *offset)?;
+    *offset += 8;
+    Some(value)
+}
+
+/// 解析 OrderPacket，仅读取到 client_order_id 为止
+pub fn order_packet_decode(data: &[u8]) -> Option<OrderPacket> {
+    let order_type = *data.first()?;
+    let side = *data.get(1)?;
+    let mut packet = OrderPacket { order_type, side, ..Default::default() };
+    let mut offset = 2;
+    match order_type {
+        ORDER_TYPE_POST_ONLY => {
+            packet.price_in_ticks = read_u64_le(data, offset)?;
+            packet.num_base_lots = read_u64_le(data, offset + 8)?;
+            packet.client_order_id =
+                u128::try_from_slice(data.get(offset + 16..offset + 32)?).ok()?;
+        }
+        ORDER_TYPE_LIMIT => {
+            packet.price_in_ticks = read_u64_le(data, offset)?;
+            packet.num_base_lots = read_u64_le(data, offset + 8)?;
+            // self_trade_behavior(u8) + match_limit(Option<u64>)
+            offset += 17;
+            read_option_u64(data, &mut offset)?;
+            packet.client_order_id = u128::try_from_slice(data.get(offset..offset + 16)?).ok()?;
+        }
+        ORDER_TYPE_IMMEDIATE_OR_CANCEL => {
+            packet.price_in_ticks = read_option_u64(data, &mut offset)?;
+            packet.num_base_lots = read_u64_le(data, offset)?;
+            packet.num_quote_lots = read_u64_le(data, offset + 8)?;
+            // min_base_lots_to_fill + min_quote_lots_to_fill + self_trade_behavior + match_limit
+            offset += 33;
+            read_option_u64(data, &mut offset)?;
+            packet.client_order_id = u128::try_from_slice(data.get(offset..offset + 16)?).ok()?;
+        }
+        _ => return None,
+    }
+    Some(packet)
+}
+
+/// 按ID撤单参数
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CancelOrderParams {
+    pub side: u8,
+    pub price_in_ticks: u64,
+    pub order_sequence_number: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AuditLogHeader {
+    pub instruction: u8,
+    pub sequence_number: u64,
+    pub timestamp: i64,
+    pub slot: u64,
+    pub market: Pubkey,
+    pub signer: Pubkey,
+    pub total_events: u16,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FillEvent {
+    pub index: u16,
+    pub maker_id: Pubkey,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_filled: u64,
+    pub base_lots_remaining: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PlaceEvent {
+    pub index: u16,
+    pub order_sequence_number: u64,
+    pub client_order_id: u128,
+    pub price_in_ticks: u64,
+    pub base_lots_placed: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ReduceEvent {
+    pub index: u16,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_removed: u64,
+    pub base_lots_remaining: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvictEvent {
+    pub index: u16,
+    pub maker_id: Pubkey,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_evicted: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FillSummaryEvent {
+    pub index: u16,
+    pub client_order_id: u128,
+    pub total_base_lots_filled: u64,
+    pub total_quote_lots_filled: u64,
+    pub total_fee_in_quote_lots: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeEvent {
+    pub index: u16,
+    pub fees_collected_in_quote_lots: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TimeInForceEvent {
+    pub index: u16,
+    pub order_sequence_number: u64,
+    pub last_valid_slot: u64,
+    pub last_valid_unix_timestamp_in_seconds: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ExpiredOrderEvent {
+    pub index: u16,
+    pub maker_id: Pubkey,
+    pub order_sequence_number: u64,
+    pub price_in_ticks: u64,
+    pub base_lots_removed: u64,
+}
+
+/// Log 指令中的市场事件
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+pub enum MarketEvent {
+    Uninitialized,
+    Header(AuditLogHeader),
+    Fill(FillEvent),
+    Place(PlaceEvent),
+    Reduce(ReduceEvent),
+    Evict(EvictEvent),
+    FillSummary(FillSummaryEvent),
+    Fee(FeeEvent),
+    TimeInForce(TimeInForceEvent),
+    ExpiredOrder(ExpiredOrderEvent),
+}
+
+/// 依次解析 Log 指令数据中的市场事件，遇到无法识别的数据即停止
+pub fn market_events_decode(data: &[u8]) -> Vec<MarketEvent> {
+    let mut events = Vec::new();
+    let mut remaining = data;
+    while !remaining.is_empty() {
+        match MarketEvent::deserialize(&mut remaining) {
+            Ok(event) => events.push(event),
+            Err(_) => break,
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按账户布局偏移写入字段，Phoenix 市场账户没有单独的鉴别器
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    /// 写入红黑树节点：左右子节点、价格和挂单量
+    fn put_node(data: &mut [u8], tree: usize, index: u32, children: [u32; 2], order: [u64; 2]) {
+        let node = tree + TREE_HEADER_SIZE + (index as usize - 1) * TREE_NODE_SIZE;
+        put(data, node + REGISTER_LEFT, &children[0].to_le_bytes());
+        put(data, node + REGISTER_RIGHT, &children[1].to_le_bytes());
+        put(data, node + 16, &order[0].to_le_bytes());
+        put(data, node + 40, &order[1].to_le_bytes());
+    }
+
+    #[test]
+    fn market_header_layout() {
+        let (base_mint, quote_mint, authority, successor) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let tree_size = tree_size(4);
+        let mut data = vec![0u8; BIDS_TREE_OFFSET + 2 * tree_size];
+        put(&mut data, 0, &8_167_313_896_524_341_111u64.to_le_bytes());
+        put(&mut data, 8, &1u64.to_le_bytes());
+        put(&mut data, 16, &4u64.to_le_bytes());
+        put(&mut data, 24, &4u64.to_le_bytes());
+        put(&mut data, 32, &128u64.to_le_bytes());
+        put(&mut data, 40, &9u32.to_le_bytes());
+        put(&mut data, 48, base_mint.as_ref());
+        put(&mut data, 112, &1_000u64.to_le_bytes());
+        put(&mut data, 120, &6u32.to_le_bytes());
+        put(&mut data, 128, quote_mint.as_ref());
+        put(&mut data, 192, &10u64.to_le_bytes());
+        put(&mut data, 200, &1_000u64.to_le_bytes());
+        put(&mut data, 208, authority.as_ref());
+        put(&mut data, 272, &55u64.to_le_bytes());
+        put(&mut data, 280, successor.as_ref());
+        put(&mut data, 312, &1u32.to_le_bytes());
+        put(&mut data, FIFO_MARKET_PARAMS_OFFSET + 24, &2u64.to_le_bytes());
+
+        // 买盘两笔挂单，卖盘一笔
+        put(&mut data, BIDS_TREE_OFFSET, &1u64.to_le_bytes());
+        put(&mut data, BIDS_TREE_OFFSET + 16, &2u64.to_le_bytes());
+        put_node(&mut data, BIDS_TREE_OFFSET, 1, [2, 0], [100, 5]);
+        put_node(&mut data, BIDS_TREE_OFFSET, 2, [0, 0], [101, 3]);
+        let asks = BIDS_TREE_OFFSET + tree_size;
+        put(&mut data, asks, &1u64.to_le_bytes());
+        put(&mut data, asks + 16, &1u64.to_le_bytes());
+        put_node(&mut data, asks, 1, [0, 0], [105, 9]);
+        let account = AccountPretty { data: data.clone(), ..Default::default() };
+
+        let event = market_parser(&account, EventMetadata::default()).unwrap();
+        let market = &event.as_any().downcast_ref::<PhoenixMarketAccountEvent>().unwrap().market;
+        let header = &market.header;
+        assert_eq!((header.discriminant, header.status), (8_167_313_896_524_341_111, 1));
+        assert_eq!(
+            header.market_size_params,
+            MarketSizeParams { bids_size: 4, asks_size: 4, num_seats: 128 }
+        );
+        assert_eq!((header.base_params.decimals, header.base_params.mint_key), (9, base_mint));
+        assert_eq!((header.quote_params.decimals, header.quote_params.mint_key), (6, quote_mint));
+        assert_eq!((header.base_lot_size, header.quote_lot_size), (1_000, 10));
+        assert_eq!(header.tick_size_in_quote_atoms_per_base_unit, 1_000);
+        assert_eq!(header.authority, authority);
+        assert_eq!(header.market_sequence_number, 55);
+        assert_eq!(header.successor, successor);
+        assert_eq!(header.raw_base_units_per_base_unit, 1);
+        assert_eq!(market.taker_fee_bps, 2);
+        assert_eq!(
+            market.bids,
+            BookSide { order_count: 2, best_price_in_ticks: 101, best_base_lots: 3 }
+        );
+        assert_eq!(
+            market.asks,
+            BookSide { order_count: 1, best_price_in_ticks: 105, best_base_lots: 9 }
+        );
+
+        // 数据不足以容纳声明大小的买卖盘时不解析
+        assert!(market_decode(&data[..data.len() - 1]).is_none());
+    }
+}
@@ -0,0 +1,39 @@
+use crate::impl_unified_event;
+use crate::streaming::event_parser::common::EventMetadata;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 程序升级事件来源
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProgramUpgradeKind {
+    /// 可升级加载器 `Upgrade` 指令
+    #[default]
+    Upgrade,
+    /// 可升级加载器 `SetAuthority` / `SetAuthorityChecked` 指令
+    SetAuthority,
+    /// ProgramData 账户变更
+    ProgramData,
+}
+
+/// 协议程序升级事件
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramUpgradeEvent {
+    pub metadata: EventMetadata,
+    pub kind: ProgramUpgradeKind,
+    /// 被升级程序所属的协议
+    pub protocol: String,
+    pub program_id: Pubkey,
+    pub program_data: Pubkey,
+    /// `Upgrade` 为升级权限，`SetAuthority` 为新权限（None 表示设为不可升级），
+    /// ProgramData 为账户中的当前权限
+    pub authority: Option<Pubkey>,
+    /// `Upgrade` 使用的缓冲账户
+    pub buffer: Option<Pubkey>,
+    /// ProgramData 中记录的最近部署 slot（仅 ProgramData 事件）
+    pub deploy_slot: Option<u64>,
+    /// 新程序数据的 SHA-256（仅 ProgramData 事件）
+    pub data_hash: Option<String>,
+}
+
+// 使用macro生成UnifiedEvent实现
+impl_unified_event!(ProgramUpgradeEvent,);
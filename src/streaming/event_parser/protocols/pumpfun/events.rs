@@ -3,8 +3,10 @@ use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
 use crate::impl_unified_event;
-use crate::streaming::event_parser::common::EventMetadata;
-use crate::streaming::event_parser::protocols::pumpfun::types::{BondingCurve, Global};
+use crate::streaming::event_parser::common::{decode_versioned_layout, read_u32_le, EventMetadata};
+use crate::streaming::event_parser::protocols::pumpfun::types::{
+    BondingCurve, FeeConfig, Global, GlobalVolumeAccumulator, UserVolumeAccumulator,
+};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PumpFunCreateTokenEvent {
@@ -26,15 +28,30 @@ pub struct PumpFunCreateTokenEvent {
     pub mint_authority: Pubkey,
     #[borsh(skip)]
     pub associated_bonding_curve: Pubkey,
+    pub token_program: Pubkey,
+    pub is_mayhem_mode: bool,
+    /// 事件日志布局版本，从 1 开始（0 表示未解码到事件日志）
+    #[borsh(skip)]
+    pub layout_version: u8,
 }
 
+#[deprecated(note = "创建事件含变长字符串，按版本解码请使用 PUMPFUN_CREATE_TOKEN_EVENT_LOG_SIZES")]
 pub const PUMPFUN_CREATE_TOKEN_EVENT_LOG_SIZE: usize = 257;
 
+/// 创建事件各版本在三个字符串之后的大小：v1 至 `user`，v2 至 `token_total_supply`，
+/// v3 至 `is_mayhem_mode`
+pub const PUMPFUN_CREATE_TOKEN_EVENT_LOG_SIZES: [usize; 3] = [96, 168, 201];
+
 pub fn pumpfun_create_token_event_log_decode(data: &[u8]) -> Option<PumpFunCreateTokenEvent> {
-    if data.len() < PUMPFUN_CREATE_TOKEN_EVENT_LOG_SIZE {
-        return None;
+    // name / symbol / uri 为变长字符串，各版本大小从字符串之后开始计算
+    let mut strings_len = 0;
+    for _ in 0..3 {
+        strings_len += 4 + read_u32_le(data, strings_len)? as usize;
     }
-    borsh::from_slice::<PumpFunCreateTokenEvent>(&data[..PUMPFUN_CREATE_TOKEN_EVENT_LOG_SIZE]).ok()
+    let layout_sizes = PUMPFUN_CREATE_TOKEN_EVENT_LOG_SIZES.map(|size| strings_len + size);
+    let (event, layout_version) =
+        decode_versioned_layout::<PumpFunCreateTokenEvent>(data, &layout_sizes)?;
+    Some(PumpFunCreateTokenEvent { layout_version, ..event })
 }
 
 impl_unified_event!(
@@ -47,7 +64,8 @@ impl_unified_event!(
     virtual_token_reserves,
     virtual_sol_reserves,
     real_token_reserves,
-    token_total_supply
+    token_total_supply,
+    layout_version
 );
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
@@ -83,6 +101,10 @@ pub struct PumpFunTradeEvent {
     #[borsh(skip)]
     pub amount: u64,
     #[borsh(skip)]
+    pub spendable_sol_in: u64,
+    #[borsh(skip)]
+    pub min_tokens_out: u64,
+    #[borsh(skip)]
     pub is_bot: bool,
     #[borsh(skip)]
     pub is_dev_create_token_trade: bool, // 是否是dev创建token的交易
@@ -119,15 +141,21 @@ pub struct PumpFunTradeEvent {
     pub fee_config: Pubkey,
     #[borsh(skip)]
     pub fee_program: Pubkey,
+    /// 事件日志布局版本，从 1 开始（0 表示未解码到事件日志）
+    #[borsh(skip)]
+    pub layout_version: u8,
 }
 
 pub const PUMPFUN_TRADE_EVENT_LOG_SIZE: usize = 250;
 
+/// 交易事件各版本大小：v1 至 `real_token_reserves`，v2 增加手续费与创作者费用，
+/// v3 增加交易量统计
+pub const PUMPFUN_TRADE_EVENT_LOG_SIZES: &[usize] = &[121, 217, PUMPFUN_TRADE_EVENT_LOG_SIZE];
+
 pub fn pumpfun_trade_event_log_decode(data: &[u8]) -> Option<PumpFunTradeEvent> {
-    if data.len() < PUMPFUN_TRADE_EVENT_LOG_SIZE {
-        return None;
-    }
-    borsh::from_slice::<PumpFunTradeEvent>(&data[..PUMPFUN_TRADE_EVENT_LOG_SIZE]).ok()
+    let (event, layout_version) =
+        decode_versioned_layout::<PumpFunTradeEvent>(data, PUMPFUN_TRADE_EVENT_LOG_SIZES)?;
+    Some(PumpFunTradeEvent { layout_version, ..event })
 }
 
 impl_unified_event!(
@@ -147,25 +175,29 @@ impl_unified_event!(
     fee,
     creator,
     creator_fee_basis_points,
-    creator_fee
+    creator_fee,
+    track_volume,
+    total_unclaimed_tokens,
+    total_claimed_tokens,
+    current_sol_volume,
+    last_update_timestamp,
+    layout_version
 );
 
-use crate::streaming::event_parser::protocols::raydium_cpmm::types::{TradeDirection, TradeInfo, CopyTradeableEvent};
+use crate::streaming::event_parser::protocols::raydium_cpmm::types::{
+    CopyTradeableEvent, TradeDirection, TradeInfo,
+};
 
 impl PumpFunTradeEvent {
     /// Extract trade information with direction detection
     /// Always returns Some(TradeInfo) since PumpFun trades are always SOL<->Token
     pub fn get_trade_info(&self) -> Option<TradeInfo> {
-        let direction = if self.is_buy {
-            TradeDirection::Buy
-        } else {
-            TradeDirection::Sell
-        };
-        
+        let direction = if self.is_buy { TradeDirection::Buy } else { TradeDirection::Sell };
+
         let user_address = self.user.to_string();
         let token_mint = self.mint.to_string();
         let sol_amount = self.sol_amount as f64 / 1_000_000_000.0;
-        
+
         Some(TradeInfo {
             direction: direction.clone(),
             user_address,
@@ -279,6 +311,378 @@ impl_unified_event!(
     pool
 );
 
+/// 领取创作者费用
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunCollectCreatorFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub creator: Pubkey,
+    pub creator_fee: u64,
+    #[borsh(skip)]
+    pub creator_vault: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_COLLECT_CREATOR_FEE_EVENT_LOG_SIZE: usize = 48;
+
+pub fn pumpfun_collect_creator_fee_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunCollectCreatorFeeEvent> {
+    if data.len() < PUMPFUN_COLLECT_CREATOR_FEE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunCollectCreatorFeeEvent>(
+        &data[..PUMPFUN_COLLECT_CREATOR_FEE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(PumpFunCollectCreatorFeeEvent, timestamp, creator, creator_fee);
+
+/// 更新全局参数
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunSetParamsEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub final_real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    pub fee_basis_points: u64,
+    pub withdraw_authority: Pubkey,
+    pub enable_migrate: bool,
+    pub pool_migration_fee: u64,
+    pub creator_fee_basis_points: u64,
+    pub fee_recipients: [Pubkey; 8],
+    pub timestamp: i64,
+    pub set_creator_authority: Pubkey,
+    pub admin_set_creator_authority: Pubkey,
+    #[borsh(skip)]
+    pub global: Pubkey,
+    #[borsh(skip)]
+    pub authority: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_SET_PARAMS_EVENT_LOG_SIZE: usize = 425;
+
+pub fn pumpfun_set_params_event_log_decode(data: &[u8]) -> Option<PumpFunSetParamsEvent> {
+    if data.len() < PUMPFUN_SET_PARAMS_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunSetParamsEvent>(&data[..PUMPFUN_SET_PARAMS_EVENT_LOG_SIZE]).ok()
+}
+
+impl_unified_event!(
+    PumpFunSetParamsEvent,
+    initial_virtual_token_reserves,
+    initial_virtual_sol_reserves,
+    initial_real_token_reserves,
+    final_real_sol_reserves,
+    token_total_supply,
+    fee_basis_points,
+    withdraw_authority,
+    enable_migrate,
+    pool_migration_fee,
+    creator_fee_basis_points,
+    fee_recipients,
+    timestamp,
+    set_creator_authority,
+    admin_set_creator_authority
+);
+
+/// 扩容账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunExtendAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub account: Pubkey,
+    pub user: Pubkey,
+    pub current_size: u64,
+    pub new_size: u64,
+    pub timestamp: i64,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_EXTEND_ACCOUNT_EVENT_LOG_SIZE: usize = 88;
+
+pub fn pumpfun_extend_account_event_log_decode(data: &[u8]) -> Option<PumpFunExtendAccountEvent> {
+    if data.len() < PUMPFUN_EXTEND_ACCOUNT_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunExtendAccountEvent>(&data[..PUMPFUN_EXTEND_ACCOUNT_EVENT_LOG_SIZE])
+        .ok()
+}
+
+impl_unified_event!(PumpFunExtendAccountEvent, account, user, current_size, new_size, timestamp);
+
+/// 领取交易量激励代币
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunClaimTokenIncentivesEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    #[borsh(skip)]
+    pub user_ata: Pubkey,
+    #[borsh(skip)]
+    pub global_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub global_incentive_token_account: Pubkey,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub token_program: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub associated_token_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+    #[borsh(skip)]
+    pub payer: Pubkey,
+}
+
+pub const PUMPFUN_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE: usize = 96;
+
+pub fn pumpfun_claim_token_incentives_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunClaimTokenIncentivesEvent> {
+    if data.len() < PUMPFUN_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunClaimTokenIncentivesEvent>(
+        &data[..PUMPFUN_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    PumpFunClaimTokenIncentivesEvent,
+    user,
+    mint,
+    amount,
+    timestamp,
+    total_claimed_tokens,
+    current_sol_volume
+);
+
+/// 创建用户交易量累加器
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunInitUserVolumeAccumulatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub payer: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_INIT_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE: usize = 72;
+
+pub fn pumpfun_init_user_volume_accumulator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunInitUserVolumeAccumulatorEvent> {
+    if data.len() < PUMPFUN_INIT_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunInitUserVolumeAccumulatorEvent>(
+        &data[..PUMPFUN_INIT_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(PumpFunInitUserVolumeAccumulatorEvent, payer, user, timestamp);
+
+/// 关闭用户交易量累加器
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunCloseUserVolumeAccumulatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub user: Pubkey,
+    pub timestamp: i64,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_CLOSE_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE: usize = 72;
+
+pub fn pumpfun_close_user_volume_accumulator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunCloseUserVolumeAccumulatorEvent> {
+    if data.len() < PUMPFUN_CLOSE_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunCloseUserVolumeAccumulatorEvent>(
+        &data[..PUMPFUN_CLOSE_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    PumpFunCloseUserVolumeAccumulatorEvent,
+    user,
+    timestamp,
+    total_unclaimed_tokens,
+    total_claimed_tokens,
+    current_sol_volume,
+    last_update_timestamp
+);
+
+/// 同步用户交易量累加器
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunSyncUserVolumeAccumulatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub user: Pubkey,
+    pub total_claimed_tokens_before: u64,
+    pub total_claimed_tokens_after: u64,
+    pub timestamp: i64,
+    #[borsh(skip)]
+    pub global_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_SYNC_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE: usize = 56;
+
+pub fn pumpfun_sync_user_volume_accumulator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunSyncUserVolumeAccumulatorEvent> {
+    if data.len() < PUMPFUN_SYNC_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunSyncUserVolumeAccumulatorEvent>(
+        &data[..PUMPFUN_SYNC_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    PumpFunSyncUserVolumeAccumulatorEvent,
+    user,
+    total_claimed_tokens_before,
+    total_claimed_tokens_after,
+    timestamp
+);
+
+/// 设置代币创作者
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunSetCreatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub creator: Pubkey,
+    #[borsh(skip)]
+    pub set_creator_authority: Pubkey,
+    #[borsh(skip)]
+    pub global: Pubkey,
+    #[borsh(skip)]
+    pub mint_metadata: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_SET_CREATOR_EVENT_LOG_SIZE: usize = 104;
+
+pub fn pumpfun_set_creator_event_log_decode(data: &[u8]) -> Option<PumpFunSetCreatorEvent> {
+    if data.len() < PUMPFUN_SET_CREATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunSetCreatorEvent>(&data[..PUMPFUN_SET_CREATOR_EVENT_LOG_SIZE]).ok()
+}
+
+impl_unified_event!(PumpFunSetCreatorEvent, timestamp, mint, bonding_curve, creator);
+
+/// 管理员修改代币创作者
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunAdminSetCreatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub admin_set_creator_authority: Pubkey,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub old_creator: Pubkey,
+    pub new_creator: Pubkey,
+    #[borsh(skip)]
+    pub global: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_ADMIN_SET_CREATOR_EVENT_LOG_SIZE: usize = 168;
+
+pub fn pumpfun_admin_set_creator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunAdminSetCreatorEvent> {
+    if data.len() < PUMPFUN_ADMIN_SET_CREATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunAdminSetCreatorEvent>(
+        &data[..PUMPFUN_ADMIN_SET_CREATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    PumpFunAdminSetCreatorEvent,
+    timestamp,
+    admin_set_creator_authority,
+    mint,
+    bonding_curve,
+    old_creator,
+    new_creator
+);
+
 /// 铸币曲线
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PumpFunBondingCurveAccountEvent {
@@ -308,6 +712,45 @@ pub struct PumpFunGlobalAccountEvent {
 }
 impl_unified_event!(PumpFunGlobalAccountEvent,);
 
+/// 全局交易量累加器
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpFunGlobalVolumeAccumulatorAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub global_volume_accumulator: GlobalVolumeAccumulator,
+}
+impl_unified_event!(PumpFunGlobalVolumeAccumulatorAccountEvent,);
+
+/// 用户交易量累加器
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpFunUserVolumeAccumulatorAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub user_volume_accumulator: UserVolumeAccumulator,
+}
+impl_unified_event!(PumpFunUserVolumeAccumulatorAccountEvent,);
+
+/// 费率配置（归属 pump fees 程序）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpFunFeeConfigAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub fee_config: FeeConfig,
+}
+impl_unified_event!(PumpFunFeeConfigAccountEvent,);
+
 /// 事件鉴别器常量
 pub mod discriminators {
     // 事件鉴别器
@@ -320,14 +763,146 @@ pub mod discriminators {
     // pub const COMPLETE_PUMP_AMM_MIGRATION_EVENT: &str = "0xe445a52e51cb9a1dbde95db95c94ea94";
     pub const COMPLETE_PUMP_AMM_MIGRATION_EVENT: &[u8] =
         &[228, 69, 165, 46, 81, 203, 154, 29, 189, 233, 93, 185, 92, 148, 234, 148];
+    pub const COLLECT_CREATOR_FEE_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 122, 2, 127, 1, 14, 191, 12, 175];
+    pub const SET_PARAMS_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 223, 195, 159, 246, 62, 48, 143, 131];
+    pub const EXTEND_ACCOUNT_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 97, 97, 215, 144, 93, 146, 22, 124];
+    pub const CLAIM_TOKEN_INCENTIVES_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 79, 172, 246, 49, 205, 91, 206, 232];
+    pub const INIT_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 134, 36, 13, 72, 232, 101, 130, 216];
+    pub const CLOSE_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 146, 159, 189, 172, 146, 88, 56, 244];
+    pub const SYNC_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 197, 122, 167, 124, 116, 81, 91, 255];
+    pub const SET_CREATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 237, 52, 123, 37, 245, 251, 72, 210];
+    pub const ADMIN_SET_CREATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 64, 69, 192, 104, 29, 30, 25, 107];
 
     // 指令鉴别器
     pub const CREATE_TOKEN_IX: &[u8] = &[24, 30, 200, 40, 5, 28, 7, 119];
     pub const BUY_IX: &[u8] = &[102, 6, 61, 18, 1, 218, 235, 234];
     pub const SELL_IX: &[u8] = &[51, 230, 133, 164, 1, 127, 131, 173];
     pub const MIGRATE_IX: &[u8] = &[155, 234, 231, 146, 236, 158, 162, 30];
+    pub const CREATE_V2_IX: &[u8] = &[214, 144, 76, 236, 95, 139, 49, 180];
+    pub const BUY_EXACT_SOL_IN_IX: &[u8] = &[56, 252, 116, 8, 158, 223, 205, 95];
+    pub const COLLECT_CREATOR_FEE_IX: &[u8] = &[20, 22, 86, 123, 198, 28, 219, 132];
+    pub const SET_PARAMS_IX: &[u8] = &[27, 234, 178, 52, 147, 2, 187, 141];
+    pub const EXTEND_ACCOUNT_IX: &[u8] = &[234, 102, 194, 203, 150, 72, 62, 229];
+    pub const CLAIM_TOKEN_INCENTIVES_IX: &[u8] = &[16, 4, 71, 28, 204, 1, 40, 27];
+    pub const INIT_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[94, 6, 202, 115, 255, 96, 232, 183];
+    pub const CLOSE_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[249, 69, 164, 218, 150, 103, 84, 138];
+    pub const SYNC_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[86, 31, 192, 87, 163, 87, 79, 238];
+    pub const SET_CREATOR_IX: &[u8] = &[254, 148, 255, 112, 207, 142, 170, 165];
+    pub const ADMIN_SET_CREATOR_IX: &[u8] = &[69, 25, 171, 142, 57, 239, 13, 4];
 
     // 账户鉴别器
     pub const BONDING_CURVE_ACCOUNT: &[u8] = &[23, 183, 248, 55, 96, 216, 172, 96];
     pub const GLOBAL_ACCOUNT: &[u8] = &[167, 232, 232, 177, 200, 108, 114, 127];
+    pub const GLOBAL_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[202, 42, 246, 43, 142, 190, 30, 255];
+    pub const USER_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[86, 255, 112, 14, 102, 53, 154, 250];
+    pub const FEE_CONFIG_ACCOUNT: &[u8] = &[143, 52, 146, 187, 219, 123, 76, 155];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use std::str::FromStr;
+
+    // 按各版本事件布局构造的事件数据（不含鉴别器）的 base64
+    const TRADE_EVENT_V1: &str = concat!(
+        "3G8Xu+yCT/+Phlh5ZrIEfbarc2eFhAFR8T0dqxJOKlQAypo7AAAAAM4pzfF6HwAAAQT4mW2nY7epabECjuMA",
+        "dWnq86Y1SG3ashHVEshbnfj7AHjnaAAAAAAAdr43BwAAADLmClZosAMAAMqaOwAAAAAyTvgJ17ECAA==",
+    );
+    const TRADE_EVENT_V3: &str = concat!(
+        "3G8Xu+yCT/+Phlh5ZrIEfbarc2eFhAFR8T0dqxJOKlQAypo7AAAAAM4pzfF6HwAAAQT4mW2nY7epabECjuMA",
+        "dWnq86Y1SG3ashHVEshbnfj7AHjnaAAAAAAAdr43BwAAADLmClZosAMAAMqaOwAAAAAyTvgJ17ECAHzU7cR4",
+        "KCpSk00F0wxiaEaDYqv/O2Bh6IQSdvYWWOUGXwAAAAAAAABg9ZAAAAAAALxr/YSOvXgZyagr8STWXn9znQjg",
+        "AmAeI7uQaqzUCj2BBQAAAAAAAAAgoQcAAAAAAAE5MAAAAAAAAAAAAAAAAAAAAMqaOwAAAAAAeOdoAAAAAA==",
+    );
+    const CREATE_EVENT_V2: &str = concat!(
+        "CQAAAFRlc3QgQ29pbgQAAABURVNUGwAAAGh0dHBzOi8vaXBmcy5pby9pcGZzL1FtVGVzdNxvF7vsgk//j4ZY",
+        "eWayBH22q3NnhYQBUfE9HasSTipUUDt5899Fg3fhIJnGG2O+4gkApr1QrGIQNM2wyhcaoKQE+Jltp2O3qWmx",
+        "Ao7jAHVp6vOmNUht2rIR1RLIW534+7xr/YSOvXgZyagr8STWXn9znQjgAmAeI7uQaqzUCj2BAHjnaAAAAAAA",
+        "ENhH488DAACsI/wGAAAAAHjF+1HRAgAAgMakfo0DAA==",
+    );
+    const CREATE_EVENT_V3: &str = concat!(
+        "CQAAAFRlc3QgQ29pbgQAAABURVNUGwAAAGh0dHBzOi8vaXBmcy5pby9pcGZzL1FtVGVzdNxvF7vsgk//j4ZY",
+        "eWayBH22q3NnhYQBUfE9HasSTipUUDt5899Fg3fhIJnGG2O+4gkApr1QrGIQNM2wyhcaoKQE+Jltp2O3qWmx",
+        "Ao7jAHVp6vOmNUht2rIR1RLIW534+7xr/YSOvXgZyagr8STWXn9znQjgAmAeI7uQaqzUCj2BAHjnaAAAAAAA",
+        "ENhH488DAACsI/wGAAAAAHjF+1HRAgAAgMakfo0DAH+NYVYFTBgsaozTqD95EPGBPcOD0OM+weyNp4hpXgTt",
+        "AQ==",
+    );
+
+    #[test]
+    fn trade_event_decodes_each_layout_version() {
+        let v1 = STANDARD.decode(TRADE_EVENT_V1).unwrap();
+        let event = pumpfun_trade_event_log_decode(&v1).unwrap();
+        assert_eq!(event.layout_version, 1);
+        assert_eq!(
+            event.mint,
+            Pubkey::from_str("FqUwnBMN1shpeqKVm7W5fN73tvrjVr19TQFFgkoFFzhq").unwrap()
+        );
+        assert_eq!((event.sol_amount, event.token_amount), (1_000_000_000, 34_612_903_225_806));
+        assert!(event.is_buy);
+        assert_eq!(event.real_token_reserves, 758_487_096_774_194);
+        // 旧版本缺失的字段为零值
+        assert_eq!((event.fee, event.creator_fee, event.current_sol_volume), (0, 0, 0));
+        assert_eq!(event.creator, Pubkey::default());
+
+        let v3 = STANDARD.decode(TRADE_EVENT_V3).unwrap();
+        let event = pumpfun_trade_event_log_decode(&v3).unwrap();
+        assert_eq!(event.layout_version, 3);
+        assert_eq!((event.fee_basis_points, event.fee), (95, 9_500_000));
+        assert_eq!((event.creator_fee_basis_points, event.creator_fee), (5, 500_000));
+        assert_eq!(
+            event.creator,
+            Pubkey::from_str("DgX9xEoN7RZGWevFVCy13JuzKsnmAx9B3VLfvoJxwqKn").unwrap()
+        );
+        assert!(event.track_volume);
+        assert_eq!(event.total_unclaimed_tokens, 12_345);
+        assert_eq!(event.current_sol_volume, 1_000_000_000);
+        assert_eq!(event.last_update_timestamp, 1_760_000_000);
+
+        // 新增的尾部字段被忽略，版本记为最新版本之后
+        let mut newer = v3.clone();
+        newer.extend_from_slice(&[7; 8]);
+        let newer_event = pumpfun_trade_event_log_decode(&newer).unwrap();
+        assert_eq!(newer_event.layout_version, 4);
+        assert_eq!(PumpFunTradeEvent { layout_version: 3, ..newer_event }, event);
+
+        assert!(pumpfun_trade_event_log_decode(&v1[..v1.len() - 1]).is_none());
+    }
+
+    #[test]
+    fn create_token_event_decodes_after_variable_strings() {
+        let v2 = STANDARD.decode(CREATE_EVENT_V2).unwrap();
+        let event = pumpfun_create_token_event_log_decode(&v2).unwrap();
+        assert_eq!(event.layout_version, 2);
+        assert_eq!((event.name.as_str(), event.symbol.as_str()), ("Test Coin", "TEST"));
+        assert_eq!(event.uri, "https://ipfs.io/ipfs/QmTest");
+        assert_eq!(
+            event.user,
+            Pubkey::from_str("LQVcTQajEfHFgC7dJeWJ6R3uBsqZrSdp9rTzv344p4A").unwrap()
+        );
+        assert_eq!(event.timestamp, 1_760_000_000);
+        assert_eq!(event.token_total_supply, 1_000_000_000_000_000);
+        assert_eq!(event.token_program, Pubkey::default());
+        assert!(!event.is_mayhem_mode);
+
+        let v3 = STANDARD.decode(CREATE_EVENT_V3).unwrap();
+        let event = pumpfun_create_token_event_log_decode(&v3).unwrap();
+        assert_eq!(event.layout_version, 3);
+        assert_eq!(
+            event.token_program,
+            Pubkey::from_str("9aupGese1i6LsEtQFUGeR8j8HV9TrSoWc33ZceRpvVEL").unwrap()
+        );
+        assert!(event.is_mayhem_mode);
+
+        assert!(pumpfun_create_token_event_log_decode(&v2[..v2.len() - 80]).is_none());
+    }
 }
@@ -3,12 +3,26 @@ use solana_sdk::pubkey::Pubkey;
 use crate::{
     impl_event_parser_delegate,
     streaming::event_parser::{
-        common::{EventMetadata, EventType, ProtocolType},
+        common::{
+            read_borsh_string, read_pubkey, read_u64_le, read_u8, EventMetadata, EventType,
+            ProtocolType,
+        },
         core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
         protocols::pumpfun::{
-            discriminators, pumpfun_create_token_event_log_decode,
-            pumpfun_migrate_event_log_decode, pumpfun_trade_event_log_decode,
-            PumpFunCreateTokenEvent, PumpFunMigrateEvent, PumpFunTradeEvent,
+            discriminators, pumpfun_admin_set_creator_event_log_decode,
+            pumpfun_claim_token_incentives_event_log_decode,
+            pumpfun_close_user_volume_accumulator_event_log_decode,
+            pumpfun_collect_creator_fee_event_log_decode, pumpfun_create_token_event_log_decode,
+            pumpfun_extend_account_event_log_decode,
+            pumpfun_init_user_volume_accumulator_event_log_decode,
+            pumpfun_migrate_event_log_decode, pumpfun_set_creator_event_log_decode,
+            pumpfun_set_params_event_log_decode,
+            pumpfun_sync_user_volume_accumulator_event_log_decode, pumpfun_trade_event_log_decode,
+            PumpFunAdminSetCreatorEvent, PumpFunClaimTokenIncentivesEvent,
+            PumpFunCloseUserVolumeAccumulatorEvent, PumpFunCollectCreatorFeeEvent,
+            PumpFunCreateTokenEvent, PumpFunExtendAccountEvent,
+            PumpFunInitUserVolumeAccumulatorEvent, PumpFunMigrateEvent, PumpFunSetCreatorEvent,
+            PumpFunSetParamsEvent, PumpFunSyncUserVolumeAccumulatorEvent, PumpFunTradeEvent,
         },
     },
 };
@@ -17,6 +31,10 @@ use crate::{
 pub const PUMPFUN_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
 
+/// Pump 费率程序ID（FeeConfig 账户的所有者）
+pub const PUMP_FEES_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("pfeeUxB6jkeY1Hxd7CsFCAjcbHA9rWtchMGdZ6VojVZ");
+
 /// PumpFun事件解析器
 pub struct PumpFunEventParser {
     inner: GenericEventParser,
@@ -68,6 +86,114 @@ impl PumpFunEventParser {
                 inner_instruction_parser: Some(Self::parse_migrate_inner_instruction),
                 instruction_parser: Some(Self::parse_migrate_instruction),
             },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator: discriminators::CREATE_TOKEN_EVENT,
+                instruction_discriminator: discriminators::CREATE_V2_IX,
+                event_type: EventType::PumpFunCreateTokenV2,
+                inner_instruction_parser: Some(Self::parse_create_token_inner_instruction),
+                instruction_parser: Some(Self::parse_create_v2_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator: discriminators::TRADE_EVENT,
+                instruction_discriminator: discriminators::BUY_EXACT_SOL_IN_IX,
+                event_type: EventType::PumpFunBuyExactSolIn,
+                inner_instruction_parser: Some(Self::parse_trade_inner_instruction),
+                instruction_parser: Some(Self::parse_buy_exact_sol_in_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator: discriminators::COLLECT_CREATOR_FEE_EVENT,
+                instruction_discriminator: discriminators::COLLECT_CREATOR_FEE_IX,
+                event_type: EventType::PumpFunCollectCreatorFee,
+                inner_instruction_parser: Some(Self::parse_collect_creator_fee_inner_instruction),
+                instruction_parser: Some(Self::parse_collect_creator_fee_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator: discriminators::SET_PARAMS_EVENT,
+                instruction_discriminator: discriminators::SET_PARAMS_IX,
+                event_type: EventType::PumpFunSetParams,
+                inner_instruction_parser: Some(Self::parse_set_params_inner_instruction),
+                instruction_parser: Some(Self::parse_set_params_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator: discriminators::EXTEND_ACCOUNT_EVENT,
+                instruction_discriminator: discriminators::EXTEND_ACCOUNT_IX,
+                event_type: EventType::PumpFunExtendAccount,
+                inner_instruction_parser: Some(Self::parse_extend_account_inner_instruction),
+                instruction_parser: Some(Self::parse_extend_account_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator: discriminators::CLAIM_TOKEN_INCENTIVES_EVENT,
+                instruction_discriminator: discriminators::CLAIM_TOKEN_INCENTIVES_IX,
+                event_type: EventType::PumpFunClaimTokenIncentives,
+                inner_instruction_parser: Some(
+                    Self::parse_claim_token_incentives_inner_instruction,
+                ),
+                instruction_parser: Some(Self::parse_claim_token_incentives_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator: discriminators::INIT_USER_VOLUME_ACCUMULATOR_EVENT,
+                instruction_discriminator: discriminators::INIT_USER_VOLUME_ACCUMULATOR_IX,
+                event_type: EventType::PumpFunInitUserVolumeAccumulator,
+                inner_instruction_parser: Some(
+                    Self::parse_init_user_volume_accumulator_inner_instruction,
+                ),
+                instruction_parser: Some(Self::parse_init_user_volume_accumulator_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator:
+                    discriminators::CLOSE_USER_VOLUME_ACCUMULATOR_EVENT,
+                instruction_discriminator: discriminators::CLOSE_USER_VOLUME_ACCUMULATOR_IX,
+                event_type: EventType::PumpFunCloseUserVolumeAccumulator,
+                inner_instruction_parser: Some(
+                    Self::parse_close_user_volume_accumulator_inner_instruction,
+                ),
+                instruction_parser: Some(Self::parse_close_user_volume_accumulator_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator: discriminators::SYNC_USER_VOLUME_ACCUMULATOR_EVENT,
+                instruction_discriminator: discriminators::SYNC_USER_VOLUME_ACCUMULATOR_IX,
+                event_type: EventType::PumpFunSyncUserVolumeAccumulator,
+                inner_instruction_parser: Some(
+                    Self::parse_sync_user_volume_accumulator_inner_instruction,
+                ),
+                instruction_parser: Some(Self::parse_sync_user_volume_accumulator_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator: discriminators::SET_CREATOR_EVENT,
+                instruction_discriminator: discriminators::SET_CREATOR_IX,
+                event_type: EventType::PumpFunSetCreator,
+                inner_instruction_parser: Some(Self::parse_set_creator_inner_instruction),
+                instruction_parser: Some(Self::parse_set_creator_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPFUN_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpFun,
+                inner_instruction_discriminator: discriminators::ADMIN_SET_CREATOR_EVENT,
+                instruction_discriminator: discriminators::ADMIN_SET_CREATOR_IX,
+                event_type: EventType::PumpFunAdminSetCreator,
+                inner_instruction_parser: Some(Self::parse_admin_set_creator_inner_instruction),
+                instruction_parser: Some(Self::parse_admin_set_creator_instruction),
+            },
         ];
 
         let inner = GenericEventParser::new(vec![PUMPFUN_PROGRAM_ID], configs);
@@ -111,6 +237,344 @@ impl PumpFunEventParser {
         }
     }
 
+    /// 解析领取创作者费用日志事件
+    fn parse_collect_creator_fee_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pumpfun_collect_creator_fee_event_log_decode(data)?;
+        Some(Box::new(PumpFunCollectCreatorFeeEvent { metadata, ..event }))
+    }
+
+    /// 解析更新全局参数日志事件
+    fn parse_set_params_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pumpfun_set_params_event_log_decode(data)?;
+        Some(Box::new(PumpFunSetParamsEvent { metadata, ..event }))
+    }
+
+    /// 解析扩容账户日志事件
+    fn parse_extend_account_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pumpfun_extend_account_event_log_decode(data)?;
+        Some(Box::new(PumpFunExtendAccountEvent { metadata, ..event }))
+    }
+
+    /// 解析领取激励代币日志事件
+    fn parse_claim_token_incentives_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pumpfun_claim_token_incentives_event_log_decode(data)?;
+        Some(Box::new(PumpFunClaimTokenIncentivesEvent { metadata, ..event }))
+    }
+
+    /// 解析创建用户交易量累加器日志事件
+    fn parse_init_user_volume_accumulator_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pumpfun_init_user_volume_accumulator_event_log_decode(data)?;
+        Some(Box::new(PumpFunInitUserVolumeAccumulatorEvent { metadata, ..event }))
+    }
+
+    /// 解析关闭用户交易量累加器日志事件
+    fn parse_close_user_volume_accumulator_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pumpfun_close_user_volume_accumulator_event_log_decode(data)?;
+        Some(Box::new(PumpFunCloseUserVolumeAccumulatorEvent { metadata, ..event }))
+    }
+
+    /// 解析同步用户交易量累加器日志事件
+    fn parse_sync_user_volume_accumulator_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pumpfun_sync_user_volume_accumulator_event_log_decode(data)?;
+        Some(Box::new(PumpFunSyncUserVolumeAccumulatorEvent { metadata, ..event }))
+    }
+
+    /// 解析设置创作者日志事件
+    fn parse_set_creator_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pumpfun_set_creator_event_log_decode(data)?;
+        Some(Box::new(PumpFunSetCreatorEvent { metadata, ..event }))
+    }
+
+    /// 解析管理员修改创作者日志事件
+    fn parse_admin_set_creator_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pumpfun_admin_set_creator_event_log_decode(data)?;
+        Some(Box::new(PumpFunAdminSetCreatorEvent { metadata, ..event }))
+    }
+    /// 解析 Token-2022 创建代币指令事件
+    fn parse_create_v2_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 8 {
+            return None;
+        }
+        let mut offset = 0;
+        let name = read_borsh_string(data, &mut offset)?;
+        let symbol = read_borsh_string(data, &mut offset)?;
+        let uri = read_borsh_string(data, &mut offset)?;
+        let creator = read_pubkey(data, offset)?;
+        let is_mayhem_mode = read_u8(data, offset + 32).unwrap_or(0) != 0;
+        Some(Box::new(PumpFunCreateTokenEvent {
+            metadata,
+            name,
+            symbol,
+            uri,
+            creator,
+            mint: accounts[0],
+            mint_authority: accounts[1],
+            bonding_curve: accounts[2],
+            associated_bonding_curve: accounts[3],
+            user: accounts[5],
+            token_program: accounts[7],
+            is_mayhem_mode,
+            ..Default::default()
+        }))
+    }
+
+    /// 解析按 SOL 数量买入指令事件
+    fn parse_buy_exact_sol_in_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 16 || accounts.len() < 13 {
+            return None;
+        }
+        Some(Box::new(PumpFunTradeEvent {
+            metadata,
+            global: accounts[0],
+            fee_recipient: accounts[1],
+            mint: accounts[2],
+            bonding_curve: accounts[3],
+            associated_bonding_curve: accounts[4],
+            associated_user: accounts[5],
+            user: accounts[6],
+            system_program: accounts[7],
+            token_program: accounts[8],
+            creator_vault: accounts[9],
+            event_authority: accounts[10],
+            program: accounts[11],
+            global_volume_accumulator: accounts[12],
+            user_volume_accumulator: accounts.get(13).copied().unwrap_or_default(),
+            fee_config: accounts.get(14).copied().unwrap_or_default(),
+            fee_program: accounts.get(15).copied().unwrap_or_default(),
+            spendable_sol_in: read_u64_le(data, 0)?,
+            min_tokens_out: read_u64_le(data, 8)?,
+            is_buy: true,
+            ..Default::default()
+        }))
+    }
+
+    /// 解析领取创作者费用指令事件
+    fn parse_collect_creator_fee_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 5 {
+            return None;
+        }
+        Some(Box::new(PumpFunCollectCreatorFeeEvent {
+            metadata,
+            creator: accounts[0],
+            creator_vault: accounts[1],
+            system_program: accounts[2],
+            event_authority: accounts[3],
+            program: accounts[4],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析更新全局参数指令事件（参数以日志事件为准）
+    fn parse_set_params_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 4 {
+            return None;
+        }
+        Some(Box::new(PumpFunSetParamsEvent {
+            metadata,
+            global: accounts[0],
+            authority: accounts[1],
+            event_authority: accounts[2],
+            program: accounts[3],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析扩容账户指令事件
+    fn parse_extend_account_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 5 {
+            return None;
+        }
+        Some(Box::new(PumpFunExtendAccountEvent {
+            metadata,
+            account: accounts[0],
+            user: accounts[1],
+            system_program: accounts[2],
+            event_authority: accounts[3],
+            program: accounts[4],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析领取激励代币指令事件
+    fn parse_claim_token_incentives_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 12 {
+            return None;
+        }
+        Some(Box::new(PumpFunClaimTokenIncentivesEvent {
+            metadata,
+            user: accounts[0],
+            user_ata: accounts[1],
+            global_volume_accumulator: accounts[2],
+            global_incentive_token_account: accounts[3],
+            user_volume_accumulator: accounts[4],
+            mint: accounts[5],
+            token_program: accounts[6],
+            system_program: accounts[7],
+            associated_token_program: accounts[8],
+            event_authority: accounts[9],
+            program: accounts[10],
+            payer: accounts[11],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析创建用户交易量累加器指令事件
+    fn parse_init_user_volume_accumulator_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 6 {
+            return None;
+        }
+        Some(Box::new(PumpFunInitUserVolumeAccumulatorEvent {
+            metadata,
+            payer: accounts[0],
+            user: accounts[1],
+            user_volume_accumulator: accounts[2],
+            system_program: accounts[3],
+            event_authority: accounts[4],
+            program: accounts[5],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析关闭用户交易量累加器指令事件
+    fn parse_close_user_volume_accumulator_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 4 {
+            return None;
+        }
+        Some(Box::new(PumpFunCloseUserVolumeAccumulatorEvent {
+            metadata,
+            user: accounts[0],
+            user_volume_accumulator: accounts[1],
+            event_authority: accounts[2],
+            program: accounts[3],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析同步用户交易量累加器指令事件
+    fn parse_sync_user_volume_accumulator_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 5 {
+            return None;
+        }
+        Some(Box::new(PumpFunSyncUserVolumeAccumulatorEvent {
+            metadata,
+            user: accounts[0],
+            global_volume_accumulator: accounts[1],
+            user_volume_accumulator: accounts[2],
+            event_authority: accounts[3],
+            program: accounts[4],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析设置创作者指令事件
+    fn parse_set_creator_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 8 {
+            return None;
+        }
+        Some(Box::new(PumpFunSetCreatorEvent {
+            metadata,
+            creator: read_pubkey(data, 0)?,
+            set_creator_authority: accounts[0],
+            global: accounts[1],
+            mint: accounts[2],
+            mint_metadata: accounts[3],
+            bonding_curve: accounts[4],
+            system_program: accounts[5],
+            event_authority: accounts[6],
+            program: accounts[7],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析管理员修改创作者指令事件
+    fn parse_admin_set_creator_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 6 {
+            return None;
+        }
+        Some(Box::new(PumpFunAdminSetCreatorEvent {
+            metadata,
+            new_creator: read_pubkey(data, 0)?,
+            admin_set_creator_authority: accounts[0],
+            global: accounts[1],
+            mint: accounts[2],
+            bonding_curve: accounts[3],
+            event_authority: accounts[4],
+            program: accounts[5],
+            ..Default::default()
+        }))
+    }
+
     /// 解析创建代币指令事件
     fn parse_create_token_instruction(
         data: &[u8],
@@ -165,7 +629,7 @@ impl PumpFunEventParser {
         }
         let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
         let max_sol_cost = u64::from_le_bytes(data[8..16].try_into().unwrap());
-        
+
         // 🛡️ CRITICAL FIX: Use safe array access to prevent out-of-bounds panic
         Some(Box::new(PumpFunTradeEvent {
             metadata,
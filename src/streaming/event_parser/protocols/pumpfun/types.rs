@@ -1,3 +1,5 @@
+use std::sync::LazyLock;
+
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -5,7 +7,12 @@ use solana_sdk::pubkey::Pubkey;
 use crate::streaming::{
     event_parser::{
         common::EventMetadata,
-        protocols::pumpfun::{PumpFunBondingCurveAccountEvent, PumpFunGlobalAccountEvent},
+        protocols::pumpfun::{
+            parser::{PUMPFUN_PROGRAM_ID, PUMP_FEES_PROGRAM_ID},
+            PumpFunBondingCurveAccountEvent, PumpFunFeeConfigAccountEvent,
+            PumpFunGlobalAccountEvent, PumpFunGlobalVolumeAccumulatorAccountEvent,
+            PumpFunUserVolumeAccumulatorAccountEvent,
+        },
         UnifiedEvent,
     },
     grpc::AccountPretty,
@@ -102,3 +109,137 @@ pub fn global_parser(
         None
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct GlobalVolumeAccumulator {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub seconds_in_a_day: i64,
+    pub mint: Pubkey,
+    pub total_token_supply: [u64; 30],
+    pub sol_volumes: [u64; 30],
+}
+
+pub const GLOBAL_VOLUME_ACCUMULATOR_SIZE: usize = 8 * 3 + 32 + 8 * 30 * 2;
+
+pub fn global_volume_accumulator_decode(data: &[u8]) -> Option<GlobalVolumeAccumulator> {
+    if data.len() < GLOBAL_VOLUME_ACCUMULATOR_SIZE {
+        return None;
+    }
+    borsh::from_slice::<GlobalVolumeAccumulator>(&data[..GLOBAL_VOLUME_ACCUMULATOR_SIZE]).ok()
+}
+
+pub fn global_volume_accumulator_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < GLOBAL_VOLUME_ACCUMULATOR_SIZE + 8 {
+        return None;
+    }
+    let global_volume_accumulator =
+        global_volume_accumulator_decode(&account.data[8..GLOBAL_VOLUME_ACCUMULATOR_SIZE + 8])?;
+    Some(Box::new(PumpFunGlobalVolumeAccumulatorAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        global_volume_accumulator,
+    }))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UserVolumeAccumulator {
+    pub user: Pubkey,
+    pub needs_claim: bool,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+    pub has_total_claimed_tokens: bool,
+}
+
+pub const USER_VOLUME_ACCUMULATOR_SIZE: usize = 32 + 1 + 8 * 4 + 1;
+
+pub fn user_volume_accumulator_decode(data: &[u8]) -> Option<UserVolumeAccumulator> {
+    if data.len() < USER_VOLUME_ACCUMULATOR_SIZE {
+        return None;
+    }
+    borsh::from_slice::<UserVolumeAccumulator>(&data[..USER_VOLUME_ACCUMULATOR_SIZE]).ok()
+}
+
+pub fn user_volume_accumulator_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < USER_VOLUME_ACCUMULATOR_SIZE + 8 {
+        return None;
+    }
+    let user_volume_accumulator =
+        user_volume_accumulator_decode(&account.data[8..USER_VOLUME_ACCUMULATOR_SIZE + 8])?;
+    Some(Box::new(PumpFunUserVolumeAccumulatorAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        user_volume_accumulator,
+    }))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Fees {
+    pub lp_fee_bps: u64,
+    pub protocol_fee_bps: u64,
+    pub creator_fee_bps: u64,
+}
+
+/// 按市值分档的费率
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeTier {
+    pub market_cap_lamports_threshold: u128,
+    pub fees: Fees,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeConfig {
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub flat_fees: Fees,
+    pub fee_tiers: Vec<FeeTier>,
+}
+
+/// 费率档位为变长数组，账户末尾可能有未使用空间，不要求读完
+pub fn fee_config_decode(data: &[u8]) -> Option<FeeConfig> {
+    <FeeConfig as BorshDeserialize>::deserialize(&mut &data[..]).ok()
+}
+
+/// PumpFun 在 pump fees 程序下的费率配置 PDA，用于与 PumpSwap 的费率配置区分
+pub static PUMPFUN_FEE_CONFIG: LazyLock<Pubkey> = LazyLock::new(|| {
+    Pubkey::find_program_address(
+        &[b"fee_config", PUMPFUN_PROGRAM_ID.as_ref()],
+        &PUMP_FEES_PROGRAM_ID,
+    )
+    .0
+});
+
+pub fn fee_config_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.pubkey != *PUMPFUN_FEE_CONFIG || account.data.len() < 8 {
+        return None;
+    }
+    let fee_config = fee_config_decode(&account.data[8..])?;
+    Some(Box::new(PumpFunFeeConfigAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        fee_config,
+    }))
+}
@@ -3,7 +3,10 @@ use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
 use crate::impl_unified_event;
-use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::common::{decode_versioned_layout, EventMetadata};
+use crate::streaming::event_parser::protocols::pumpfun::types::{
+    FeeConfig, GlobalVolumeAccumulator, UserVolumeAccumulator,
+};
 use crate::streaming::event_parser::protocols::pumpswap::types::{GlobalConfig, Pool};
 
 /// 买入事件
@@ -51,15 +54,21 @@ pub struct PumpSwapBuyEvent {
     pub fee_config: Pubkey,
     #[borsh(skip)]
     pub fee_program: Pubkey,
+    /// 事件日志布局版本，从 1 开始（0 表示未解码到事件日志）
+    #[borsh(skip)]
+    pub layout_version: u8,
 }
 
 pub const PUMP_SWAP_BUY_EVENT_LOG_SIZE: usize = 385;
 
+/// 买入事件各版本大小：v1 至 `protocol_fee_recipient_token_account`，v2 增加创作者费用，
+/// v3 增加交易量统计
+pub const PUMP_SWAP_BUY_EVENT_LOG_SIZES: &[usize] = &[304, 352, PUMP_SWAP_BUY_EVENT_LOG_SIZE];
+
 pub fn pump_swap_buy_event_log_decode(data: &[u8]) -> Option<PumpSwapBuyEvent> {
-    if data.len() < PUMP_SWAP_BUY_EVENT_LOG_SIZE {
-        return None;
-    }
-    borsh::from_slice::<PumpSwapBuyEvent>(&data[..PUMP_SWAP_BUY_EVENT_LOG_SIZE]).ok()
+    let (event, layout_version) =
+        decode_versioned_layout::<PumpSwapBuyEvent>(data, PUMP_SWAP_BUY_EVENT_LOG_SIZES)?;
+    Some(PumpSwapBuyEvent { layout_version, ..event })
 }
 
 // 使用宏生成UnifiedEvent实现，指定需要合并的字段
@@ -87,7 +96,13 @@ impl_unified_event!(
     protocol_fee_recipient_token_account,
     coin_creator,
     coin_creator_fee_basis_points,
-    coin_creator_fee
+    coin_creator_fee,
+    track_volume,
+    total_unclaimed_tokens,
+    total_claimed_tokens,
+    current_sol_volume,
+    last_update_timestamp,
+    layout_version
 );
 
 /// 卖出事件
@@ -130,15 +145,20 @@ pub struct PumpSwapSellEvent {
     pub fee_config: Pubkey,
     #[borsh(skip)]
     pub fee_program: Pubkey,
+    /// 事件日志布局版本，从 1 开始（0 表示未解码到事件日志）
+    #[borsh(skip)]
+    pub layout_version: u8,
 }
 
 pub const PUMP_SWAP_SELL_EVENT_LOG_SIZE: usize = 352;
 
+/// 卖出事件各版本大小：v1 至 `protocol_fee_recipient_token_account`，v2 增加创作者费用
+pub const PUMP_SWAP_SELL_EVENT_LOG_SIZES: &[usize] = &[304, PUMP_SWAP_SELL_EVENT_LOG_SIZE];
+
 pub fn pump_swap_sell_event_log_decode(data: &[u8]) -> Option<PumpSwapSellEvent> {
-    if data.len() < PUMP_SWAP_SELL_EVENT_LOG_SIZE {
-        return None;
-    }
-    borsh::from_slice::<PumpSwapSellEvent>(&data[..PUMP_SWAP_SELL_EVENT_LOG_SIZE]).ok()
+    let (event, layout_version) =
+        decode_versioned_layout::<PumpSwapSellEvent>(data, PUMP_SWAP_SELL_EVENT_LOG_SIZES)?;
+    Some(PumpSwapSellEvent { layout_version, ..event })
 }
 
 // 使用宏生成UnifiedEvent实现，指定需要合并的字段
@@ -166,7 +186,8 @@ impl_unified_event!(
     protocol_fee_recipient_token_account,
     coin_creator,
     coin_creator_fee_basis_points,
-    coin_creator_fee
+    coin_creator_fee,
+    layout_version
 );
 
 /// 创建池子事件
@@ -343,6 +364,339 @@ impl_unified_event!(
     user_pool_token_account
 );
 
+/// 领取代币创作者费用
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapCollectCoinCreatorFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub coin_creator: Pubkey,
+    pub coin_creator_fee: u64,
+    pub coin_creator_vault_ata: Pubkey,
+    pub coin_creator_token_account: Pubkey,
+    #[borsh(skip)]
+    pub quote_mint: Pubkey,
+    #[borsh(skip)]
+    pub quote_token_program: Pubkey,
+    #[borsh(skip)]
+    pub coin_creator_vault_authority: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMP_SWAP_COLLECT_COIN_CREATOR_FEE_EVENT_LOG_SIZE: usize = 112;
+
+pub fn pump_swap_collect_coin_creator_fee_event_log_decode(
+    data: &[u8],
+) -> Option<PumpSwapCollectCoinCreatorFeeEvent> {
+    if data.len() < PUMP_SWAP_COLLECT_COIN_CREATOR_FEE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapCollectCoinCreatorFeeEvent>(
+        &data[..PUMP_SWAP_COLLECT_COIN_CREATOR_FEE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    PumpSwapCollectCoinCreatorFeeEvent,
+    timestamp,
+    coin_creator,
+    coin_creator_fee,
+    coin_creator_vault_ata,
+    coin_creator_token_account
+);
+
+/// 更新费率配置
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapUpdateFeeConfigEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub lp_fee_basis_points: u64,
+    pub protocol_fee_basis_points: u64,
+    pub protocol_fee_recipients: [Pubkey; 8],
+    pub coin_creator_fee_basis_points: u64,
+    pub admin_set_coin_creator_authority: Pubkey,
+    #[borsh(skip)]
+    pub global_config: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMP_SWAP_UPDATE_FEE_CONFIG_EVENT_LOG_SIZE: usize = 352;
+
+pub fn pump_swap_update_fee_config_event_log_decode(
+    data: &[u8],
+) -> Option<PumpSwapUpdateFeeConfigEvent> {
+    if data.len() < PUMP_SWAP_UPDATE_FEE_CONFIG_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapUpdateFeeConfigEvent>(
+        &data[..PUMP_SWAP_UPDATE_FEE_CONFIG_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    PumpSwapUpdateFeeConfigEvent,
+    timestamp,
+    admin,
+    lp_fee_basis_points,
+    protocol_fee_basis_points,
+    protocol_fee_recipients,
+    coin_creator_fee_basis_points,
+    admin_set_coin_creator_authority
+);
+
+/// 更换管理员
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapUpdateAdminEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub new_admin: Pubkey,
+    #[borsh(skip)]
+    pub global_config: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMP_SWAP_UPDATE_ADMIN_EVENT_LOG_SIZE: usize = 72;
+
+pub fn pump_swap_update_admin_event_log_decode(data: &[u8]) -> Option<PumpSwapUpdateAdminEvent> {
+    if data.len() < PUMP_SWAP_UPDATE_ADMIN_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapUpdateAdminEvent>(&data[..PUMP_SWAP_UPDATE_ADMIN_EVENT_LOG_SIZE])
+        .ok()
+}
+
+impl_unified_event!(PumpSwapUpdateAdminEvent, timestamp, admin, new_admin);
+
+/// 禁用/启用功能
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapDisableEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub create_pool: bool,
+    pub deposit: bool,
+    pub withdraw: bool,
+    pub buy: bool,
+    pub sell: bool,
+    #[borsh(skip)]
+    pub global_config: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMP_SWAP_DISABLE_EVENT_LOG_SIZE: usize = 45;
+
+pub fn pump_swap_disable_event_log_decode(data: &[u8]) -> Option<PumpSwapDisableEvent> {
+    if data.len() < PUMP_SWAP_DISABLE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapDisableEvent>(&data[..PUMP_SWAP_DISABLE_EVENT_LOG_SIZE]).ok()
+}
+
+impl_unified_event!(
+    PumpSwapDisableEvent,
+    timestamp,
+    admin,
+    create_pool,
+    deposit,
+    withdraw,
+    buy,
+    sell
+);
+
+/// 领取交易量激励代币
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapClaimTokenIncentivesEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    #[borsh(skip)]
+    pub user_ata: Pubkey,
+    #[borsh(skip)]
+    pub global_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub global_incentive_token_account: Pubkey,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub token_program: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub associated_token_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+    #[borsh(skip)]
+    pub payer: Pubkey,
+}
+
+pub const PUMP_SWAP_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE: usize = 96;
+
+pub fn pump_swap_claim_token_incentives_event_log_decode(
+    data: &[u8],
+) -> Option<PumpSwapClaimTokenIncentivesEvent> {
+    if data.len() < PUMP_SWAP_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapClaimTokenIncentivesEvent>(
+        &data[..PUMP_SWAP_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    PumpSwapClaimTokenIncentivesEvent,
+    user,
+    mint,
+    amount,
+    timestamp,
+    total_claimed_tokens,
+    current_sol_volume
+);
+
+/// 创建用户交易量累加器
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapInitUserVolumeAccumulatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub payer: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMP_SWAP_INIT_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE: usize = 72;
+
+pub fn pump_swap_init_user_volume_accumulator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpSwapInitUserVolumeAccumulatorEvent> {
+    if data.len() < PUMP_SWAP_INIT_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapInitUserVolumeAccumulatorEvent>(
+        &data[..PUMP_SWAP_INIT_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(PumpSwapInitUserVolumeAccumulatorEvent, payer, user, timestamp);
+
+/// 关闭用户交易量累加器
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapCloseUserVolumeAccumulatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub user: Pubkey,
+    pub timestamp: i64,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMP_SWAP_CLOSE_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE: usize = 72;
+
+pub fn pump_swap_close_user_volume_accumulator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpSwapCloseUserVolumeAccumulatorEvent> {
+    if data.len() < PUMP_SWAP_CLOSE_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapCloseUserVolumeAccumulatorEvent>(
+        &data[..PUMP_SWAP_CLOSE_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    PumpSwapCloseUserVolumeAccumulatorEvent,
+    user,
+    timestamp,
+    total_unclaimed_tokens,
+    total_claimed_tokens,
+    current_sol_volume,
+    last_update_timestamp
+);
+
+/// 同步用户交易量累加器
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapSyncUserVolumeAccumulatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub user: Pubkey,
+    pub total_claimed_tokens_before: u64,
+    pub total_claimed_tokens_after: u64,
+    pub timestamp: i64,
+    #[borsh(skip)]
+    pub global_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMP_SWAP_SYNC_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE: usize = 56;
+
+pub fn pump_swap_sync_user_volume_accumulator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpSwapSyncUserVolumeAccumulatorEvent> {
+    if data.len() < PUMP_SWAP_SYNC_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapSyncUserVolumeAccumulatorEvent>(
+        &data[..PUMP_SWAP_SYNC_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+impl_unified_event!(
+    PumpSwapSyncUserVolumeAccumulatorEvent,
+    user,
+    total_claimed_tokens_before,
+    total_claimed_tokens_after,
+    timestamp
+);
+
 /// 全局配置
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PumpSwapGlobalConfigAccountEvent {
@@ -371,6 +725,45 @@ pub struct PumpSwapPoolAccountEvent {
 }
 impl_unified_event!(PumpSwapPoolAccountEvent,);
 
+/// 全局交易量累加器
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapGlobalVolumeAccumulatorAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub global_volume_accumulator: GlobalVolumeAccumulator,
+}
+impl_unified_event!(PumpSwapGlobalVolumeAccumulatorAccountEvent,);
+
+/// 用户交易量累加器
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapUserVolumeAccumulatorAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub user_volume_accumulator: UserVolumeAccumulator,
+}
+impl_unified_event!(PumpSwapUserVolumeAccumulatorAccountEvent,);
+
+/// 费率配置（归属 pump fees 程序）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapFeeConfigAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub fee_config: FeeConfig,
+}
+impl_unified_event!(PumpSwapFeeConfigAccountEvent,);
+
 /// 事件鉴别器常量
 pub mod discriminators {
     // 事件鉴别器
@@ -389,6 +782,22 @@ pub mod discriminators {
     // pub const WITHDRAW_EVENT: &str = "0xe445a52e51cb9a1d1609851aa02c47c0";
     pub const WITHDRAW_EVENT: &[u8] =
         &[228, 69, 165, 46, 81, 203, 154, 29, 22, 9, 133, 26, 160, 44, 71, 192];
+    pub const COLLECT_COIN_CREATOR_FEE_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 232, 245, 194, 238, 234, 218, 58, 89];
+    pub const UPDATE_FEE_CONFIG_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 90, 23, 65, 35, 62, 244, 188, 208];
+    pub const UPDATE_ADMIN_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 225, 152, 171, 87, 246, 63, 66, 234];
+    pub const DISABLE_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 107, 253, 193, 76, 228, 202, 27, 104];
+    pub const CLAIM_TOKEN_INCENTIVES_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 79, 172, 246, 49, 205, 91, 206, 232];
+    pub const INIT_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 134, 36, 13, 72, 232, 101, 130, 216];
+    pub const CLOSE_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 146, 159, 189, 172, 146, 88, 56, 244];
+    pub const SYNC_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 197, 122, 167, 124, 116, 81, 91, 255];
 
     // 指令鉴别器
     pub const BUY_IX: &[u8] = &[102, 6, 61, 18, 1, 218, 235, 234];
@@ -396,10 +805,21 @@ pub mod discriminators {
     pub const CREATE_POOL_IX: &[u8] = &[233, 146, 209, 142, 207, 104, 64, 188];
     pub const DEPOSIT_IX: &[u8] = &[242, 35, 198, 137, 82, 225, 242, 182];
     pub const WITHDRAW_IX: &[u8] = &[183, 18, 70, 156, 148, 109, 161, 34];
+    pub const COLLECT_COIN_CREATOR_FEE_IX: &[u8] = &[160, 57, 89, 42, 181, 139, 43, 66];
+    pub const UPDATE_FEE_CONFIG_IX: &[u8] = &[104, 184, 103, 242, 88, 151, 107, 20];
+    pub const UPDATE_ADMIN_IX: &[u8] = &[161, 176, 40, 213, 60, 184, 179, 228];
+    pub const DISABLE_IX: &[u8] = &[185, 173, 187, 90, 216, 15, 238, 233];
+    pub const CLAIM_TOKEN_INCENTIVES_IX: &[u8] = &[16, 4, 71, 28, 204, 1, 40, 27];
+    pub const INIT_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[94, 6, 202, 115, 255, 96, 232, 183];
+    pub const CLOSE_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[249, 69, 164, 218, 150, 103, 84, 138];
+    pub const SYNC_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[86, 31, 192, 87, 163, 87, 79, 238];
 
     // 账户鉴别器
     pub const GLOBAL_CONFIG_ACCOUNT: &[u8] = &[149, 8, 156, 202, 160, 252, 176, 217];
     pub const POOL_ACCOUNT: &[u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
+    pub const GLOBAL_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[202, 42, 246, 43, 142, 190, 30, 255];
+    pub const USER_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[86, 255, 112, 14, 102, 53, 154, 250];
+    pub const FEE_CONFIG_ACCOUNT: &[u8] = &[143, 52, 146, 187, 219, 123, 76, 155];
 }
 
 use crate::streaming::event_parser::protocols::raydium_cpmm::types::{
@@ -576,3 +996,42 @@ impl CopyTradeableEvent for PumpSwapSellEvent {
         self.get_trade_info()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    // 按 v2 买入事件布局（含创作者费用、不含交易量统计）构造的事件数据 base64
+    const BUY_EVENT_V2: &str = concat!(
+        "AHjnaAAAAAAAIEqp0QEAAIB3jgYAAAAAACBKqdEBAAAAAAAAAAAAAABAD4S1owAAAH8+NgIAAAAwyUsGAAAA",
+        "ABQAAAAAAAAAPDkDAAAAAAAFAAAAAAAAAE/OAAAAAAAAbAJPBgAAAAC70E8GAAAAACfKxVA4NnZc0QdR0nq0",
+        "puF9eoDUyUhDClqBUTlz+bUeBPiZbadjt6lpsQKO4wB1aerzpjVIbdqyEdUSyFud+Psyx55aM1ouMRFUkYbs",
+        "4d2FF3yXRHNgHgMW/RvmmIBKWWIjp9+R7QTO8dd133GsZRFZ38bfag2M4e5KXtWZ458xfNTtxHgoKlKTTQXT",
+        "DGJoRoNiq/87YGHohBJ29hZY5QbDNrcz3oToyyXOesiniuGaCr0pcgutWo7wbRg2LQmo+6ZWt4Mb+hgyuqUs",
+        "XvIsMeec9ZGQoCJLE37uJZXrx2mBBQAAAAAAAABPzgAAAAAAAA==",
+    );
+
+    #[test]
+    fn buy_event_decodes_each_layout_version() {
+        let v2 = STANDARD.decode(BUY_EVENT_V2).unwrap();
+        let event = pump_swap_buy_event_log_decode(&v2).unwrap();
+        assert_eq!(event.layout_version, 2);
+        assert_eq!(event.base_amount_out, 2_000_000_000_000);
+        assert_eq!(event.quote_amount_in, 105_630_000);
+        assert_eq!(event.user_quote_amount_in, 105_894_075);
+        assert_eq!((event.coin_creator_fee_basis_points, event.coin_creator_fee), (5, 52_815));
+        assert!(!event.track_volume);
+        assert_eq!(event.last_update_timestamp, 0);
+
+        let v1 = pump_swap_buy_event_log_decode(&v2[..304]).unwrap();
+        assert_eq!(v1.layout_version, 1);
+        assert_eq!(
+            v1.protocol_fee_recipient_token_account,
+            event.protocol_fee_recipient_token_account
+        );
+        assert_eq!((v1.coin_creator, v1.coin_creator_fee), (Pubkey::default(), 0));
+
+        assert!(pump_swap_buy_event_log_decode(&v2[..303]).is_none());
+    }
+}
@@ -3,13 +3,25 @@ use solana_sdk::pubkey::Pubkey;
 use crate::{
     impl_event_parser_delegate,
     streaming::event_parser::{
-        common::{read_u64_le, EventMetadata, EventType, ProtocolType},
+        common::{read_pubkey, read_u64_le, EventMetadata, EventType, ProtocolType},
         core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
         protocols::pumpswap::{
-            discriminators, pump_swap_buy_event_log_decode, pump_swap_create_pool_event_log_decode,
-            pump_swap_deposit_event_log_decode, pump_swap_sell_event_log_decode,
-            pump_swap_withdraw_event_log_decode, PumpSwapBuyEvent, PumpSwapCreatePoolEvent,
-            PumpSwapDepositEvent, PumpSwapSellEvent, PumpSwapWithdrawEvent,
+            discriminators, pump_swap_buy_event_log_decode,
+            pump_swap_claim_token_incentives_event_log_decode,
+            pump_swap_close_user_volume_accumulator_event_log_decode,
+            pump_swap_collect_coin_creator_fee_event_log_decode,
+            pump_swap_create_pool_event_log_decode, pump_swap_deposit_event_log_decode,
+            pump_swap_disable_event_log_decode,
+            pump_swap_init_user_volume_accumulator_event_log_decode,
+            pump_swap_sell_event_log_decode,
+            pump_swap_sync_user_volume_accumulator_event_log_decode,
+            pump_swap_update_admin_event_log_decode, pump_swap_update_fee_config_event_log_decode,
+            pump_swap_withdraw_event_log_decode, PumpSwapBuyEvent,
+            PumpSwapClaimTokenIncentivesEvent, PumpSwapCloseUserVolumeAccumulatorEvent,
+            PumpSwapCollectCoinCreatorFeeEvent, PumpSwapCreatePoolEvent, PumpSwapDepositEvent,
+            PumpSwapDisableEvent, PumpSwapInitUserVolumeAccumulatorEvent, PumpSwapSellEvent,
+            PumpSwapSyncUserVolumeAccumulatorEvent, PumpSwapUpdateAdminEvent,
+            PumpSwapUpdateFeeConfigEvent, PumpSwapWithdrawEvent,
         },
     },
 };
@@ -78,6 +90,89 @@ impl PumpSwapEventParser {
                 inner_instruction_parser: Some(Self::parse_withdraw_inner_instruction),
                 instruction_parser: Some(Self::parse_withdraw_instruction),
             },
+            GenericEventParseConfig {
+                program_id: PUMPSWAP_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpSwap,
+                inner_instruction_discriminator: discriminators::COLLECT_COIN_CREATOR_FEE_EVENT,
+                instruction_discriminator: discriminators::COLLECT_COIN_CREATOR_FEE_IX,
+                event_type: EventType::PumpSwapCollectCoinCreatorFee,
+                inner_instruction_parser: Some(
+                    Self::parse_collect_coin_creator_fee_inner_instruction,
+                ),
+                instruction_parser: Some(Self::parse_collect_coin_creator_fee_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPSWAP_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpSwap,
+                inner_instruction_discriminator: discriminators::UPDATE_FEE_CONFIG_EVENT,
+                instruction_discriminator: discriminators::UPDATE_FEE_CONFIG_IX,
+                event_type: EventType::PumpSwapUpdateFeeConfig,
+                inner_instruction_parser: Some(Self::parse_update_fee_config_inner_instruction),
+                instruction_parser: Some(Self::parse_update_fee_config_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPSWAP_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpSwap,
+                inner_instruction_discriminator: discriminators::UPDATE_ADMIN_EVENT,
+                instruction_discriminator: discriminators::UPDATE_ADMIN_IX,
+                event_type: EventType::PumpSwapUpdateAdmin,
+                inner_instruction_parser: Some(Self::parse_update_admin_inner_instruction),
+                instruction_parser: Some(Self::parse_update_admin_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPSWAP_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpSwap,
+                inner_instruction_discriminator: discriminators::DISABLE_EVENT,
+                instruction_discriminator: discriminators::DISABLE_IX,
+                event_type: EventType::PumpSwapDisable,
+                inner_instruction_parser: Some(Self::parse_disable_inner_instruction),
+                instruction_parser: Some(Self::parse_disable_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPSWAP_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpSwap,
+                inner_instruction_discriminator: discriminators::CLAIM_TOKEN_INCENTIVES_EVENT,
+                instruction_discriminator: discriminators::CLAIM_TOKEN_INCENTIVES_IX,
+                event_type: EventType::PumpSwapClaimTokenIncentives,
+                inner_instruction_parser: Some(
+                    Self::parse_claim_token_incentives_inner_instruction,
+                ),
+                instruction_parser: Some(Self::parse_claim_token_incentives_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPSWAP_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpSwap,
+                inner_instruction_discriminator: discriminators::INIT_USER_VOLUME_ACCUMULATOR_EVENT,
+                instruction_discriminator: discriminators::INIT_USER_VOLUME_ACCUMULATOR_IX,
+                event_type: EventType::PumpSwapInitUserVolumeAccumulator,
+                inner_instruction_parser: Some(
+                    Self::parse_init_user_volume_accumulator_inner_instruction,
+                ),
+                instruction_parser: Some(Self::parse_init_user_volume_accumulator_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPSWAP_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpSwap,
+                inner_instruction_discriminator:
+                    discriminators::CLOSE_USER_VOLUME_ACCUMULATOR_EVENT,
+                instruction_discriminator: discriminators::CLOSE_USER_VOLUME_ACCUMULATOR_IX,
+                event_type: EventType::PumpSwapCloseUserVolumeAccumulator,
+                inner_instruction_parser: Some(
+                    Self::parse_close_user_volume_accumulator_inner_instruction,
+                ),
+                instruction_parser: Some(Self::parse_close_user_volume_accumulator_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: PUMPSWAP_PROGRAM_ID,
+                protocol_type: ProtocolType::PumpSwap,
+                inner_instruction_discriminator: discriminators::SYNC_USER_VOLUME_ACCUMULATOR_EVENT,
+                instruction_discriminator: discriminators::SYNC_USER_VOLUME_ACCUMULATOR_IX,
+                event_type: EventType::PumpSwapSyncUserVolumeAccumulator,
+                inner_instruction_parser: Some(
+                    Self::parse_sync_user_volume_accumulator_inner_instruction,
+                ),
+                instruction_parser: Some(Self::parse_sync_user_volume_accumulator_instruction),
+            },
         ];
 
         let inner = GenericEventParser::new(vec![PUMPSWAP_PROGRAM_ID], configs);
@@ -185,6 +280,78 @@ impl PumpSwapEventParser {
         }
     }
 
+    /// 解析领取代币创作者费用日志事件
+    fn parse_collect_coin_creator_fee_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pump_swap_collect_coin_creator_fee_event_log_decode(data)?;
+        Some(Box::new(PumpSwapCollectCoinCreatorFeeEvent { metadata, ..event }))
+    }
+
+    /// 解析更新费率配置日志事件
+    fn parse_update_fee_config_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pump_swap_update_fee_config_event_log_decode(data)?;
+        Some(Box::new(PumpSwapUpdateFeeConfigEvent { metadata, ..event }))
+    }
+
+    /// 解析更换管理员日志事件
+    fn parse_update_admin_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pump_swap_update_admin_event_log_decode(data)?;
+        Some(Box::new(PumpSwapUpdateAdminEvent { metadata, ..event }))
+    }
+
+    /// 解析禁用功能日志事件
+    fn parse_disable_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pump_swap_disable_event_log_decode(data)?;
+        Some(Box::new(PumpSwapDisableEvent { metadata, ..event }))
+    }
+
+    /// 解析领取激励代币日志事件
+    fn parse_claim_token_incentives_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pump_swap_claim_token_incentives_event_log_decode(data)?;
+        Some(Box::new(PumpSwapClaimTokenIncentivesEvent { metadata, ..event }))
+    }
+
+    /// 解析创建用户交易量累加器日志事件
+    fn parse_init_user_volume_accumulator_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pump_swap_init_user_volume_accumulator_event_log_decode(data)?;
+        Some(Box::new(PumpSwapInitUserVolumeAccumulatorEvent { metadata, ..event }))
+    }
+
+    /// 解析关闭用户交易量累加器日志事件
+    fn parse_close_user_volume_accumulator_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pump_swap_close_user_volume_accumulator_event_log_decode(data)?;
+        Some(Box::new(PumpSwapCloseUserVolumeAccumulatorEvent { metadata, ..event }))
+    }
+
+    /// 解析同步用户交易量累加器日志事件
+    fn parse_sync_user_volume_accumulator_inner_instruction(
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        let event = pump_swap_sync_user_volume_accumulator_event_log_decode(data)?;
+        Some(Box::new(PumpSwapSyncUserVolumeAccumulatorEvent { metadata, ..event }))
+    }
+
     /// 解析买入指令事件
     fn parse_buy_instruction(
         data: &[u8],
@@ -347,6 +514,188 @@ impl PumpSwapEventParser {
             ..Default::default()
         }))
     }
+
+    /// 解析领取代币创作者费用指令事件
+    fn parse_collect_coin_creator_fee_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 8 {
+            return None;
+        }
+        Some(Box::new(PumpSwapCollectCoinCreatorFeeEvent {
+            metadata,
+            quote_mint: accounts[0],
+            quote_token_program: accounts[1],
+            coin_creator: accounts[2],
+            coin_creator_vault_authority: accounts[3],
+            coin_creator_vault_ata: accounts[4],
+            coin_creator_token_account: accounts[5],
+            event_authority: accounts[6],
+            program: accounts[7],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析更新费率配置指令事件
+    fn parse_update_fee_config_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 312 || accounts.len() < 4 {
+            return None;
+        }
+        let mut protocol_fee_recipients = [Pubkey::default(); 8];
+        for (i, recipient) in protocol_fee_recipients.iter_mut().enumerate() {
+            *recipient = read_pubkey(data, 16 + i * 32)?;
+        }
+        Some(Box::new(PumpSwapUpdateFeeConfigEvent {
+            metadata,
+            admin: accounts[0],
+            global_config: accounts[1],
+            event_authority: accounts[2],
+            program: accounts[3],
+            lp_fee_basis_points: read_u64_le(data, 0)?,
+            protocol_fee_basis_points: read_u64_le(data, 8)?,
+            protocol_fee_recipients,
+            coin_creator_fee_basis_points: read_u64_le(data, 272)?,
+            admin_set_coin_creator_authority: read_pubkey(data, 280)?,
+            ..Default::default()
+        }))
+    }
+
+    /// 解析更换管理员指令事件
+    fn parse_update_admin_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 5 {
+            return None;
+        }
+        Some(Box::new(PumpSwapUpdateAdminEvent {
+            metadata,
+            admin: accounts[0],
+            global_config: accounts[1],
+            new_admin: accounts[2],
+            event_authority: accounts[3],
+            program: accounts[4],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析禁用功能指令事件
+    fn parse_disable_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 5 || accounts.len() < 4 {
+            return None;
+        }
+        Some(Box::new(PumpSwapDisableEvent {
+            metadata,
+            admin: accounts[0],
+            global_config: accounts[1],
+            event_authority: accounts[2],
+            program: accounts[3],
+            create_pool: data[0] != 0,
+            deposit: data[1] != 0,
+            withdraw: data[2] != 0,
+            buy: data[3] != 0,
+            sell: data[4] != 0,
+            ..Default::default()
+        }))
+    }
+
+    /// 解析领取激励代币指令事件
+    fn parse_claim_token_incentives_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 12 {
+            return None;
+        }
+        Some(Box::new(PumpSwapClaimTokenIncentivesEvent {
+            metadata,
+            user: accounts[0],
+            user_ata: accounts[1],
+            global_volume_accumulator: accounts[2],
+            global_incentive_token_account: accounts[3],
+            user_volume_accumulator: accounts[4],
+            mint: accounts[5],
+            token_program: accounts[6],
+            system_program: accounts[7],
+            associated_token_program: accounts[8],
+            event_authority: accounts[9],
+            program: accounts[10],
+            payer: accounts[11],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析创建用户交易量累加器指令事件
+    fn parse_init_user_volume_accumulator_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 6 {
+            return None;
+        }
+        Some(Box::new(PumpSwapInitUserVolumeAccumulatorEvent {
+            metadata,
+            payer: accounts[0],
+            user: accounts[1],
+            user_volume_accumulator: accounts[2],
+            system_program: accounts[3],
+            event_authority: accounts[4],
+            program: accounts[5],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析关闭用户交易量累加器指令事件
+    fn parse_close_user_volume_accumulator_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 4 {
+            return None;
+        }
+        Some(Box::new(PumpSwapCloseUserVolumeAccumulatorEvent {
+            metadata,
+            user: accounts[0],
+            user_volume_accumulator: accounts[1],
+            event_authority: accounts[2],
+            program: accounts[3],
+            ..Default::default()
+        }))
+    }
+
+    /// 解析同步用户交易量累加器指令事件
+    fn parse_sync_user_volume_accumulator_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.len() < 5 {
+            return None;
+        }
+        Some(Box::new(PumpSwapSyncUserVolumeAccumulatorEvent {
+            metadata,
+            user: accounts[0],
+            global_volume_accumulator: accounts[1],
+            user_volume_accumulator: accounts[2],
+            event_authority: accounts[3],
+            program: accounts[4],
+            ..Default::default()
+        }))
+    }
 }
 
 impl_event_parser_delegate!(PumpSwapEventParser);
@@ -1,3 +1,5 @@
+use std::sync::LazyLock;
+
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -5,8 +7,20 @@ use solana_sdk::pubkey::Pubkey;
 use crate::streaming::{
     event_parser::{
         common::EventMetadata,
-        protocols::pumpswap::{
-            PumpSwapGlobalConfigAccountEvent, PumpSwapPoolAccountEvent,
+        protocols::{
+            pumpfun::{
+                parser::PUMP_FEES_PROGRAM_ID,
+                types::{
+                    fee_config_decode, global_volume_accumulator_decode,
+                    user_volume_accumulator_decode, GLOBAL_VOLUME_ACCUMULATOR_SIZE,
+                    USER_VOLUME_ACCUMULATOR_SIZE,
+                },
+            },
+            pumpswap::{
+                parser::PUMPSWAP_PROGRAM_ID, PumpSwapFeeConfigAccountEvent,
+                PumpSwapGlobalConfigAccountEvent, PumpSwapGlobalVolumeAccumulatorAccountEvent,
+                PumpSwapPoolAccountEvent, PumpSwapUserVolumeAccumulatorAccountEvent,
+            },
         },
         UnifiedEvent,
     },
@@ -99,3 +113,178 @@ pub fn pool_parser(
         None
     }
 }
+
+pub fn global_volume_accumulator_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < GLOBAL_VOLUME_ACCUMULATOR_SIZE + 8 {
+        return None;
+    }
+    let global_volume_accumulator = global_volume_accumulator_decode(
+        &account.data[8..GLOBAL_VOLUME_ACCUMULATOR_SIZE + 8],
+    )?;
+    Some(Box::new(PumpSwapGlobalVolumeAccumulatorAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        global_volume_accumulator,
+    }))
+}
+
+pub fn user_volume_accumulator_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < USER_VOLUME_ACCUMULATOR_SIZE + 8 {
+        return None;
+    }
+    let user_volume_accumulator =
+        user_volume_accumulator_decode(&account.data[8..USER_VOLUME_ACCUMULATOR_SIZE + 8])?;
+    Some(Box::new(PumpSwapUserVolumeAccumulatorAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        user_volume_accumulator,
+    }))
+}
+
+/// PumpSwap 在 pump fees 程序下的费率配置 PDA，与 PumpFun 共用同一账户结构
+pub static PUMPSWAP_FEE_CONFIG: LazyLock<Pubkey> = LazyLock::new(|| {
+    Pubkey::find_program_address(
+        &[b"fee_config", PUMPSWAP_PROGRAM_ID.as_ref()],
+        &PUMP_FEES_PROGRAM_ID,
+    )
+    .0
+});
+
+pub fn fee_config_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.pubkey != *PUMPSWAP_FEE_CONFIG || account.data.len() < 8 {
+        return None;
+    }
+    let fee_config = fee_config_decode(&account.data[8..])?;
+    Some(Box::new(PumpSwapFeeConfigAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        fee_config,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    use super::*;
+
+    // 按 FeeConfig 布局构造：鉴别器 + bump + admin + 固定费率 + 两档费率 + 16 字节未用空间的 base64
+    const FEE_CONFIG_FIXTURE: &str = concat!(
+        "jzSSu9t7TJv/Kg4yF+Y1B9D2oSrIKoZ0TlC3XQ3CFCL5etKPmelNjHAUAAAAAAAAAAUAAAAAAAAABQAAAAAAAAAC",
+        "AAAAAAAAAAAAAAAAAAAAAAAAABkAAAAAAAAAXwAAAAAAAAAFAAAAAAAAAABo88lhAAAAAAAAAAAAAAAUAAAAAAAA",
+        "AAUAAAAAAAAABQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+    );
+
+    // 按 UserVolumeAccumulator 布局构造：鉴别器 + user + 各累计字段的 base64
+    const USER_VOLUME_ACCUMULATOR_FIXTURE: &str = concat!(
+        "Vv9wDmY1mvrdrZtuj1eJt2CcenPm2zDL86yzm9AzjA+Hk5xFiHV8kQFg4xYAAAAAAJDQAwAAAAAAAF7QsgAAAAAA",
+        "eOdoAAAAAAE=",
+    );
+
+    /// 按账户布局偏移写入字段，偏移包含 8 字节鉴别器
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[8 + offset..8 + offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[test]
+    fn fee_config_layout() {
+        let account = AccountPretty {
+            pubkey: *PUMPSWAP_FEE_CONFIG,
+            data: STANDARD.decode(FEE_CONFIG_FIXTURE).unwrap(),
+            ..Default::default()
+        };
+        let event = fee_config_parser(&account, EventMetadata::default()).unwrap();
+        let fee_config =
+            &event.as_any().downcast_ref::<PumpSwapFeeConfigAccountEvent>().unwrap().fee_config;
+        assert_eq!(fee_config.bump, 255);
+        assert_eq!(
+            fee_config.admin,
+            Pubkey::from_str("3qAgKujRJUqXFinqQSfREyvZ4weMm9ngCVvA9SB31JZm").unwrap()
+        );
+        assert_eq!(fee_config.flat_fees.lp_fee_bps, 20);
+        assert_eq!(fee_config.flat_fees.protocol_fee_bps, 5);
+        assert_eq!(fee_config.flat_fees.creator_fee_bps, 5);
+        assert_eq!(fee_config.fee_tiers.len(), 2);
+        assert_eq!(fee_config.fee_tiers[0].market_cap_lamports_threshold, 0);
+        assert_eq!(fee_config.fee_tiers[0].fees.protocol_fee_bps, 95);
+        assert_eq!(fee_config.fee_tiers[1].market_cap_lamports_threshold, 420_000_000_000);
+        assert_eq!(fee_config.fee_tiers[1].fees.lp_fee_bps, 20);
+
+        // 非 PumpSwap 费率配置 PDA 的账户不解析
+        let other = AccountPretty { pubkey: Pubkey::new_unique(), ..account };
+        assert!(fee_config_parser(&other, EventMetadata::default()).is_none());
+    }
+
+    #[test]
+    fn global_volume_accumulator_layout() {
+        let mint = Pubkey::new_unique();
+        let mut data = vec![0u8; GLOBAL_VOLUME_ACCUMULATOR_SIZE + 8];
+        put(&mut data, 0, &1_760_000_000i64.to_le_bytes());
+        put(&mut data, 8, &1_762_592_000i64.to_le_bytes());
+        put(&mut data, 16, &86_400i64.to_le_bytes());
+        put(&mut data, 24, mint.as_ref());
+        put(&mut data, 56, &1_000_000_000u64.to_le_bytes());
+        put(&mut data, 56 + 240 + 29 * 8, &7_500_000_000u64.to_le_bytes());
+        let account = AccountPretty { data, ..Default::default() };
+
+        let event = global_volume_accumulator_parser(&account, EventMetadata::default()).unwrap();
+        let accumulator = &event
+            .as_any()
+            .downcast_ref::<PumpSwapGlobalVolumeAccumulatorAccountEvent>()
+            .unwrap()
+            .global_volume_accumulator;
+        assert_eq!(accumulator.start_time, 1_760_000_000);
+        assert_eq!(accumulator.end_time, 1_762_592_000);
+        assert_eq!(accumulator.seconds_in_a_day, 86_400);
+        assert_eq!(accumulator.mint, mint);
+        assert_eq!(accumulator.total_token_supply[0], 1_000_000_000);
+        assert_eq!(accumulator.sol_volumes[29], 7_500_000_000);
+    }
+
+    #[test]
+    fn user_volume_accumulator_layout() {
+        let account = AccountPretty {
+            data: STANDARD.decode(USER_VOLUME_ACCUMULATOR_FIXTURE).unwrap(),
+            ..Default::default()
+        };
+        let event = user_volume_accumulator_parser(&account, EventMetadata::default()).unwrap();
+        let accumulator = &event
+            .as_any()
+            .downcast_ref::<PumpSwapUserVolumeAccumulatorAccountEvent>()
+            .unwrap()
+            .user_volume_accumulator;
+        assert_eq!(
+            accumulator.user,
+            Pubkey::from_str("FvLe8pDNbaUsvtG6HXMKvne7t44YLcbaYeHraJGemmA8").unwrap()
+        );
+        assert!(accumulator.needs_claim);
+        assert_eq!(accumulator.total_unclaimed_tokens, 1_500_000);
+        assert_eq!(accumulator.total_claimed_tokens, 250_000);
+        assert_eq!(accumulator.current_sol_volume, 3_000_000_000);
+        assert_eq!(accumulator.last_update_timestamp, 1_760_000_000);
+        assert!(accumulator.has_total_claimed_tokens);
+    }
+}
@@ -0,0 +1 @@
+pub mod raw_instruction_event;
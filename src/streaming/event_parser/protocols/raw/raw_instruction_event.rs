@@ -0,0 +1,29 @@
+use crate::impl_unified_event;
+use crate::streaming::event_parser::common::EventMetadata;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 未能解析为事件的原因
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RawInstructionReason {
+    /// 没有匹配的指令鉴别器
+    #[default]
+    UnknownInstruction,
+    /// 鉴别器匹配但解码失败，通常是程序升级后布局变化
+    DecodeFailed,
+}
+
+/// 被监听程序的原始指令事件（需调用 `set_raw_instruction_capture(true)` 开启）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RawInstructionEvent {
+    pub metadata: EventMetadata,
+    pub reason: RawInstructionReason,
+    pub program_id: Pubkey,
+    /// 指令数据的前 8 字节，解码失败时为匹配到的鉴别器
+    pub discriminator: Vec<u8>,
+    pub data: Vec<u8>,
+    pub accounts: Vec<Pubkey>,
+}
+
+// 使用macro生成UnifiedEvent实现
+impl_unified_event!(RawInstructionEvent,);
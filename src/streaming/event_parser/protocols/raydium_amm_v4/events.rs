@@ -1,11 +1,16 @@
 use crate::streaming::event_parser::common::EventMetadata;
 use crate::{
-    impl_unified_event, streaming::event_parser::protocols::raydium_amm_v4::types::AmmInfo,
+    impl_unified_event,
+    streaming::event_parser::protocols::raydium_amm_v4::types::{
+        AmmInfo, SWAP_DIRECTION_COIN_TO_PC,
+    },
 };
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
-use std::collections::HashMap;
+use std::str::FromStr;
+
+const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
 
 /// Balance change entry for a specific mint/account
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -58,122 +63,98 @@ pub struct RaydiumAmmV4SwapEvent {
     pub user_source_token_account: Pubkey,
     pub user_destination_token_account: Pubkey,
     pub user_source_owner: Pubkey,
+
+    // ray_log 解码结果，交易日志可用时填充
+    pub ray_log_decoded: bool,
+    pub coin_to_pc: bool,
+    pub actual_amount_in: u64,
+    pub actual_amount_out: u64,
+    pub post_pool_coin_amount: u64,
+    pub post_pool_pc_amount: u64,
 }
 
 impl_unified_event!(RaydiumAmmV4SwapEvent,);
 
 impl RaydiumAmmV4SwapEvent {
+    /// 根据 `ray_log` 中的实际数量和交易前储备填充交易结果
+    pub fn apply_swap_log(
+        &mut self,
+        direction: u64,
+        amount_in: u64,
+        amount_out: u64,
+        pool_coin: u64,
+        pool_pc: u64,
+    ) {
+        self.ray_log_decoded = true;
+        self.coin_to_pc = direction == SWAP_DIRECTION_COIN_TO_PC;
+        self.actual_amount_in = amount_in;
+        self.actual_amount_out = amount_out;
+        if self.coin_to_pc {
+            self.post_pool_coin_amount = pool_coin.saturating_add(amount_in);
+            self.post_pool_pc_amount = pool_pc.saturating_sub(amount_out);
+        } else {
+            self.post_pool_coin_amount = pool_coin.saturating_sub(amount_out);
+            self.post_pool_pc_amount = pool_pc.saturating_add(amount_in);
+        }
+        let swap_data = self.metadata.swap_data.get_or_insert_with(Default::default);
+        swap_data.from_amount = amount_in;
+        swap_data.to_amount = amount_out;
+    }
+
     /// Calculate balance changes for the transaction user
     pub fn calculate_balance_changes(&self, user_address: &str) -> Vec<BalanceChangeEntry> {
         let mut balance_changes = Vec::new();
-        const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
 
-        // Extract raw transaction from metadata if available
-        let Some(raw_tx_wrapper) = &self.metadata.raw_transaction else { 
-            return balance_changes; 
+        let Ok(user) = Pubkey::from_str(user_address) else {
+            return balance_changes;
         };
-        
-        // Serialize the inner transaction to JSON for processing
-        let tx_json = match serde_json::to_value(&raw_tx_wrapper.inner) {
-            Ok(json) => json,
-            Err(_) => return balance_changes,
+        // Prefer balance changes attached during parsing, fall back to raw transaction data
+        let changes = match &self.metadata.balance_changes {
+            Some(changes) if changes.owner == user => Some(changes.clone()),
+            _ => self
+                .metadata
+                .raw_transaction
+                .as_ref()
+                .and_then(|raw_transaction| raw_transaction.balance_changes(&user)),
         };
-
-        let Some(meta) = tx_json.get("meta") else { return balance_changes; };
-
-        // 1) Pull the REAL message.accountKeys so indices match pre/postBalances
-        let real_keys: Vec<String> = tx_json.get("transaction")
-            .and_then(|t| t.get("message"))
-            .and_then(|m| m.get("accountKeys"))
-            .and_then(|ks| ks.as_array())
-            .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
-            .unwrap_or_default();
-
-        // Find signer index in the REAL key order (or fall back to fee payer index 0)
-        let signer_index = real_keys
-            .iter()
-            .position(|k| k == user_address)
-            .unwrap_or(0);
-
-        // 2) Native SOL (lamports) delta for signer
-        let (mut native_pre, mut native_post) = (0u64, 0u64);
-        if let (Some(pre), Some(post)) = (meta.get("preBalances"), meta.get("postBalances")) {
-            if let (Some(pre_arr), Some(post_arr)) = (pre.as_array(), post.as_array()) {
-                native_pre = pre_arr.get(signer_index).and_then(|b| b.as_u64()).unwrap_or(0);
-                native_post = post_arr.get(signer_index).and_then(|b| b.as_u64()).unwrap_or(0);
-            }
-        }
-
-        // 3) Token deltas owned by the signer (including wSOL ONLY if owned by signer)
-        let mut signer_wsol_delta: i64 = 0;
-        let mut token_changes: HashMap<String, (u64, u64)> = HashMap::new();
-
-        let pull_balances = |field: &str, map: &mut HashMap<String, (u64, u64)>, signer: &str, wsol_delta_out: &mut i64| {
-            if let Some(arr) = meta.get(field).and_then(|v| v.as_array()) {
-                for t in arr {
-                    let owner = t.get("owner").and_then(|o| o.as_str()).unwrap_or("");
-                    let mint = t.get("mint").and_then(|m| m.as_str()).unwrap_or("");
-                    let amt_str = t
-                        .get("uiTokenAmount")
-                        .and_then(|ui| ui.get("amount"))
-                        .and_then(|a| a.as_str())
-                        .unwrap_or("0");
-
-                    if owner == signer {
-                        if let Ok(amount) = amt_str.parse::<u64>() {
-                            let entry = map.entry(mint.to_string()).or_insert((0, 0));
-                            if field == "preTokenBalances" {
-                                entry.0 = amount;
-                            } else {
-                                entry.1 = amount;
-                            }
-                        }
-                    }
-
-                    // Track wSOL only when it's actually *owned by the signer*
-                    if owner == signer && mint == WSOL_MINT {
-                        if let Ok(amount) = amt_str.parse::<u64>() {
-                            if field == "preTokenBalances" {
-                                *wsol_delta_out -= amount as i64; // will be reconciled with post below
-                            } else {
-                                *wsol_delta_out += amount as i64;
-                            }
-                        }
-                    }
-                }
-            }
+        let Some(changes) = changes else {
+            return balance_changes;
         };
 
-        pull_balances("preTokenBalances", &mut token_changes, user_address, &mut signer_wsol_delta);
-        pull_balances("postTokenBalances", &mut token_changes, user_address, &mut signer_wsol_delta);
-
-        // 4) Emit non-wSOL token changes (owned by signer)
-        for (mint, (pre_amt, post_amt)) in token_changes.into_iter() {
-            if pre_amt != post_amt && mint != WSOL_MINT {
-                balance_changes.push(BalanceChangeEntry {
-                    mint,
-                    balance_pre: pre_amt,
-                    balance_post: post_amt,
-                    change: post_amt as i64 - pre_amt as i64,
-                });
-            }
-        }
-
-        // 5) Summaries
-        // - Native SOL change already includes unwraps via CloseAccount even if the temp wSOL ATA was NOT owned by signer.
-        // - Only add signer_wsol_delta when the signer actually had a wSOL ATA.
-        let combined_pre = native_pre;
-        let combined_post = (native_post as i64 + signer_wsol_delta).max(0) as u64;
-        let combined_change = (combined_post as i64) - (combined_pre as i64);
+        // Native SOL change already includes unwraps via CloseAccount even if the temp
+        // wSOL ATA was NOT owned by signer, so only signer-owned wSOL is added on top.
+        let signer_wsol_delta = changes.token_change(&WSOL_MINT) as i64;
+        let combined_pre = changes.sol_pre;
+        let combined_post = (changes.sol_post as i64 + signer_wsol_delta).max(0) as u64;
 
         // First row: combined view (native + signer-owned wSOL only)
-        balance_changes.insert(0, BalanceChangeEntry {
+        balance_changes.push(BalanceChangeEntry {
             mint: "SOL (native + signer-owned wSOL)".to_string(),
             balance_pre: combined_pre,
             balance_post: combined_post,
-            change: combined_change,
+            change: (combined_post as i64) - (combined_pre as i64),
         });
 
+        // Non-wSOL token changes owned by signer, one row per mint across all its accounts
+        let mut token_rows: Vec<BalanceChangeEntry> = Vec::new();
+        for change in changes.token_changes.iter().filter(|c| c.mint != WSOL_MINT) {
+            let mint = change.mint.to_string();
+            match token_rows.iter_mut().find(|entry| entry.mint == mint) {
+                Some(entry) => {
+                    entry.balance_pre += change.pre_amount;
+                    entry.balance_post += change.post_amount;
+                    entry.change += change.change as i64;
+                }
+                None => token_rows.push(BalanceChangeEntry {
+                    mint,
+                    balance_pre: change.pre_amount,
+                    balance_post: change.post_amount,
+                    change: change.change as i64,
+                }),
+            }
+        }
+        balance_changes.extend(token_rows.into_iter().filter(|entry| entry.change != 0));
+
         balance_changes
     }
 
@@ -309,6 +290,15 @@ pub struct RaydiumAmmV4DepositEvent {
     pub user_lp_token_account: Pubkey,
     pub user_owner: Pubkey,
     pub serum_event_queue: Pubkey,
+
+    // ray_log 解码结果，交易日志可用时填充
+    pub ray_log_decoded: bool,
+    pub actual_coin_amount: u64,
+    pub actual_pc_amount: u64,
+    pub mint_lp_amount: u64,
+    pub post_pool_coin_amount: u64,
+    pub post_pool_pc_amount: u64,
+    pub post_pool_lp_amount: u64,
 }
 impl_unified_event!(RaydiumAmmV4DepositEvent,);
 
@@ -343,6 +333,15 @@ pub struct RaydiumAmmV4Initialize2Event {
     pub user_token_coin: Pubkey,
     pub user_token_pc: Pubkey,
     pub user_lp_token_account: Pubkey,
+
+    // ray_log 解码结果，交易日志可用时填充
+    pub ray_log_decoded: bool,
+    pub pc_decimals: u8,
+    pub coin_decimals: u8,
+    pub pc_lot_size: u64,
+    pub coin_lot_size: u64,
+    pub post_pool_coin_amount: u64,
+    pub post_pool_pc_amount: u64,
 }
 impl_unified_event!(RaydiumAmmV4Initialize2Event,);
 
@@ -375,6 +374,14 @@ pub struct RaydiumAmmV4WithdrawEvent {
     pub serum_event_queue: Pubkey,
     pub serum_bids: Pubkey,
     pub serum_asks: Pubkey,
+
+    // ray_log 解码结果，交易日志可用时填充
+    pub ray_log_decoded: bool,
+    pub out_coin_amount: u64,
+    pub out_pc_amount: u64,
+    pub post_pool_coin_amount: u64,
+    pub post_pool_pc_amount: u64,
+    pub post_pool_lp_amount: u64,
 }
 impl_unified_event!(RaydiumAmmV4WithdrawEvent,);
 
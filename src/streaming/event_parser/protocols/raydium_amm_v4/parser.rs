@@ -161,6 +161,7 @@ impl RaydiumAmmV4EventParser {
             serum_event_queue: accounts[19],
             serum_bids: accounts[20],
             serum_asks: accounts[21],
+            ..Default::default()
         }))
     }
 
@@ -206,6 +207,7 @@ impl RaydiumAmmV4EventParser {
             user_token_coin: accounts[18],
             user_token_pc: accounts[19],
             user_lp_token_account: accounts[20],
+            ..Default::default()
         }))
     }
 
@@ -242,6 +244,7 @@ impl RaydiumAmmV4EventParser {
             user_lp_token_account: accounts[11],
             user_owner: accounts[12],
             serum_event_queue: accounts[13],
+            ..Default::default()
         }))
     }
 
@@ -1,10 +1,18 @@
+use std::collections::HashMap;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::{
     event_parser::{
-        common::EventMetadata, protocols::raydium_amm_v4::RaydiumAmmV4AmmInfoAccountEvent,
+        common::{collect_program_logs, EventMetadata},
+        protocols::raydium_amm_v4::{
+            parser::RAYDIUM_AMM_V4_PROGRAM_ID, RaydiumAmmV4AmmInfoAccountEvent,
+            RaydiumAmmV4DepositEvent, RaydiumAmmV4Initialize2Event, RaydiumAmmV4SwapEvent,
+            RaydiumAmmV4WithdrawEvent,
+        },
         UnifiedEvent,
     },
     grpc::AccountPretty,
@@ -142,3 +150,271 @@ pub fn market_state_decode(data: &[u8]) -> Option<MarketState> {
     }
     borsh::from_slice::<MarketState>(&data[..MARKET_STATE_SIZE]).ok()
 }
+
+/// `ray_log` 日志前缀
+pub const RAY_LOG_PREFIX: &str = "Program log: ray_log: ";
+
+/// 初始化日志
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitLog {
+    pub time: u64,
+    pub pc_decimals: u8,
+    pub coin_decimals: u8,
+    pub pc_lot_size: u64,
+    pub coin_lot_size: u64,
+    pub pc_amount: u64,
+    pub coin_amount: u64,
+    pub market: Pubkey,
+}
+
+/// 添加流动性日志，池子数量为操作前的值
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DepositLog {
+    pub max_coin: u64,
+    pub max_pc: u64,
+    pub base: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub pool_lp: u64,
+    pub calc_pnl_x: u128,
+    pub calc_pnl_y: u128,
+    pub deduct_coin: u64,
+    pub deduct_pc: u64,
+    pub mint_lp: u64,
+}
+
+/// 移除流动性日志，池子数量为操作前的值
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawLog {
+    pub withdraw_lp: u64,
+    pub user_lp: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub pool_lp: u64,
+    pub calc_pnl_x: u128,
+    pub calc_pnl_y: u128,
+    pub out_coin: u64,
+    pub out_pc: u64,
+}
+
+/// 固定输入交易日志，池子数量为交易前的值
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapBaseInLog {
+    pub amount_in: u64,
+    pub minimum_out: u64,
+    pub direction: u64,
+    pub user_source: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub out_amount: u64,
+}
+
+/// 固定输出交易日志，池子数量为交易前的值
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapBaseOutLog {
+    pub max_in: u64,
+    pub amount_out: u64,
+    pub direction: u64,
+    pub user_source: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub deduct_in: u64,
+}
+
+/// 交易方向：coin 换 pc
+pub const SWAP_DIRECTION_COIN_TO_PC: u64 = 1;
+
+/// AMM v4 通过 `ray_log` 输出的日志
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RayLog {
+    Init(InitLog),
+    Deposit(DepositLog),
+    Withdraw(WithdrawLog),
+    SwapBaseIn(SwapBaseInLog),
+    SwapBaseOut(SwapBaseOutLog),
+}
+
+/// 解码 `ray_log` 的 base64 内容，首字节为日志类型
+pub fn ray_log_decode(log: &str) -> Option<RayLog> {
+    let data = STANDARD.decode(log.trim()).ok()?;
+    let (log_type, data) = data.split_first()?;
+    let mut data = data;
+    match log_type {
+        0 => <InitLog as BorshDeserialize>::deserialize(&mut data).ok().map(RayLog::Init),
+        1 => <DepositLog as BorshDeserialize>::deserialize(&mut data).ok().map(RayLog::Deposit),
+        2 => <WithdrawLog as BorshDeserialize>::deserialize(&mut data).ok().map(RayLog::Withdraw),
+        3 => {
+            <SwapBaseInLog as BorshDeserialize>::deserialize(&mut data).ok().map(RayLog::SwapBaseIn)
+        }
+        4 => <SwapBaseOutLog as BorshDeserialize>::deserialize(&mut data)
+            .ok()
+            .map(RayLog::SwapBaseOut),
+        _ => None,
+    }
+}
+
+/// 按 (外层指令索引, 内层指令索引) 收集交易日志中的 `ray_log`
+pub fn collect_ray_logs(log_messages: &[String]) -> HashMap<(i64, Option<i64>), RayLog> {
+    if !log_messages.iter().any(|log| log.starts_with(RAY_LOG_PREFIX)) {
+        return HashMap::new();
+    }
+    let program_id = RAYDIUM_AMM_V4_PROGRAM_ID.to_string();
+    collect_program_logs(log_messages, &program_id, RAY_LOG_PREFIX)
+        .into_iter()
+        .filter_map(|(outer_index, inner_index, log)| {
+            Some(((outer_index, inner_index), ray_log_decode(log)?))
+        })
+        .collect()
+}
+
+/// 将 `ray_log` 解码结果写入对应的 AMM v4 指令事件，返回是否匹配
+pub fn apply_ray_log(event: &mut dyn UnifiedEvent, log: &RayLog) -> bool {
+    let any = event.as_any_mut();
+    match log {
+        RayLog::SwapBaseIn(log) => {
+            let Some(e) = any.downcast_mut::<RaydiumAmmV4SwapEvent>() else {
+                return false;
+            };
+            e.apply_swap_log(
+                log.direction,
+                log.amount_in,
+                log.out_amount,
+                log.pool_coin,
+                log.pool_pc,
+            );
+        }
+        RayLog::SwapBaseOut(log) => {
+            let Some(e) = any.downcast_mut::<RaydiumAmmV4SwapEvent>() else {
+                return false;
+            };
+            e.apply_swap_log(
+                log.direction,
+                log.deduct_in,
+                log.amount_out,
+                log.pool_coin,
+                log.pool_pc,
+            );
+        }
+        RayLog::Deposit(log) => {
+            let Some(e) = any.downcast_mut::<RaydiumAmmV4DepositEvent>() else {
+                return false;
+            };
+            e.ray_log_decoded = true;
+            e.actual_coin_amount = log.deduct_coin;
+            e.actual_pc_amount = log.deduct_pc;
+            e.mint_lp_amount = log.mint_lp;
+            e.post_pool_coin_amount = log.pool_coin.saturating_add(log.deduct_coin);
+            e.post_pool_pc_amount = log.pool_pc.saturating_add(log.deduct_pc);
+            e.post_pool_lp_amount = log.pool_lp.saturating_add(log.mint_lp);
+        }
+        RayLog::Withdraw(log) => {
+            let Some(e) = any.downcast_mut::<RaydiumAmmV4WithdrawEvent>() else {
+                return false;
+            };
+            e.ray_log_decoded = true;
+            e.out_coin_amount = log.out_coin;
+            e.out_pc_amount = log.out_pc;
+            e.post_pool_coin_amount = log.pool_coin.saturating_sub(log.out_coin);
+            e.post_pool_pc_amount = log.pool_pc.saturating_sub(log.out_pc);
+            e.post_pool_lp_amount = log.pool_lp.saturating_sub(log.withdraw_lp);
+        }
+        RayLog::Init(log) => {
+            let Some(e) = any.downcast_mut::<RaydiumAmmV4Initialize2Event>() else {
+                return false;
+            };
+            e.ray_log_decoded = true;
+            e.pc_decimals = log.pc_decimals;
+            e.coin_decimals = log.coin_decimals;
+            e.pc_lot_size = log.pc_lot_size;
+            e.coin_lot_size = log.coin_lot_size;
+            e.post_pool_coin_amount = log.coin_amount;
+            e.post_pool_pc_amount = log.pc_amount;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 按 ray_log 布局构造：类型字节 + 7 个 u64（小端）的 base64
+    const SWAP_BASE_IN_LOG: &str =
+        "AwBlzR0AAAAAWP+VSQAAAAACAAAAAAAAAABlzR0AAAAA906HfwomAACxKJBlFAEAAJNfoUMDAAAA";
+    const SWAP_BASE_OUT_LOG: &str =
+        "BACUNXcAAAAAAHQ7pAsAAAABAAAAAAAAAABe0LIAAAAA906HfwomAACxKJBlFAEAAFP9GGoAAAAA";
+
+    fn swap_logs() -> Vec<String> {
+        let amm = RAYDIUM_AMM_V4_PROGRAM_ID.to_string();
+        let router = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+        let token = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+        vec![
+            "Program ComputeBudget111111111111111111111111111111 invoke [1]".to_string(),
+            "Program ComputeBudget111111111111111111111111111111 success".to_string(),
+            format!("Program {router} invoke [1]"),
+            format!("Program {amm} invoke [2]"),
+            format!("{RAY_LOG_PREFIX}{SWAP_BASE_IN_LOG}"),
+            format!("Program {token} invoke [3]"),
+            format!("Program {token} success"),
+            format!("Program {amm} success"),
+            format!("Program {router} success"),
+            format!("Program {amm} invoke [1]"),
+            format!("{RAY_LOG_PREFIX}{SWAP_BASE_OUT_LOG}"),
+            format!("Program {amm} success"),
+        ]
+    }
+
+    #[test]
+    fn ray_log_decode_swap_base_in() {
+        let log = ray_log_decode(SWAP_BASE_IN_LOG).unwrap();
+        assert_eq!(
+            log,
+            RayLog::SwapBaseIn(SwapBaseInLog {
+                amount_in: 500_000_000,
+                minimum_out: 1_234_567_000,
+                direction: 2,
+                user_source: 500_000_000,
+                pool_coin: 41_826_531_102_455,
+                pool_pc: 1_187_114_920_113,
+                out_amount: 14_019_551_123,
+            })
+        );
+        assert!(ray_log_decode("AwBlzR0AAAAA").is_none());
+        assert!(ray_log_decode("not base64").is_none());
+    }
+
+    #[test]
+    fn collect_ray_logs_by_instruction_position() {
+        let logs = collect_ray_logs(&swap_logs());
+        assert_eq!(logs.len(), 2);
+        assert!(matches!(logs.get(&(1, Some(0))), Some(RayLog::SwapBaseIn(_))));
+        assert!(matches!(logs.get(&(2, None)), Some(RayLog::SwapBaseOut(_))));
+    }
+
+    #[test]
+    fn apply_ray_log_fills_swap_event() {
+        let logs = collect_ray_logs(&swap_logs());
+
+        let mut event = RaydiumAmmV4SwapEvent::default();
+        assert!(apply_ray_log(&mut event, &logs[&(1, Some(0))]));
+        assert!(event.ray_log_decoded);
+        assert!(!event.coin_to_pc);
+        assert_eq!(event.actual_amount_in, 500_000_000);
+        assert_eq!(event.actual_amount_out, 14_019_551_123);
+        assert_eq!(event.post_pool_coin_amount, 41_812_511_551_332);
+        assert_eq!(event.post_pool_pc_amount, 1_187_614_920_113);
+        let swap_data = event.metadata.swap_data.as_ref().unwrap();
+        assert_eq!((swap_data.from_amount, swap_data.to_amount), (500_000_000, 14_019_551_123));
+
+        let mut event = RaydiumAmmV4SwapEvent::default();
+        assert!(apply_ray_log(&mut event, &logs[&(2, None)]));
+        assert!(event.coin_to_pc);
+        assert_eq!(event.actual_amount_in, 1_780_022_611);
+        assert_eq!(event.actual_amount_out, 50_000_000_000);
+        assert_eq!(event.post_pool_coin_amount, 41_828_311_125_066);
+        assert_eq!(event.post_pool_pc_amount, 1_137_114_920_113);
+
+        let mut deposit = RaydiumAmmV4DepositEvent::default();
+        assert!(!apply_ray_log(&mut deposit, &logs[&(2, None)]));
+    }
+}
@@ -1,5 +1,7 @@
 use crate::streaming::event_parser::common::EventMetadata;
-use crate::streaming::event_parser::protocols::raydium_clmm::types::{PoolState, TickArrayState};
+use crate::streaming::event_parser::protocols::raydium_clmm::types::{
+    ObservationState, PersonalPositionState, PoolState, ProtocolPositionState, TickArrayState,
+};
 use crate::{
     impl_unified_event, streaming::event_parser::protocols::raydium_clmm::types::AmmConfig,
 };
@@ -219,6 +221,164 @@ pub struct RaydiumClmmOpenPositionV2Event {
 }
 impl_unified_event!(RaydiumClmmOpenPositionV2Event,);
 
+/// 打开仓位
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmOpenPositionEvent {
+    pub metadata: EventMetadata,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub tick_array_lower_start_index: i32,
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    pub amount0_max: u64,
+    pub amount1_max: u64,
+
+    pub payer: Pubkey,
+    pub position_nft_owner: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub metadata_account: Pubkey,
+    pub pool_state: Pubkey,
+    pub protocol_position: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+    pub personal_position: Pubkey,
+    pub token_account0: Pubkey,
+    pub token_account1: Pubkey,
+    pub token_vault0: Pubkey,
+    pub token_vault1: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub associated_token_program: Pubkey,
+    pub metadata_program: Pubkey,
+    pub remaining_accounts: Vec<Pubkey>,
+}
+impl_unified_event!(RaydiumClmmOpenPositionEvent,);
+
+/// 增加流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmIncreaseLiquidityEvent {
+    pub metadata: EventMetadata,
+    pub liquidity: u128,
+    pub amount0_max: u64,
+    pub amount1_max: u64,
+    pub nft_owner: Pubkey,
+    pub nft_account: Pubkey,
+    pub pool_state: Pubkey,
+    pub protocol_position: Pubkey,
+    pub personal_position: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+    pub token_account0: Pubkey,
+    pub token_account1: Pubkey,
+    pub token_vault0: Pubkey,
+    pub token_vault1: Pubkey,
+    pub token_program: Pubkey,
+}
+impl_unified_event!(RaydiumClmmIncreaseLiquidityEvent,);
+
+/// 减少流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmDecreaseLiquidityEvent {
+    pub metadata: EventMetadata,
+    pub liquidity: u128,
+    pub amount0_min: u64,
+    pub amount1_min: u64,
+    pub nft_owner: Pubkey,
+    pub nft_account: Pubkey,
+    pub personal_position: Pubkey,
+    pub pool_state: Pubkey,
+    pub protocol_position: Pubkey,
+    pub token_vault0: Pubkey,
+    pub token_vault1: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+    pub recipient_token_account0: Pubkey,
+    pub recipient_token_account1: Pubkey,
+    pub token_program: Pubkey,
+    pub remaining_accounts: Vec<Pubkey>,
+}
+impl_unified_event!(RaydiumClmmDecreaseLiquidityEvent,);
+
+/// 领取剩余奖励
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmCollectRemainingRewardsEvent {
+    pub metadata: EventMetadata,
+    pub reward_index: u8,
+    pub reward_funder: Pubkey,
+    pub funder_token_account: Pubkey,
+    pub pool_state: Pubkey,
+    pub reward_token_vault: Pubkey,
+    pub reward_vault_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
+    pub memo_program: Pubkey,
+}
+impl_unified_event!(RaydiumClmmCollectRemainingRewardsEvent,);
+
+/// 多跳路由交易（固定输入）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmSwapRouterBaseInEvent {
+    pub metadata: EventMetadata,
+    pub amount_in: u64,
+    pub amount_out_minimum: u64,
+    pub payer: Pubkey,
+    pub input_token_account: Pubkey,
+    pub input_token_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
+    pub memo_program: Pubkey,
+    pub remaining_accounts: Vec<Pubkey>,
+}
+impl_unified_event!(RaydiumClmmSwapRouterBaseInEvent,);
+
+/// 初始化奖励
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmInitializeRewardEvent {
+    pub metadata: EventMetadata,
+    pub open_time: u64,
+    pub end_time: u64,
+    pub emissions_per_second_x64: u128,
+    pub reward_funder: Pubkey,
+    pub funder_token_account: Pubkey,
+    pub amm_config: Pubkey,
+    pub pool_state: Pubkey,
+    pub operation_state: Pubkey,
+    pub reward_token_mint: Pubkey,
+    pub reward_token_vault: Pubkey,
+    pub reward_token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub rent: Pubkey,
+}
+impl_unified_event!(RaydiumClmmInitializeRewardEvent,);
+
+/// 设置奖励参数
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmSetRewardParamsEvent {
+    pub metadata: EventMetadata,
+    pub reward_index: u8,
+    pub emissions_per_second_x64: u128,
+    pub open_time: u64,
+    pub end_time: u64,
+    pub authority: Pubkey,
+    pub amm_config: Pubkey,
+    pub pool_state: Pubkey,
+    pub operation_state: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
+    pub remaining_accounts: Vec<Pubkey>,
+}
+impl_unified_event!(RaydiumClmmSetRewardParamsEvent,);
+
+/// 更新奖励信息
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmUpdateRewardInfosEvent {
+    pub metadata: EventMetadata,
+    pub pool_state: Pubkey,
+}
+impl_unified_event!(RaydiumClmmUpdateRewardInfosEvent,);
+
 /// 池配置
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RaydiumClmmAmmConfigAccountEvent {
@@ -258,6 +418,45 @@ pub struct RaydiumClmmTickArrayStateAccountEvent {
 }
 impl_unified_event!(RaydiumClmmTickArrayStateAccountEvent,);
 
+/// 个人仓位
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmPersonalPositionStateAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub personal_position_state: PersonalPositionState,
+}
+impl_unified_event!(RaydiumClmmPersonalPositionStateAccountEvent,);
+
+/// 协议仓位
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmProtocolPositionStateAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub protocol_position_state: ProtocolPositionState,
+}
+impl_unified_event!(RaydiumClmmProtocolPositionStateAccountEvent,);
+
+/// 价格观测
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmObservationStateAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub observation_state: ObservationState,
+}
+impl_unified_event!(RaydiumClmmObservationStateAccountEvent,);
+
 /// 事件鉴别器常量
 pub mod discriminators {
     // 指令鉴别器
@@ -269,11 +468,22 @@ pub mod discriminators {
     pub const CREATE_POOL: &[u8] = &[233, 146, 209, 142, 207, 104, 64, 188];
     pub const OPEN_POSITION_WITH_TOKEN_22_NFT: &[u8] = &[77, 255, 174, 82, 125, 29, 201, 46];
     pub const OPEN_POSITION_V2: &[u8] = &[77, 184, 74, 214, 112, 86, 241, 199];
+    pub const OPEN_POSITION: &[u8] = &[135, 128, 47, 77, 15, 152, 240, 49];
+    pub const INCREASE_LIQUIDITY: &[u8] = &[46, 156, 243, 118, 13, 205, 251, 178];
+    pub const DECREASE_LIQUIDITY: &[u8] = &[160, 38, 208, 111, 104, 91, 44, 1];
+    pub const COLLECT_REMAINING_REWARDS: &[u8] = &[18, 237, 166, 197, 34, 16, 213, 144];
+    pub const SWAP_ROUTER_BASE_IN: &[u8] = &[69, 125, 115, 218, 245, 186, 242, 196];
+    pub const INITIALIZE_REWARD: &[u8] = &[95, 135, 192, 196, 242, 129, 230, 68];
+    pub const SET_REWARD_PARAMS: &[u8] = &[112, 52, 167, 75, 32, 201, 211, 137];
+    pub const UPDATE_REWARD_INFOS: &[u8] = &[163, 172, 224, 52, 11, 154, 106, 223];
 
     // 账号鉴别器
     pub const AMM_CONFIG: &[u8] = &[218, 244, 33, 104, 203, 203, 43, 111];
     pub const POOL_STATE: &[u8] = &[247, 237, 227, 245, 215, 195, 222, 70];
     pub const TICK_ARRAY_STATE: &[u8] = &[192, 155, 85, 205, 49, 249, 129, 42];
+    pub const PERSONAL_POSITION_STATE: &[u8] = &[70, 111, 150, 126, 230, 15, 25, 117];
+    pub const PROTOCOL_POSITION_STATE: &[u8] = &[100, 226, 145, 99, 146, 218, 160, 106];
+    pub const OBSERVATION_STATE: &[u8] = &[122, 174, 197, 53, 129, 9, 165, 132];
 }
 
 use crate::streaming::event_parser::protocols::raydium_cpmm::types::{
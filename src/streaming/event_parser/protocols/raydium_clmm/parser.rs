@@ -9,10 +9,14 @@ use crate::{
         },
         core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
         protocols::raydium_clmm::{
-            discriminators, RaydiumClmmClosePositionEvent, RaydiumClmmCreatePoolEvent,
-            RaydiumClmmDecreaseLiquidityV2Event, RaydiumClmmIncreaseLiquidityV2Event,
-            RaydiumClmmOpenPositionV2Event, RaydiumClmmOpenPositionWithToken22NftEvent,
-            RaydiumClmmSwapEvent, RaydiumClmmSwapV2Event,
+            discriminators, RaydiumClmmClosePositionEvent, RaydiumClmmCollectRemainingRewardsEvent,
+            RaydiumClmmCreatePoolEvent, RaydiumClmmDecreaseLiquidityEvent,
+            RaydiumClmmDecreaseLiquidityV2Event, RaydiumClmmIncreaseLiquidityEvent,
+            RaydiumClmmIncreaseLiquidityV2Event, RaydiumClmmInitializeRewardEvent,
+            RaydiumClmmOpenPositionEvent, RaydiumClmmOpenPositionV2Event,
+            RaydiumClmmOpenPositionWithToken22NftEvent, RaydiumClmmSetRewardParamsEvent,
+            RaydiumClmmSwapEvent, RaydiumClmmSwapRouterBaseInEvent, RaydiumClmmSwapV2Event,
+            RaydiumClmmUpdateRewardInfosEvent,
         },
     },
 };
@@ -108,6 +112,78 @@ impl RaydiumClmmEventParser {
                 inner_instruction_parser: None,
                 instruction_parser: Some(Self::parse_open_position_v2_instruction),
             },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumClmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::OPEN_POSITION,
+                event_type: EventType::RaydiumClmmOpenPosition,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_open_position_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumClmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::INCREASE_LIQUIDITY,
+                event_type: EventType::RaydiumClmmIncreaseLiquidity,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_increase_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumClmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::DECREASE_LIQUIDITY,
+                event_type: EventType::RaydiumClmmDecreaseLiquidity,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_decrease_liquidity_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumClmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::COLLECT_REMAINING_REWARDS,
+                event_type: EventType::RaydiumClmmCollectRemainingRewards,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_collect_remaining_rewards_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumClmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::SWAP_ROUTER_BASE_IN,
+                event_type: EventType::RaydiumClmmSwapRouterBaseIn,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_swap_router_base_in_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumClmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::INITIALIZE_REWARD,
+                event_type: EventType::RaydiumClmmInitializeReward,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_initialize_reward_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumClmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::SET_REWARD_PARAMS,
+                event_type: EventType::RaydiumClmmSetRewardParams,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_set_reward_params_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CLMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumClmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::UPDATE_REWARD_INFOS,
+                event_type: EventType::RaydiumClmmUpdateRewardInfos,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_update_reward_infos_instruction),
+            },
         ];
 
         let inner = GenericEventParser::new(vec![RAYDIUM_CLMM_PROGRAM_ID], configs);
@@ -320,6 +396,216 @@ impl RaydiumClmmEventParser {
         }))
     }
 
+    /// 解析打开仓位指令事件
+    fn parse_open_position_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 48 || accounts.len() < 19 {
+            return None;
+        }
+        Some(Box::new(RaydiumClmmOpenPositionEvent {
+            metadata,
+            tick_lower_index: read_i32_le(data, 0)?,
+            tick_upper_index: read_i32_le(data, 4)?,
+            tick_array_lower_start_index: read_i32_le(data, 8)?,
+            tick_array_upper_start_index: read_i32_le(data, 12)?,
+            liquidity: read_u128_le(data, 16)?,
+            amount0_max: read_u64_le(data, 32)?,
+            amount1_max: read_u64_le(data, 40)?,
+            payer: accounts[0],
+            position_nft_owner: accounts[1],
+            position_nft_mint: accounts[2],
+            position_nft_account: accounts[3],
+            metadata_account: accounts[4],
+            pool_state: accounts[5],
+            protocol_position: accounts[6],
+            tick_array_lower: accounts[7],
+            tick_array_upper: accounts[8],
+            personal_position: accounts[9],
+            token_account0: accounts[10],
+            token_account1: accounts[11],
+            token_vault0: accounts[12],
+            token_vault1: accounts[13],
+            rent: accounts[14],
+            system_program: accounts[15],
+            token_program: accounts[16],
+            associated_token_program: accounts[17],
+            metadata_program: accounts[18],
+            remaining_accounts: accounts[19..].to_vec(),
+        }))
+    }
+
+    /// 解析增加流动性指令事件
+    fn parse_increase_liquidity_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 32 || accounts.len() < 12 {
+            return None;
+        }
+        Some(Box::new(RaydiumClmmIncreaseLiquidityEvent {
+            metadata,
+            liquidity: read_u128_le(data, 0)?,
+            amount0_max: read_u64_le(data, 16)?,
+            amount1_max: read_u64_le(data, 24)?,
+            nft_owner: accounts[0],
+            nft_account: accounts[1],
+            pool_state: accounts[2],
+            protocol_position: accounts[3],
+            personal_position: accounts[4],
+            tick_array_lower: accounts[5],
+            tick_array_upper: accounts[6],
+            token_account0: accounts[7],
+            token_account1: accounts[8],
+            token_vault0: accounts[9],
+            token_vault1: accounts[10],
+            token_program: accounts[11],
+        }))
+    }
+
+    /// 解析减少流动性指令事件
+    fn parse_decrease_liquidity_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 32 || accounts.len() < 12 {
+            return None;
+        }
+        Some(Box::new(RaydiumClmmDecreaseLiquidityEvent {
+            metadata,
+            liquidity: read_u128_le(data, 0)?,
+            amount0_min: read_u64_le(data, 16)?,
+            amount1_min: read_u64_le(data, 24)?,
+            nft_owner: accounts[0],
+            nft_account: accounts[1],
+            personal_position: accounts[2],
+            pool_state: accounts[3],
+            protocol_position: accounts[4],
+            token_vault0: accounts[5],
+            token_vault1: accounts[6],
+            tick_array_lower: accounts[7],
+            tick_array_upper: accounts[8],
+            recipient_token_account0: accounts[9],
+            recipient_token_account1: accounts[10],
+            token_program: accounts[11],
+            remaining_accounts: accounts[12..].to_vec(),
+        }))
+    }
+
+    /// 解析领取剩余奖励指令事件
+    fn parse_collect_remaining_rewards_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.is_empty() || accounts.len() < 8 {
+            return None;
+        }
+        Some(Box::new(RaydiumClmmCollectRemainingRewardsEvent {
+            metadata,
+            reward_index: read_u8_le(data, 0)?,
+            reward_funder: accounts[0],
+            funder_token_account: accounts[1],
+            pool_state: accounts[2],
+            reward_token_vault: accounts[3],
+            reward_vault_mint: accounts[4],
+            token_program: accounts[5],
+            token_program2022: accounts[6],
+            memo_program: accounts[7],
+        }))
+    }
+
+    /// 解析多跳路由交易指令事件
+    fn parse_swap_router_base_in_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 16 || accounts.len() < 6 {
+            return None;
+        }
+        Some(Box::new(RaydiumClmmSwapRouterBaseInEvent {
+            metadata,
+            amount_in: read_u64_le(data, 0)?,
+            amount_out_minimum: read_u64_le(data, 8)?,
+            payer: accounts[0],
+            input_token_account: accounts[1],
+            input_token_mint: accounts[2],
+            token_program: accounts[3],
+            token_program2022: accounts[4],
+            memo_program: accounts[5],
+            remaining_accounts: accounts[6..].to_vec(),
+        }))
+    }
+
+    /// 解析初始化奖励指令事件
+    fn parse_initialize_reward_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 32 || accounts.len() < 10 {
+            return None;
+        }
+        Some(Box::new(RaydiumClmmInitializeRewardEvent {
+            metadata,
+            open_time: read_u64_le(data, 0)?,
+            end_time: read_u64_le(data, 8)?,
+            emissions_per_second_x64: read_u128_le(data, 16)?,
+            reward_funder: accounts[0],
+            funder_token_account: accounts[1],
+            amm_config: accounts[2],
+            pool_state: accounts[3],
+            operation_state: accounts[4],
+            reward_token_mint: accounts[5],
+            reward_token_vault: accounts[6],
+            reward_token_program: accounts[7],
+            system_program: accounts[8],
+            rent: accounts[9],
+        }))
+    }
+
+    /// 解析设置奖励参数指令事件
+    fn parse_set_reward_params_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 33 || accounts.len() < 6 {
+            return None;
+        }
+        Some(Box::new(RaydiumClmmSetRewardParamsEvent {
+            metadata,
+            reward_index: read_u8_le(data, 0)?,
+            emissions_per_second_x64: read_u128_le(data, 1)?,
+            open_time: read_u64_le(data, 17)?,
+            end_time: read_u64_le(data, 25)?,
+            authority: accounts[0],
+            amm_config: accounts[1],
+            pool_state: accounts[2],
+            operation_state: accounts[3],
+            token_program: accounts[4],
+            token_program2022: accounts[5],
+            remaining_accounts: accounts[6..].to_vec(),
+        }))
+    }
+
+    /// 解析更新奖励信息指令事件
+    fn parse_update_reward_infos_instruction(
+        _data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if accounts.is_empty() {
+            return None;
+        }
+        Some(Box::new(RaydiumClmmUpdateRewardInfosEvent { metadata, pool_state: accounts[0] }))
+    }
+
     /// 解析交易指令事件
     fn parse_swap_instruction(
         data: &[u8],
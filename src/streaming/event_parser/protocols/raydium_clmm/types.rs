@@ -6,8 +6,9 @@ use crate::streaming::{
     event_parser::{
         common::EventMetadata,
         protocols::raydium_clmm::{
-            RaydiumClmmAmmConfigAccountEvent, RaydiumClmmPoolStateAccountEvent,
-            RaydiumClmmTickArrayStateAccountEvent,
+            RaydiumClmmAmmConfigAccountEvent, RaydiumClmmObservationStateAccountEvent,
+            RaydiumClmmPersonalPositionStateAccountEvent, RaydiumClmmPoolStateAccountEvent,
+            RaydiumClmmProtocolPositionStateAccountEvent, RaydiumClmmTickArrayStateAccountEvent,
         },
         UnifiedEvent,
     },
@@ -229,3 +230,265 @@ pub fn tick_array_state_parser(
         None
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionRewardInfo {
+    pub growth_inside_last_x64: u128,
+    pub reward_amount_owed: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PersonalPositionState {
+    pub bump: [u8; 1],
+    pub nft_mint: Pubkey,
+    pub pool_id: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub fee_growth_inside0_last_x64: u128,
+    pub fee_growth_inside1_last_x64: u128,
+    pub token_fees_owed0: u64,
+    pub token_fees_owed1: u64,
+    pub reward_infos: [PositionRewardInfo; 3],
+    pub recent_epoch: u64,
+    pub padding: [u64; 7],
+}
+
+pub const PERSONAL_POSITION_STATE_SIZE: usize =
+    1 + 32 * 2 + 4 * 2 + 16 * 3 + 8 * 2 + (16 + 8) * 3 + 8 + 8 * 7;
+
+pub fn personal_position_state_decode(data: &[u8]) -> Option<PersonalPositionState> {
+    if data.len() < PERSONAL_POSITION_STATE_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PersonalPositionState>(&data[..PERSONAL_POSITION_STATE_SIZE]).ok()
+}
+
+pub fn personal_position_state_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < PERSONAL_POSITION_STATE_SIZE + 8 {
+        return None;
+    }
+    let personal_position_state =
+        personal_position_state_decode(&account.data[8..PERSONAL_POSITION_STATE_SIZE + 8])?;
+    Some(Box::new(RaydiumClmmPersonalPositionStateAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        personal_position_state,
+    }))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ProtocolPositionState {
+    pub bump: u8,
+    pub pool_id: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub fee_growth_inside0_last_x64: u128,
+    pub fee_growth_inside1_last_x64: u128,
+    pub token_fees_owed0: u64,
+    pub token_fees_owed1: u64,
+    pub reward_growth_inside: [u128; 3],
+    pub recent_epoch: u64,
+    pub padding: [u64; 7],
+}
+
+pub const PROTOCOL_POSITION_STATE_SIZE: usize =
+    1 + 32 + 4 * 2 + 16 * 3 + 8 * 2 + 16 * 3 + 8 + 8 * 7;
+
+pub fn protocol_position_state_decode(data: &[u8]) -> Option<ProtocolPositionState> {
+    if data.len() < PROTOCOL_POSITION_STATE_SIZE {
+        return None;
+    }
+    borsh::from_slice::<ProtocolPositionState>(&data[..PROTOCOL_POSITION_STATE_SIZE]).ok()
+}
+
+pub fn protocol_position_state_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < PROTOCOL_POSITION_STATE_SIZE + 8 {
+        return None;
+    }
+    let protocol_position_state =
+        protocol_position_state_decode(&account.data[8..PROTOCOL_POSITION_STATE_SIZE + 8])?;
+    Some(Box::new(RaydiumClmmProtocolPositionStateAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        protocol_position_state,
+    }))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Observation {
+    pub block_timestamp: u32,
+    pub tick_cumulative: i64,
+    pub padding: [u64; 4],
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ObservationState {
+    pub initialized: bool,
+    pub recent_epoch: u64,
+    pub observation_index: u16,
+    pub pool_id: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub observations: [Observation; 100],
+    pub padding: [u64; 4],
+}
+
+impl Default for ObservationState {
+    fn default() -> Self {
+        Self {
+            initialized: false,
+            recent_epoch: 0,
+            observation_index: 0,
+            pool_id: Pubkey::default(),
+            observations: core::array::from_fn(|_| Observation::default()),
+            padding: [0; 4],
+        }
+    }
+}
+
+pub const OBSERVATION_STATE_SIZE: usize = 1 + 8 + 2 + 32 + (4 + 8 + 8 * 4) * 100 + 8 * 4;
+
+pub fn observation_state_decode(data: &[u8]) -> Option<ObservationState> {
+    if data.len() < OBSERVATION_STATE_SIZE {
+        return None;
+    }
+    borsh::from_slice::<ObservationState>(&data[..OBSERVATION_STATE_SIZE]).ok()
+}
+
+pub fn observation_state_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < OBSERVATION_STATE_SIZE + 8 {
+        return None;
+    }
+    let observation_state = observation_state_decode(&account.data[8..OBSERVATION_STATE_SIZE + 8])?;
+    Some(Box::new(RaydiumClmmObservationStateAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        observation_state,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按账户布局偏移写入字段，偏移包含 8 字节鉴别器
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[8 + offset..8 + offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[test]
+    fn personal_position_state_layout() {
+        let nft_mint = Pubkey::new_unique();
+        let pool_id = Pubkey::new_unique();
+        let mut data = vec![0u8; PERSONAL_POSITION_STATE_SIZE + 8];
+        put(&mut data, 0, &[254]);
+        put(&mut data, 1, nft_mint.as_ref());
+        put(&mut data, 33, pool_id.as_ref());
+        put(&mut data, 65, &(-120i32).to_le_bytes());
+        put(&mut data, 69, &360i32.to_le_bytes());
+        put(&mut data, 73, &987_654_321u128.to_le_bytes());
+        put(&mut data, 121, &1_500u64.to_le_bytes());
+        put(&mut data, 129, &2_500u64.to_le_bytes());
+        put(&mut data, 185, &(1u128 << 64).to_le_bytes());
+        put(&mut data, 201, &42u64.to_le_bytes());
+        put(&mut data, 209, &812u64.to_le_bytes());
+        let account = AccountPretty { data, ..Default::default() };
+
+        let event = personal_position_state_parser(&account, EventMetadata::default()).unwrap();
+        let position = &event
+            .as_any()
+            .downcast_ref::<RaydiumClmmPersonalPositionStateAccountEvent>()
+            .unwrap()
+            .personal_position_state;
+        assert_eq!(position.bump, [254]);
+        assert_eq!(position.nft_mint, nft_mint);
+        assert_eq!(position.pool_id, pool_id);
+        assert_eq!(position.tick_lower_index, -120);
+        assert_eq!(position.tick_upper_index, 360);
+        assert_eq!(position.liquidity, 987_654_321);
+        assert_eq!(position.token_fees_owed0, 1_500);
+        assert_eq!(position.token_fees_owed1, 2_500);
+        assert_eq!(position.reward_infos[2].growth_inside_last_x64, 1u128 << 64);
+        assert_eq!(position.reward_infos[2].reward_amount_owed, 42);
+        assert_eq!(position.recent_epoch, 812);
+    }
+
+    #[test]
+    fn protocol_position_state_layout() {
+        let pool_id = Pubkey::new_unique();
+        let mut data = vec![0u8; PROTOCOL_POSITION_STATE_SIZE + 8];
+        put(&mut data, 0, &[253]);
+        put(&mut data, 1, pool_id.as_ref());
+        put(&mut data, 33, &(-60i32).to_le_bytes());
+        put(&mut data, 37, &60i32.to_le_bytes());
+        put(&mut data, 41, &123_456u128.to_le_bytes());
+        put(&mut data, 97, &777u64.to_le_bytes());
+        put(&mut data, 137, &(3u128 << 64).to_le_bytes());
+        put(&mut data, 153, &813u64.to_le_bytes());
+        let account = AccountPretty { data, ..Default::default() };
+
+        let event = protocol_position_state_parser(&account, EventMetadata::default()).unwrap();
+        let position = &event
+            .as_any()
+            .downcast_ref::<RaydiumClmmProtocolPositionStateAccountEvent>()
+            .unwrap()
+            .protocol_position_state;
+        assert_eq!(position.bump, 253);
+        assert_eq!(position.pool_id, pool_id);
+        assert_eq!(position.tick_lower_index, -60);
+        assert_eq!(position.tick_upper_index, 60);
+        assert_eq!(position.liquidity, 123_456);
+        assert_eq!(position.token_fees_owed1, 777);
+        assert_eq!(position.reward_growth_inside[2], 3u128 << 64);
+        assert_eq!(position.recent_epoch, 813);
+    }
+
+    #[test]
+    fn observation_state_layout() {
+        let pool_id = Pubkey::new_unique();
+        let mut data = vec![0u8; OBSERVATION_STATE_SIZE + 8];
+        put(&mut data, 0, &[1]);
+        put(&mut data, 1, &814u64.to_le_bytes());
+        put(&mut data, 9, &99u16.to_le_bytes());
+        put(&mut data, 11, pool_id.as_ref());
+        // 最后一条观测：43 + 99 * 44
+        put(&mut data, 4399, &1_760_000_000u32.to_le_bytes());
+        put(&mut data, 4403, &(-5_000_000i64).to_le_bytes());
+        let account = AccountPretty { data, ..Default::default() };
+
+        let event = observation_state_parser(&account, EventMetadata::default()).unwrap();
+        let observation_state = &event
+            .as_any()
+            .downcast_ref::<RaydiumClmmObservationStateAccountEvent>()
+            .unwrap()
+            .observation_state;
+        assert!(observation_state.initialized);
+        assert_eq!(observation_state.recent_epoch, 814);
+        assert_eq!(observation_state.observation_index, 99);
+        assert_eq!(observation_state.pool_id, pool_id);
+        assert_eq!(observation_state.observations[99].block_timestamp, 1_760_000_000);
+        assert_eq!(observation_state.observations[99].tick_cumulative, -5_000_000);
+    }
+}
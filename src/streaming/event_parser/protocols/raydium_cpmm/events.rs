@@ -1,8 +1,15 @@
-use crate::streaming::event_parser::common::EventMetadata;
+use std::collections::HashMap;
+
+use crate::streaming::event_parser::common::{
+    collect_program_logs, read_pubkey, read_u64_le, EventMetadata, PROGRAM_DATA_PREFIX,
+};
+use crate::streaming::event_parser::core::traits::UnifiedEvent;
+use crate::streaming::event_parser::protocols::raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::raydium_cpmm::types::PoolState;
 use crate::{
     impl_unified_event, streaming::event_parser::protocols::raydium_cpmm::types::AmmConfig,
 };
+use base64::{engine::general_purpose::STANDARD, Engine};
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -29,9 +36,96 @@ pub struct RaydiumCpmmSwapEvent {
     pub input_token_mint: Pubkey,
     pub output_token_mint: Pubkey,
     pub observation_state: Pubkey,
+
+    // 程序日志中的 SwapEvent，交易日志可用时填充
+    pub swap_log_decoded: bool,
+    pub pool_id: Pubkey,
+    pub input_vault_before: u64,
+    pub output_vault_before: u64,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub base_input: bool,
+    pub trade_fee: u64,
+    pub creator_fee: u64,
+    pub creator_fee_on_input: bool,
 }
 
-impl_unified_event!(RaydiumCpmmSwapEvent,);
+impl_unified_event!(
+    RaydiumCpmmSwapEvent,
+    swap_log_decoded,
+    pool_id,
+    input_vault_before,
+    output_vault_before,
+    input_amount,
+    output_amount,
+    input_transfer_fee,
+    output_transfer_fee,
+    base_input,
+    trade_fee,
+    creator_fee,
+    creator_fee_on_input
+);
+
+/// 未包含手续费字段的旧版 SwapEvent 长度
+pub const RAYDIUM_CPMM_SWAP_EVENT_LOG_SIZE: usize = 32 + 8 * 6 + 1;
+/// 包含 mint 与手续费字段的 SwapEvent 长度
+pub const RAYDIUM_CPMM_SWAP_EVENT_WITH_FEES_LOG_SIZE: usize =
+    RAYDIUM_CPMM_SWAP_EVENT_LOG_SIZE + 32 * 2 + 8 * 2 + 1;
+
+/// 解码 SwapEvent（不含鉴别器），兼容不含手续费字段的旧版布局
+pub fn raydium_cpmm_swap_event_log_decode(data: &[u8]) -> Option<RaydiumCpmmSwapEvent> {
+    if data.len() < RAYDIUM_CPMM_SWAP_EVENT_LOG_SIZE {
+        return None;
+    }
+    let mut event = RaydiumCpmmSwapEvent {
+        swap_log_decoded: true,
+        pool_id: read_pubkey(data, 0)?,
+        input_vault_before: read_u64_le(data, 32)?,
+        output_vault_before: read_u64_le(data, 40)?,
+        input_amount: read_u64_le(data, 48)?,
+        output_amount: read_u64_le(data, 56)?,
+        input_transfer_fee: read_u64_le(data, 64)?,
+        output_transfer_fee: read_u64_le(data, 72)?,
+        base_input: data[80] != 0,
+        ..Default::default()
+    };
+    if data.len() >= RAYDIUM_CPMM_SWAP_EVENT_WITH_FEES_LOG_SIZE {
+        // 跳过 input_mint / output_mint，与指令账户中的 mint 相同
+        event.trade_fee = read_u64_le(data, 145)?;
+        event.creator_fee = read_u64_le(data, 153)?;
+        event.creator_fee_on_input = data[161] != 0;
+    }
+    Some(event)
+}
+
+/// 按 (外层指令索引, 内层指令索引) 收集交易日志中的 SwapEvent
+pub fn collect_swap_event_logs(
+    log_messages: &[String],
+) -> HashMap<(i64, Option<i64>), RaydiumCpmmSwapEvent> {
+    let program_id = RAYDIUM_CPMM_PROGRAM_ID.to_string();
+    collect_program_logs(log_messages, &program_id, PROGRAM_DATA_PREFIX)
+        .into_iter()
+        .filter_map(|(outer_index, inner_index, log)| {
+            let data = STANDARD.decode(log).ok()?;
+            let data = data.strip_prefix(discriminators::SWAP_EVENT)?;
+            Some(((outer_index, inner_index), raydium_cpmm_swap_event_log_decode(data)?))
+        })
+        .collect()
+}
+
+/// 将日志中的 SwapEvent 合并到交易事件，并以实际数量更新 swap_data
+pub fn apply_swap_event_log(event: &mut dyn UnifiedEvent, log: &RaydiumCpmmSwapEvent) -> bool {
+    let Some(e) = event.as_any_mut().downcast_mut::<RaydiumCpmmSwapEvent>() else {
+        return false;
+    };
+    e.merge(log);
+    let swap_data = e.metadata.swap_data.get_or_insert_with(Default::default);
+    swap_data.from_amount = e.input_amount;
+    swap_data.to_amount = e.output_amount;
+    true
+}
 
 /// 存款
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
@@ -116,6 +210,64 @@ pub struct RaydiumCpmmWithdrawEvent {
 }
 impl_unified_event!(RaydiumCpmmWithdrawEvent,);
 
+/// 领取协议手续费
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumCpmmCollectProtocolFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub amount0_requested: u64,
+    pub amount1_requested: u64,
+
+    pub owner: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub amm_config: Pubkey,
+    pub token0_vault: Pubkey,
+    pub token1_vault: Pubkey,
+    pub vault0_mint: Pubkey,
+    pub vault1_mint: Pubkey,
+    pub recipient_token0_account: Pubkey,
+    pub recipient_token1_account: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
+}
+impl_unified_event!(RaydiumCpmmCollectProtocolFeeEvent,);
+
+/// 领取基金手续费
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumCpmmCollectFundFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub amount0_requested: u64,
+    pub amount1_requested: u64,
+
+    pub owner: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub amm_config: Pubkey,
+    pub token0_vault: Pubkey,
+    pub token1_vault: Pubkey,
+    pub vault0_mint: Pubkey,
+    pub vault1_mint: Pubkey,
+    pub recipient_token0_account: Pubkey,
+    pub recipient_token1_account: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
+}
+impl_unified_event!(RaydiumCpmmCollectFundFeeEvent,);
+
+/// 更新池状态
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumCpmmUpdatePoolStatusEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub status: u8,
+
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+}
+impl_unified_event!(RaydiumCpmmUpdatePoolStatusEvent,);
+
 /// 池配置
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct RaydiumCpmmAmmConfigAccountEvent {
@@ -152,6 +304,12 @@ pub mod discriminators {
     pub const DEPOSIT: &[u8] = &[242, 35, 198, 137, 82, 225, 242, 182];
     pub const INITIALIZE: &[u8] = &[175, 175, 109, 31, 13, 152, 155, 237];
     pub const WITHDRAW: &[u8] = &[183, 18, 70, 156, 148, 109, 161, 34];
+    pub const COLLECT_PROTOCOL_FEE: &[u8] = &[136, 136, 252, 221, 194, 66, 126, 89];
+    pub const COLLECT_FUND_FEE: &[u8] = &[167, 138, 78, 149, 223, 194, 6, 126];
+    pub const UPDATE_POOL_STATUS: &[u8] = &[130, 87, 108, 6, 46, 224, 117, 123];
+
+    // 事件鉴别器
+    pub const SWAP_EVENT: &[u8] = &[64, 198, 205, 232, 38, 8, 113, 226];
 
     // 账号鉴别器
     pub const AMM_CONFIG: &[u8] = &[218, 244, 33, 104, 203, 203, 43, 111];
@@ -212,3 +370,75 @@ impl CopyTradeableEvent for RaydiumCpmmSwapEvent {
         self.get_trade_info()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    // 按 SwapEvent 布局构造：鉴别器 + 事件数据的 base64
+    const SWAP_EVENT_WITH_FEES_LOG: &str = concat!(
+        "QMbN6CYIceJdvNnDompiQgH6CAzQDqJvIJwBv3fllio6WnYVYai68pRjeHsqAAAAgrBoGOgYAAAAlDV3AAAAADRf",
+        "AahFAAAAAAAAAAAAAAAAAAAAAAAAAAEGm4hX/quBhPtof2NGGMA12sQ53BrrO1WYoPAAAAAAAcb6evO+2606PWXz",
+        "aqvJdDGxu+TC0vbg5HymAgNFL11hQEtMAAAAAABAQg8AAAAAAAE=",
+    );
+    const SWAP_EVENT_LOG: &str = concat!(
+        "QMbN6CYIceJdvNnDompiQgH6CAzQDqJvIJwBv3fllio6WnYVYai68pRjeHsqAAAAgrBoGOgYAAAAlDV3AAAAADRf",
+        "AahFAAAAAAAAAAAAAAAAAAAAAAAAAAE=",
+    );
+
+    fn swap_logs(event_log: &str) -> Vec<String> {
+        let cpmm = RAYDIUM_CPMM_PROGRAM_ID.to_string();
+        vec![
+            format!("Program {cpmm} invoke [1]"),
+            "Program log: Instruction: SwapBaseInput".to_string(),
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]".to_string(),
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success".to_string(),
+            format!("{PROGRAM_DATA_PREFIX}{event_log}"),
+            format!("Program {cpmm} success"),
+        ]
+    }
+
+    #[test]
+    fn swap_event_log_with_fees_merges_into_swap_event() {
+        let logs = collect_swap_event_logs(&swap_logs(SWAP_EVENT_WITH_FEES_LOG));
+        assert_eq!(logs.len(), 1);
+        let log = &logs[&(0, None)];
+
+        let mut event = RaydiumCpmmSwapEvent {
+            amount_in: 2_000_000_000,
+            minimum_amount_out: 290_000_000_000,
+            ..Default::default()
+        };
+        assert!(apply_swap_event_log(&mut event, log));
+        assert!(event.swap_log_decoded);
+        assert_eq!(
+            event.pool_id,
+            Pubkey::from_str("7JuwJuNU88gurFnyWeiyGKbFmExMWcmRZntn9imEzdny").unwrap()
+        );
+        assert_eq!(event.input_vault_before, 182_460_113_812);
+        assert_eq!(event.output_vault_before, 27_385_120_993_410);
+        assert_eq!(event.input_amount, 2_000_000_000);
+        assert_eq!(event.output_amount, 299_171_405_620);
+        assert!(event.base_input);
+        assert_eq!(event.trade_fee, 5_000_000);
+        assert_eq!(event.creator_fee, 1_000_000);
+        assert!(event.creator_fee_on_input);
+        // 指令参数不被日志覆盖
+        assert_eq!(event.minimum_amount_out, 290_000_000_000);
+        let swap_data = event.metadata.swap_data.as_ref().unwrap();
+        assert_eq!((swap_data.from_amount, swap_data.to_amount), (2_000_000_000, 299_171_405_620));
+    }
+
+    #[test]
+    fn swap_event_log_without_fees_decodes() {
+        let logs = collect_swap_event_logs(&swap_logs(SWAP_EVENT_LOG));
+        let log = &logs[&(0, None)];
+        assert_eq!(log.output_amount, 299_171_405_620);
+        assert_eq!((log.trade_fee, log.creator_fee, log.creator_fee_on_input), (0, 0, false));
+
+        let data = STANDARD.decode(SWAP_EVENT_LOG).unwrap();
+        let data = &data[discriminators::SWAP_EVENT.len()..];
+        assert!(raydium_cpmm_swap_event_log_decode(&data[..data.len() - 1]).is_none());
+    }
+}
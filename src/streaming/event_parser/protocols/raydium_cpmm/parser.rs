@@ -3,11 +3,12 @@ use solana_sdk::pubkey::Pubkey;
 use crate::{
     impl_event_parser_delegate,
     streaming::event_parser::{
-        common::{read_u64_le, EventMetadata, EventType, ProtocolType},
+        common::{read_u64_le, read_u8_le, EventMetadata, EventType, ProtocolType},
         core::traits::{GenericEventParseConfig, GenericEventParser, UnifiedEvent},
         protocols::raydium_cpmm::{
-            discriminators, RaydiumCpmmDepositEvent, RaydiumCpmmInitializeEvent,
-            RaydiumCpmmSwapEvent, RaydiumCpmmWithdrawEvent,
+            discriminators, RaydiumCpmmCollectFundFeeEvent, RaydiumCpmmCollectProtocolFeeEvent,
+            RaydiumCpmmDepositEvent, RaydiumCpmmInitializeEvent, RaydiumCpmmSwapEvent,
+            RaydiumCpmmUpdatePoolStatusEvent, RaydiumCpmmWithdrawEvent,
         },
     },
 };
@@ -76,6 +77,33 @@ impl RaydiumCpmmEventParser {
                 inner_instruction_parser: None,
                 instruction_parser: Some(Self::parse_withdraw_instruction),
             },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CPMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumCpmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::COLLECT_PROTOCOL_FEE,
+                event_type: EventType::RaydiumCpmmCollectProtocolFee,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_collect_protocol_fee_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CPMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumCpmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::COLLECT_FUND_FEE,
+                event_type: EventType::RaydiumCpmmCollectFundFee,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_collect_fund_fee_instruction),
+            },
+            GenericEventParseConfig {
+                program_id: RAYDIUM_CPMM_PROGRAM_ID,
+                protocol_type: ProtocolType::RaydiumCpmm,
+                inner_instruction_discriminator: &[],
+                instruction_discriminator: discriminators::UPDATE_POOL_STATUS,
+                event_type: EventType::RaydiumCpmmUpdatePoolStatus,
+                inner_instruction_parser: None,
+                instruction_parser: Some(Self::parse_update_pool_status_instruction),
+            },
         ];
 
         let inner = GenericEventParser::new(vec![RAYDIUM_CPMM_PROGRAM_ID], configs);
@@ -83,6 +111,79 @@ impl RaydiumCpmmEventParser {
         Self { inner }
     }
 
+    /// 解析领取协议手续费指令事件
+    fn parse_collect_protocol_fee_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 16 || accounts.len() < 12 {
+            return None;
+        }
+        Some(Box::new(RaydiumCpmmCollectProtocolFeeEvent {
+            metadata,
+            amount0_requested: read_u64_le(data, 0)?,
+            amount1_requested: read_u64_le(data, 8)?,
+            owner: accounts[0],
+            authority: accounts[1],
+            pool_state: accounts[2],
+            amm_config: accounts[3],
+            token0_vault: accounts[4],
+            token1_vault: accounts[5],
+            vault0_mint: accounts[6],
+            vault1_mint: accounts[7],
+            recipient_token0_account: accounts[8],
+            recipient_token1_account: accounts[9],
+            token_program: accounts[10],
+            token_program2022: accounts[11],
+        }))
+    }
+
+    /// 解析领取基金手续费指令事件
+    fn parse_collect_fund_fee_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.len() < 16 || accounts.len() < 12 {
+            return None;
+        }
+        Some(Box::new(RaydiumCpmmCollectFundFeeEvent {
+            metadata,
+            amount0_requested: read_u64_le(data, 0)?,
+            amount1_requested: read_u64_le(data, 8)?,
+            owner: accounts[0],
+            authority: accounts[1],
+            pool_state: accounts[2],
+            amm_config: accounts[3],
+            token0_vault: accounts[4],
+            token1_vault: accounts[5],
+            vault0_mint: accounts[6],
+            vault1_mint: accounts[7],
+            recipient_token0_account: accounts[8],
+            recipient_token1_account: accounts[9],
+            token_program: accounts[10],
+            token_program2022: accounts[11],
+        }))
+    }
+
+    /// 解析更新池状态指令事件
+    fn parse_update_pool_status_instruction(
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        if data.is_empty() || accounts.len() < 2 {
+            return None;
+        }
+        Some(Box::new(RaydiumCpmmUpdatePoolStatusEvent {
+            metadata,
+            status: read_u8_le(data, 0)?,
+            authority: accounts[0],
+            pool_state: accounts[1],
+        }))
+    }
+
     /// 解析提款指令事件
     fn parse_withdraw_instruction(
         data: &[u8],
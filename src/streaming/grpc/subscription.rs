@@ -13,6 +13,12 @@ use super::types::TransactionsFilterMap;
 use crate::common::AnyResult;
 use crate::streaming::common::StreamClientConfig as ClientConfig;
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::common::EventType;
+use crate::streaming::event_parser::core::program_upgrade::program_data_accounts;
+use crate::streaming::event_parser::Protocol;
+
+/// Name of the account filter that watches protocol ProgramData accounts
+pub const PROGRAM_DATA_ACCOUNTS_FILTER: &str = "program_data";
 
 /// Subscription manager
 #[derive(Clone)]
@@ -104,6 +110,37 @@ impl SubscriptionManager {
         Some(accounts)
     }
 
+    /// Add the ProgramData accounts of the subscribed protocols so that program upgrades are
+    /// reported, unless the event type filter excludes `ProgramUpgrade`
+    pub fn with_program_data_accounts(
+        &self,
+        accounts: Option<AccountsFilterMap>,
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+    ) -> Option<AccountsFilterMap> {
+        if event_type_filter
+            .is_some_and(|filter| !filter.include.contains(&EventType::ProgramUpgrade))
+        {
+            return accounts;
+        }
+        let program_data: Vec<String> =
+            program_data_accounts(protocols).iter().map(ToString::to_string).collect();
+        if program_data.is_empty() {
+            return accounts;
+        }
+        let mut accounts = accounts.unwrap_or_default();
+        accounts.insert(
+            PROGRAM_DATA_ACCOUNTS_FILTER.to_owned(),
+            SubscribeRequestFilterAccounts {
+                account: program_data,
+                owner: vec![],
+                filters: vec![],
+                nonempty_txn_signature: None,
+            },
+        );
+        Some(accounts)
+    }
+
     /// Generate subscription request filter
     pub fn get_subscribe_request_filter(
         &self,
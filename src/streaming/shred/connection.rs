@@ -8,6 +8,7 @@ use crate::protos::shredstream::shredstream_proxy_client::ShredstreamProxyClient
 use crate::streaming::common::{
     MetricsManager, PerformanceMetrics, StreamClientConfig, SubscriptionHandle,
 };
+use crate::streaming::event_parser::common::filter::InvokerFilter;
 
 /// ShredStream gRPC 客户端
 #[derive(Clone)]
@@ -17,6 +18,8 @@ pub struct ShredStreamGrpc {
     pub metrics: Arc<RwLock<PerformanceMetrics>>,
     pub metrics_manager: MetricsManager,
     pub subscription_handle: Arc<Mutex<Option<SubscriptionHandle>>>,
+    /// 调用方程序过滤
+    pub invoker_filter: Option<InvokerFilter>,
 }
 
 impl ShredStreamGrpc {
@@ -38,6 +41,7 @@ impl ShredStreamGrpc {
             metrics: metrics.clone(),
             metrics_manager,
             subscription_handle: Arc::new(Mutex::new(None)),
+            invoker_filter: None,
         })
     }
 
@@ -70,6 +74,11 @@ impl ShredStreamGrpc {
         self.config = config;
     }
 
+    /// 设置调用方程序过滤，下次订阅时生效
+    pub fn set_invoker_filter(&mut self, invoker_filter: Option<InvokerFilter>) {
+        self.invoker_filter = invoker_filter;
+    }
+
     /// 获取性能指标
     pub fn get_metrics(&self) -> PerformanceMetrics {
         self.metrics_manager.get_metrics()
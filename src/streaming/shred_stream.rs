@@ -3,11 +3,12 @@ use std::sync::Arc;
 use futures::StreamExt;
 use solana_sdk::pubkey::Pubkey;
 
-use crate::common::AnyResult;
+use crate::common::{AnyResult, SolanaRpcClient};
 use crate::protos::shredstream::SubscribeEntriesRequest;
 use crate::streaming::common::{EventProcessor, SubscriptionHandle};
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::{Protocol, UnifiedEvent};
+use crate::streaming::event_parser::core::address_lookup_table::preload_address_lookup_tables;
 use crate::streaming::event_parser::core::traits::get_high_perf_clock;
 use crate::streaming::shred::pool::factory;
 use log::error;
@@ -16,6 +17,15 @@ use solana_entry::entry::Entry;
 use super::ShredStreamGrpc;
 
 impl ShredStreamGrpc {
+    /// 通过RPC预加载地址查找表，用于解析v0交易中的查找表账户
+    pub async fn preload_address_lookup_tables(
+        &self,
+        rpc: &SolanaRpcClient,
+        tables: &[Pubkey],
+    ) -> AnyResult<usize> {
+        preload_address_lookup_tables(rpc, tables).await
+    }
+
     /// 订阅ShredStream事件（支持批处理和即时处理）
     pub async fn shredstream_subscribe<F>(
         &self,
@@ -39,6 +49,7 @@ impl ShredStreamGrpc {
         // 创建事件处理器
         let mut event_processor =
             EventProcessor::new(self.metrics_manager.clone(), self.config.clone());
+        event_processor.set_invoker_filter(self.invoker_filter.clone());
         event_processor.set_protocols_and_event_type_filter(
             protocols,
             event_type_filter,
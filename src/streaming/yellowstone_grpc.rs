@@ -2,10 +2,10 @@ use crate::common::AnyResult;
 use crate::streaming::common::{
     EventProcessor, MetricsManager, PerformanceMetrics, StreamClientConfig, SubscriptionHandle,
 };
-use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::common::filter::{EventTypeFilter, InvokerFilter};
 use crate::streaming::event_parser::{Protocol, UnifiedEvent};
 use crate::streaming::grpc::{
-    EventPretty, SubscriptionManager,
+    EventPretty, SubscriptionManager, PROGRAM_DATA_ACCOUNTS_FILTER,
 };
 use crate::streaming::grpc::pool::factory;
 use anyhow::anyhow;
@@ -118,6 +118,11 @@ impl YellowstoneGrpc {
         self.config = config;
     }
 
+    /// 设置调用方程序过滤，下次订阅时生效
+    pub fn set_invoker_filter(&mut self, invoker_filter: Option<InvokerFilter>) {
+        self.event_processor.set_invoker_filter(invoker_filter);
+    }
+
     /// 获取性能指标
     pub fn get_metrics(&self) -> PerformanceMetrics {
         self.metrics_manager.get_metrics()
@@ -195,6 +200,11 @@ impl YellowstoneGrpc {
             account_filter.owner,
             event_type_filter.as_ref(),
         );
+        let accounts = self.subscription_manager.with_program_data_accounts(
+            accounts,
+            &protocols,
+            event_type_filter.as_ref(),
+        );
 
         // 订阅事件
         let (mut subscribe_tx, mut stream, subscribe_request) = self
@@ -356,10 +366,15 @@ impl YellowstoneGrpc {
             )
             .unwrap_or_default();
 
+        // 保留订阅时加入的 ProgramData 账户，程序升级监控不随过滤器更新而中断
+        let program_data = request.accounts.remove(PROGRAM_DATA_ACCOUNTS_FILTER);
         request.accounts = self
             .subscription_manager
             .subscribe_with_account_request(account_filter.account, account_filter.owner, None)
             .unwrap_or_default();
+        if let Some(program_data) = program_data {
+            request.accounts.insert(PROGRAM_DATA_ACCOUNTS_FILTER.to_owned(), program_data);
+        }
 
         control_sender
             .send(request.clone())